@@ -0,0 +1,246 @@
+//! Benchmarks of the recomposition throughput, run with `cargo bench -p recompose_core`.
+
+use bevy_app::App;
+use bevy_ecs::{event::Event, observer::Trigger, system::RunSystemOnce};
+use bevy_hierarchy::HierarchyPlugin;
+use bevy_ui::Node;
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use recompose_core::{
+    Compose, RecomposePlugin, Root,
+    keyed::Keyed,
+    modify::ModifyFunctions,
+    scope::Scope,
+    spawn::Spawn,
+    state::{SetState, TypedStateId},
+};
+use std::hint::black_box;
+
+const SIZES: [usize; 2] = [1_000, 5_000];
+
+const COUNTER: TypedStateId<u32> = TypedStateId::new(0);
+const ITEMS: TypedStateId<Vec<usize>> = TypedStateId::new(1);
+
+/// The manual state ids of the leaves start after the ids of the other states.
+const LEAF_IDS: usize = 2;
+
+fn app_with_root(content: impl Compose + 'static) -> App {
+    let mut app = App::new();
+    app.add_plugins((HierarchyPlugin, RecomposePlugin));
+    app.world_mut().spawn(Root::new(content));
+    app
+}
+
+fn set_state<T: Clone + Send + Sync + 'static>(
+    app: &mut App,
+    state: TypedStateId<T>,
+    modify: impl Fn(&T) -> T + Clone + Send + Sync + 'static,
+) {
+    app.world_mut()
+        .run_system_once(move |mut state_setter: SetState| {
+            state_setter.modify(state, modify.clone())
+        })
+        .unwrap();
+}
+
+/// A flat list of keyed nodes.
+#[derive(Clone)]
+struct Nodes(usize);
+
+impl Compose for Nodes {
+    fn compose<'a>(&self, _: &mut Scope) -> impl Compose + 'a {
+        (0..self.0)
+            .map(|index| Keyed::new(index, Spawn::new(Node::default())))
+            .collect::<Vec<_>>()
+    }
+}
+
+/// A list of leaves that each have their own state.
+#[derive(Clone)]
+struct Leaves(usize);
+
+impl Compose for Leaves {
+    fn compose<'a>(&self, _: &mut Scope) -> impl Compose + 'a {
+        (0..self.0)
+            .map(|index| Keyed::new(index, Leaf(index)))
+            .collect::<Vec<_>>()
+    }
+}
+
+#[derive(Clone)]
+struct Leaf(usize);
+
+impl Compose for Leaf {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let count = cx.use_state_with_id(TypedStateId::new(LEAF_IDS + self.0), 0u32);
+
+        Spawn::new(Node::default()).some_if(count.is_multiple_of(2))
+    }
+}
+
+/// A list of keyed nodes whose keys are stored in a state.
+#[derive(Clone)]
+struct KeyedNodes(usize);
+
+impl Compose for KeyedNodes {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let items = cx.use_state_with_id(ITEMS, (0..self.0).collect::<Vec<_>>());
+
+        items
+            .iter()
+            .map(|item| Keyed::new(*item, Spawn::new(Node::default())))
+            .collect::<Vec<_>>()
+    }
+}
+
+/// A list of leaves that is mounted and unmounted as a whole when the counter changes.
+#[derive(Clone)]
+struct ToggledLeaves(usize);
+
+impl Compose for ToggledLeaves {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let counter = cx.use_state_with_id(COUNTER, 0);
+
+        counter.is_multiple_of(2).then_some(Leaves(self.0))
+    }
+}
+
+#[derive(Event)]
+struct Ping;
+
+/// A list of nodes that each add an observer, which are all replaced when the counter changes.
+#[derive(Clone)]
+struct ObservedNodes(usize);
+
+impl Compose for ObservedNodes {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let counter = cx.use_state_with_id(COUNTER, 0);
+        let counter = *counter;
+
+        (0..self.0)
+            .map(|index| {
+                let node = Spawn::new(Node::default()).observe(move |_: Trigger<Ping>| {
+                    black_box(counter);
+                });
+
+                Keyed::new(index, node)
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
+fn initial_compose(c: &mut Criterion) {
+    let mut group = c.benchmark_group("initial_compose");
+
+    for size in SIZES {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            b.iter_batched(
+                || app_with_root(Nodes(size)),
+                |mut app| {
+                    app.update();
+                    app
+                },
+                BatchSize::LargeInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+fn leaf_state_change(c: &mut Criterion) {
+    let mut group = c.benchmark_group("leaf_state_change");
+
+    for size in SIZES {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            let mut app = app_with_root(Leaves(size));
+            app.update();
+
+            let leaf = TypedStateId::<u32>::new(LEAF_IDS + size / 2);
+
+            b.iter(|| {
+                set_state(&mut app, leaf, |count| count + 1);
+                app.update();
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn keyed_insert_remove(c: &mut Criterion) {
+    let mut group = c.benchmark_group("keyed_insert_remove");
+
+    for size in SIZES {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            let mut app = app_with_root(KeyedNodes(size));
+            app.update();
+
+            // Every iteration inserts an item in the middle of the list, and removes it again.
+            b.iter(|| {
+                set_state(&mut app, ITEMS, move |items| {
+                    let mut items = items.clone();
+                    items.insert(items.len() / 2, size);
+                    items
+                });
+                app.update();
+
+                set_state(&mut app, ITEMS, move |items| {
+                    items.iter().copied().filter(|item| *item != size).collect()
+                });
+                app.update();
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn mount_unmount(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mount_unmount");
+
+    for size in SIZES {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            let mut app = app_with_root(ToggledLeaves(size));
+            app.update();
+
+            // Every iteration unmounts all leaves, and mounts them again.
+            b.iter(|| {
+                set_state(&mut app, COUNTER, |counter| counter + 1);
+                app.update();
+
+                set_state(&mut app, COUNTER, |counter| counter + 1);
+                app.update();
+            });
+        });
+    }
+
+    group.finish();
+}
+
+fn observer_churn(c: &mut Criterion) {
+    let mut group = c.benchmark_group("observer_churn");
+
+    for size in SIZES {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            let mut app = app_with_root(ObservedNodes(size));
+            app.update();
+
+            b.iter(|| {
+                set_state(&mut app, COUNTER, |counter| counter + 1);
+                app.update();
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    initial_compose,
+    leaf_state_change,
+    keyed_insert_remove,
+    mount_unmount,
+    observer_churn
+);
+criterion_main!(benches);
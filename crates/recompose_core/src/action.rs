@@ -0,0 +1,302 @@
+use crate::{
+    focus::InputFocus,
+    scope::{ScopeId, ScopeRef, Scopes},
+    state::{GetStateId, SetState, StateId},
+    Scope,
+};
+use bevy_ecs::{
+    entity::Entity,
+    system::{Query, Res, ResMut, Resource, SystemParam},
+};
+use bevy_hierarchy::Parent;
+use bevy_input::{
+    gamepad::{Gamepad, GamepadButton},
+    keyboard::KeyCode,
+    ButtonInput,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+pub(crate) type ActionHandler = Arc<dyn Fn(&mut SetState) + Send + Sync>;
+
+/// A logical input action, like confirming or navigating. Actions are mapped to keys and gamepad buttons by the
+/// [`InputMap`], and handled by composables with [`use_action`](Scope::use_action).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Action {
+    /// Activates the focused element.
+    Confirm,
+    /// Goes back or closes the current menu.
+    Cancel,
+    /// Moves the selection up.
+    Up,
+    /// Moves the selection down.
+    Down,
+    /// Moves the selection to the left.
+    Left,
+    /// Moves the selection to the right.
+    Right,
+    /// Moves to the next page or tab.
+    Next,
+    /// Moves to the previous page or tab.
+    Previous,
+    /// An action defined by the app, identified by its name.
+    Custom(&'static str),
+}
+
+/// A key or gamepad button that triggers an [`Action`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum InputBinding {
+    /// A key of the keyboard.
+    Key(KeyCode),
+    /// A button of any connected gamepad.
+    GamepadButton(GamepadButton),
+}
+
+impl From<KeyCode> for InputBinding {
+    fn from(key: KeyCode) -> Self {
+        InputBinding::Key(key)
+    }
+}
+
+impl From<GamepadButton> for InputBinding {
+    fn from(button: GamepadButton) -> Self {
+        InputBinding::GamepadButton(button)
+    }
+}
+
+/// Maps keys and gamepad buttons to [`Action`]s. The map is a resource that is initialized with sensible defaults for
+/// menus by the [`RecomposePlugin`](crate::RecomposePlugin). It can be replaced by inserting a custom map, or changed
+/// at runtime, for example to let players rebind their controls.
+///
+/// # Example
+/// ```ignore
+/// let mut input_map = InputMap::default();
+/// input_map.bind(Action::Custom("inventory"), KeyCode::KeyI);
+/// input_map.bind(Action::Custom("inventory"), GamepadButton::North);
+///
+/// app.insert_resource(input_map);
+/// ```
+#[derive(Resource, Clone, Debug)]
+pub struct InputMap {
+    bindings: HashMap<Action, Vec<InputBinding>>,
+}
+
+impl InputMap {
+    /// Creates an empty map, without any bindings.
+    pub fn empty() -> Self {
+        Self {
+            bindings: HashMap::new(),
+        }
+    }
+
+    /// Adds a binding that triggers the given action.
+    pub fn bind(&mut self, action: Action, binding: impl Into<InputBinding>) -> &mut Self {
+        let binding = binding.into();
+        let bindings = self.bindings.entry(action).or_default();
+
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+
+        self
+    }
+
+    /// Removes all bindings of the given action.
+    pub fn clear(&mut self, action: Action) -> &mut Self {
+        self.bindings.remove(&action);
+        self
+    }
+
+    /// Returns the bindings of the given action.
+    pub fn bindings(&self, action: Action) -> &[InputBinding] {
+        self.bindings.get(&action).map_or(&[], |bindings| bindings)
+    }
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        let mut map = Self::empty();
+
+        map.bind(Action::Confirm, KeyCode::Enter)
+            .bind(Action::Confirm, KeyCode::Space)
+            .bind(Action::Confirm, GamepadButton::South)
+            .bind(Action::Cancel, KeyCode::Escape)
+            .bind(Action::Cancel, GamepadButton::East)
+            .bind(Action::Up, KeyCode::ArrowUp)
+            .bind(Action::Up, GamepadButton::DPadUp)
+            .bind(Action::Down, KeyCode::ArrowDown)
+            .bind(Action::Down, GamepadButton::DPadDown)
+            .bind(Action::Left, KeyCode::ArrowLeft)
+            .bind(Action::Left, GamepadButton::DPadLeft)
+            .bind(Action::Right, KeyCode::ArrowRight)
+            .bind(Action::Right, GamepadButton::DPadRight)
+            .bind(Action::Next, KeyCode::PageDown)
+            .bind(Action::Next, GamepadButton::RightTrigger)
+            .bind(Action::Previous, KeyCode::PageUp)
+            .bind(Action::Previous, GamepadButton::LeftTrigger);
+
+        map
+    }
+}
+
+/// Reads the [`InputMap`] together with the inputs it maps.
+#[derive(SystemParam)]
+pub(crate) struct ActionInput<'w, 's> {
+    input_map: Res<'w, InputMap>,
+    keys: Option<Res<'w, ButtonInput<KeyCode>>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl ActionInput<'_, '_> {
+    /// Returns the actions with at least one binding that was just pressed.
+    fn just_pressed(&self) -> HashSet<Action> {
+        self.input_map
+            .bindings
+            .iter()
+            .filter(|(_, bindings)| bindings.iter().any(|binding| self.is_just_pressed(binding)))
+            .map(|(action, _)| *action)
+            .collect()
+    }
+
+    fn is_just_pressed(&self, binding: &InputBinding) -> bool {
+        match binding {
+            InputBinding::Key(key) => self
+                .keys
+                .as_ref()
+                .is_some_and(|keys| keys.just_pressed(*key)),
+            InputBinding::GamepadButton(button) => self
+                .gamepads
+                .iter()
+                .any(|gamepad| gamepad.just_pressed(*button)),
+        }
+    }
+}
+
+struct ActionSubscription {
+    action: Action,
+    scope: ScopeId,
+    handler: ActionHandler,
+}
+
+/// The handlers registered by [`use_action`](Scope::use_action), keyed by the id of the state of the hook.
+#[derive(Resource, Default)]
+pub(crate) struct ActionSubscriptions {
+    subscriptions: HashMap<StateId, ActionSubscription>,
+}
+
+pub(crate) fn dispatch_actions(
+    input: ActionInput,
+    focus: Res<InputFocus>,
+    parents: Query<&Parent>,
+    subscriptions: Res<ActionSubscriptions>,
+    scopes: Scopes,
+    mut state: SetState,
+) {
+    if subscriptions.subscriptions.is_empty() {
+        return;
+    }
+
+    let actions = input.just_pressed();
+
+    let Some(focused) = focus.0.filter(|_| !actions.is_empty()) else {
+        return;
+    };
+
+    // A subtree has focus when one of its entities is the focused entity or one of its ancestors.
+    let focused_path = std::iter::successors(Some(focused), |entity| {
+        parents.get(*entity).ok().map(|parent| parent.get())
+    })
+    .collect::<HashSet<Entity>>();
+
+    let scopes = scopes
+        .iter()
+        .flat_map(|(_, scope)| scope.descendants())
+        .map(|scope| (scope.id(), scope))
+        .collect::<HashMap<_, _>>();
+
+    for action in actions {
+        let focused_subscriptions = subscriptions
+            .subscriptions
+            .values()
+            .filter(|subscription| subscription.action == action)
+            .filter_map(|subscription| {
+                let scope = scopes.get(&subscription.scope)?;
+                has_focus(scope, &focused_path).then_some((scope, subscription))
+            })
+            .collect::<Vec<_>>();
+
+        // Only the innermost composables with focus handle the action, so that a dialog can handle `Cancel` without
+        // the screen behind it handling it as well.
+        let Some(innermost) = focused_subscriptions
+            .iter()
+            .map(|(scope, _)| depth(scope))
+            .max()
+        else {
+            continue;
+        };
+
+        for (scope, subscription) in focused_subscriptions {
+            if depth(scope) == innermost {
+                (subscription.handler)(&mut state);
+            }
+        }
+    }
+}
+
+fn has_focus(scope: &ScopeRef, focused_path: &HashSet<Entity>) -> bool {
+    scope
+        .descendants()
+        .filter_map(|scope| scope.entity())
+        .any(|entity| focused_path.contains(&entity))
+}
+
+fn depth(scope: &ScopeRef) -> usize {
+    scope.stable_path().segments().len()
+}
+
+impl Scope<'_> {
+    /// Calls the handler when the given [`Action`] is triggered through the [`InputMap`], while the focused entity is
+    /// one of the entities of this composable or its descendants. When several composables with focus handle the same
+    /// action, only the innermost ones are called. Nothing is handled while no entity has
+    /// [`InputFocus`](crate::focus::InputFocus).
+    ///
+    /// # Example
+    /// ```ignore
+    /// let selected = cx.use_state(0);
+    /// let selected_id = selected.get_typed_id();
+    ///
+    /// cx.use_action(Action::Down, move |state| {
+    ///     state.modify(selected_id, |selected| (selected + 1).min(MENU_ITEMS - 1));
+    /// });
+    /// cx.use_action(Action::Confirm, move |state| {
+    ///     state.set(confirmed_id, true);
+    /// });
+    /// ```
+    pub fn use_action(
+        &mut self,
+        action: Action,
+        handler: impl Fn(&mut SetState) + Send + Sync + 'static,
+    ) {
+        let key = self.use_state(()).get_id();
+        let scope = self.id;
+        let handler: ActionHandler = Arc::new(handler);
+
+        // The handler is replaced on every recomposition, so that it always captures the latest values.
+        self.run_system(move |mut subscriptions: ResMut<ActionSubscriptions>| {
+            subscriptions.subscriptions.insert(
+                key,
+                ActionSubscription {
+                    action,
+                    scope,
+                    handler: handler.clone(),
+                },
+            );
+        });
+
+        self.use_unmount_system_once(move |mut subscriptions: ResMut<ActionSubscriptions>| {
+            subscriptions.subscriptions.remove(&key);
+        });
+    }
+}
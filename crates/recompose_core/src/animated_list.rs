@@ -0,0 +1,191 @@
+use crate::{dyn_compose::DynCompose, keyed::Keyed, state::SetState, AnyKey, Compose, Key, Scope};
+use bevy_ecs::system::Res;
+use bevy_time::Time;
+use std::{sync::Arc, time::Duration};
+
+/// The phase of an item in an [`AnimatedList`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ListItemPhase {
+    /// The item is part of the list.
+    Present,
+    /// The item was removed from the list and is kept alive until its exit transition is finished. The value is the
+    /// progress of the exit transition, from `0.0` to `1.0`.
+    Exiting(f32),
+}
+
+impl ListItemPhase {
+    /// Whether the item was removed from the list and is currently exiting.
+    pub fn is_exiting(&self) -> bool {
+        matches!(self, ListItemPhase::Exiting(_))
+    }
+}
+
+#[derive(Clone)]
+struct ListEntry<T> {
+    item: T,
+    key: AnyKey,
+    exit: Option<ExitProgress>,
+}
+
+#[derive(Clone, Copy)]
+struct ExitProgress {
+    /// The elapsed time of the app when the item started exiting. It is `None` until the exit is picked up by the
+    /// system that advances the transitions.
+    started: Option<Duration>,
+    progress: f32,
+}
+
+type RenderFn<T> = Arc<dyn Fn(&T, ListItemPhase) -> DynCompose + Send + Sync>;
+
+/// A keyed list of items that keeps removed items alive for a given duration, so that they can animate out before they
+/// are decomposed. Every item is composed with the `render` function, which receives the [`ListItemPhase`] of the item.
+/// Like a `Vec` of keyed composables, the items are identified by their [`Key`].
+///
+/// While any item is exiting, the list is recomposed every frame to advance the exit transitions.
+///
+/// # Example
+/// ```ignore
+/// AnimatedList::new((*todos).clone(), |todo, phase| {
+///     let opacity = match phase {
+///         ListItemPhase::Present => 1.0,
+///         ListItemPhase::Exiting(progress) => 1.0 - progress,
+///     };
+///
+///     TodoItem::new(todo.clone()).with_bundle(TextColor(Color::WHITE.with_alpha(opacity)))
+/// })
+/// .exit_duration(Duration::from_millis(300))
+/// ```
+#[derive(Clone)]
+pub struct AnimatedList<T> {
+    items: Vec<T>,
+    render: RenderFn<T>,
+    exit_duration: Duration,
+}
+
+impl<T: Key + Clone + Send + Sync + 'static> AnimatedList<T> {
+    /// Creates a new animated list from the given items and render function.
+    pub fn new<C: Compose + 'static>(
+        items: Vec<T>,
+        render: impl Fn(&T, ListItemPhase) -> C + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            items,
+            render: Arc::new(move |item, phase| DynCompose::new(render(item, phase))),
+            exit_duration: Duration::from_millis(250),
+        }
+    }
+
+    /// Sets how long removed items are kept alive before they are decomposed.
+    pub fn exit_duration(mut self, exit_duration: Duration) -> Self {
+        self.exit_duration = exit_duration;
+        self
+    }
+}
+
+impl<T: Key + Clone + Send + Sync + 'static> Compose for AnimatedList<T> {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let entries = cx.use_state(Vec::<ListEntry<T>>::new());
+        let entries_id = entries.get_typed_id();
+
+        let mut new_entries = self
+            .items
+            .iter()
+            .map(|item| ListEntry {
+                item: item.clone(),
+                key: AnyKey::new(item),
+                exit: None,
+            })
+            .collect::<Vec<_>>();
+
+        // Removed items are kept right after the item that preceded them, so that they exit in place.
+        let mut insert_index = 0;
+
+        for entry in entries.iter() {
+            if let Some(index) = new_entries
+                .iter()
+                .position(|new_entry| new_entry.key == entry.key)
+            {
+                insert_index = index + 1;
+                continue;
+            }
+
+            let exit = entry.exit.unwrap_or(ExitProgress {
+                started: None,
+                progress: 0.0,
+            });
+
+            if exit.progress >= 1.0 {
+                continue;
+            }
+
+            new_entries.insert(
+                insert_index,
+                ListEntry {
+                    exit: Some(exit),
+                    ..entry.clone()
+                },
+            );
+            insert_index += 1;
+        }
+
+        let has_exiting_entries = new_entries.iter().any(|entry| entry.exit.is_some());
+
+        if has_exiting_entries {
+            let exit_duration = self.exit_duration;
+
+            cx.run_system(move |time: Option<Res<Time>>, mut state: SetState| {
+                let now = time.map(|time| time.elapsed());
+
+                state.modify(entries_id, move |entries| {
+                    let mut entries = entries.clone();
+
+                    for exit in entries.iter_mut().filter_map(|entry| entry.exit.as_mut()) {
+                        exit.progress = advance_transition(&mut exit.started, now, exit_duration);
+                    }
+
+                    entries
+                });
+            });
+        }
+
+        let children = new_entries
+            .iter()
+            .map(|entry| {
+                let phase = match entry.exit {
+                    Some(exit) => ListItemPhase::Exiting(exit.progress),
+                    None => ListItemPhase::Present,
+                };
+
+                Keyed::new(entry.key.clone(), (self.render)(&entry.item, phase))
+            })
+            .collect::<Vec<_>>();
+
+        cx.set_state_unchanged(&entries, new_entries);
+
+        children
+    }
+
+    fn name(&self) -> String {
+        String::from("AnimatedList")
+    }
+}
+
+/// Returns the progress of a transition of the given duration, from `0.0` to `1.0`. The transition starts at `now` if
+/// it hasn't started yet. Without a `Time` resource, transitions can't advance, so they are finished immediately.
+pub(crate) fn advance_transition(
+    started: &mut Option<Duration>,
+    now: Option<Duration>,
+    duration: Duration,
+) -> f32 {
+    let Some(now) = now else {
+        return 1.0;
+    };
+
+    let started = *started.get_or_insert(now);
+
+    if duration.is_zero() {
+        return 1.0;
+    }
+
+    ((now - started).as_secs_f32() / duration.as_secs_f32()).min(1.0)
+}
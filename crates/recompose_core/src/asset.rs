@@ -0,0 +1,199 @@
+use crate::{
+    state::{GetStateId, SetState, State, StateId, TypedStateId},
+    Scope,
+};
+use bevy_asset::{Asset, AssetServer, Handle, LoadState, UntypedHandle};
+use bevy_ecs::system::{Res, ResMut, Resource};
+use std::{collections::HashMap, mem::discriminant, mem::Discriminant};
+
+/// An asset loaded with [`use_asset`](Scope::use_asset), together with its load state.
+#[derive(Clone, Debug)]
+pub struct AssetState<A: Asset> {
+    /// The handle of the asset. `None` until the asset has started loading, which happens before the next
+    /// recomposition.
+    pub handle: Option<Handle<A>>,
+    /// The load state of the asset.
+    pub load_state: LoadState,
+}
+
+impl<A: Asset> AssetState<A> {
+    /// Whether the asset has been loaded.
+    pub fn is_loaded(&self) -> bool {
+        self.load_state.is_loaded()
+    }
+
+    /// Whether the asset failed to load.
+    pub fn is_failed(&self) -> bool {
+        self.load_state.is_failed()
+    }
+}
+
+impl<A: Asset> Default for AssetState<A> {
+    fn default() -> Self {
+        Self {
+            handle: None,
+            load_state: LoadState::NotLoaded,
+        }
+    }
+}
+
+type SetLoadStateFn = Box<dyn Fn(LoadState, &mut SetState) + Send + Sync>;
+
+struct AssetSubscription {
+    handle: UntypedHandle,
+    set_load_state: SetLoadStateFn,
+    last_load_state: Discriminant<LoadState>,
+}
+
+/// The assets that are watched by [`use_asset`](Scope::use_asset), keyed by the id of the state that holds the asset.
+#[derive(Resource, Default)]
+pub(crate) struct AssetSubscriptions {
+    subscriptions: HashMap<StateId, AssetSubscription>,
+}
+
+pub(crate) fn update_asset_subscriptions(
+    server: Option<Res<AssetServer>>,
+    mut subscriptions: ResMut<AssetSubscriptions>,
+    mut state: SetState,
+) {
+    let Some(server) = server else {
+        return;
+    };
+
+    for subscription in subscriptions.subscriptions.values_mut() {
+        let load_state = server.load_state(subscription.handle.id());
+
+        // `LoadState` can't be compared, but only changes of the variant are of interest anyways.
+        if discriminant(&load_state) == subscription.last_load_state {
+            continue;
+        }
+
+        subscription.last_load_state = discriminant(&load_state);
+        (subscription.set_load_state)(load_state, &mut state);
+    }
+}
+
+impl Scope<'_> {
+    /// Loads an asset with the `AssetServer` and returns it as a state, together with its [`LoadState`]. The composable
+    /// is recomposed when the asset finishes loading or fails to load. The asset is loaded again when the path changes,
+    /// and the handle is kept alive until the composable is decomposed.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let icon = cx.use_asset::<Image>("icons/save.png");
+    ///
+    /// match (&icon.handle, icon.is_loaded()) {
+    ///     (Some(handle), true) => DynCompose::new(ImageNode::new(handle.clone()).to_compose()),
+    ///     _ => DynCompose::new(Spinner),
+    /// }
+    /// ```
+    pub fn use_asset<A: Asset>(&mut self, path: impl Into<String>) -> State<AssetState<A>> {
+        let asset = self.use_state(AssetState::default());
+        let loaded_path = self.use_state(None);
+        let path = path.into();
+        let state_id = asset.get_id();
+        let typed_state_id: TypedStateId<AssetState<A>> = asset.get_typed_id();
+
+        if loaded_path.as_ref() != Some(&path) {
+            self.set_state_unchanged(&loaded_path, Some(path.clone()));
+
+            self.run_system(
+                move |server: Res<AssetServer>,
+                      mut subscriptions: ResMut<AssetSubscriptions>,
+                      mut state: SetState| {
+                    let handle = server.load::<A>(path.clone());
+                    let load_state = server.load_state(handle.id());
+                    let subscribed_handle = handle.clone();
+
+                    subscriptions.subscriptions.insert(
+                        state_id,
+                        AssetSubscription {
+                            handle: handle.clone().untyped(),
+                            set_load_state: Box::new(move |load_state, state| {
+                                let asset = AssetState {
+                                    handle: Some(subscribed_handle.clone()),
+                                    load_state,
+                                };
+
+                                state.set(typed_state_id, asset);
+                            }),
+                            last_load_state: discriminant(&load_state),
+                        },
+                    );
+
+                    let asset = AssetState {
+                        handle: Some(handle),
+                        load_state,
+                    };
+
+                    state.set(typed_state_id, asset);
+                },
+            );
+        }
+
+        self.use_unmount_system_once(move |mut subscriptions: ResMut<AssetSubscriptions>| {
+            subscriptions.subscriptions.remove(&state_id);
+        });
+
+        asset
+    }
+    /// Returns the [`LoadState`] of the asset of the given handle as a state. The composable is recomposed when the
+    /// asset finishes loading or fails to load. Assets that were added to `Assets` directly, rather than loaded by the
+    /// `AssetServer`, are always loaded.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let load_state = cx.use_load_state(&self.portrait);
+    ///
+    /// match load_state.is_loaded() {
+    ///     true => DynCompose::new(ImageNode::new(self.portrait.clone()).to_compose()),
+    ///     false => DynCompose::new(Spinner),
+    /// }
+    /// ```
+    pub fn use_load_state<A: Asset>(&mut self, handle: &Handle<A>) -> State<LoadState> {
+        let load_state = self.use_state(LoadState::NotLoaded);
+        let watched_asset = self.use_state(None);
+        let state_id = load_state.get_id();
+        let typed_state_id: TypedStateId<LoadState> = load_state.get_typed_id();
+
+        if *watched_asset != Some(handle.id()) {
+            self.set_state_unchanged(&watched_asset, Some(handle.id()));
+
+            let handle = handle.clone().untyped();
+
+            self.run_system(
+                move |server: Option<Res<AssetServer>>,
+                      mut subscriptions: ResMut<AssetSubscriptions>,
+                      mut state: SetState| {
+                    let Some(load_state) = server
+                        .as_ref()
+                        .and_then(|server| server.get_load_state(handle.id()))
+                    else {
+                        subscriptions.subscriptions.remove(&state_id);
+                        state.set(typed_state_id, LoadState::Loaded);
+                        return;
+                    };
+
+                    subscriptions.subscriptions.insert(
+                        state_id,
+                        AssetSubscription {
+                            handle: handle.clone(),
+                            set_load_state: Box::new(move |load_state, state| {
+                                state.set(typed_state_id, load_state);
+                            }),
+                            last_load_state: discriminant(&load_state),
+                        },
+                    );
+
+                    state.set(typed_state_id, load_state);
+                },
+            );
+        }
+
+        self.use_unmount_system_once(move |mut subscriptions: ResMut<AssetSubscriptions>| {
+            subscriptions.subscriptions.remove(&state_id);
+        });
+
+        load_state
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{
+    state::{GetStateId, SetState, State, StateId, TypedStateId},
+    Scope,
+};
+use std::{any::Any, ops::Deref, sync::Arc};
+
+/// A callback that is called with the new value when a control is edited.
+pub type ChangeHandler<T> = Arc<dyn Fn(T, &mut SetState) + Send + Sync>;
+
+/// A two-way binding to an external state, created from a [`State`]. It holds the value of the state at the time the
+/// binding was created, which is what lets a control pull in external changes when it is recomposed.
+///
+/// Bindings are passed to form controls with [`bind_value`](BindValue::bind_value).
+#[derive(Clone)]
+pub struct Binding<T> {
+    value: T,
+    id: Option<TypedStateId<T>>,
+}
+
+impl<T> Binding<T> {
+    /// Creates a one-way binding to the given value. Changes to the value are pulled in when the control is
+    /// recomposed, but edits are not pushed out. This is useful for controls whose value is controlled by the parent
+    /// through a change callback.
+    pub fn from_value(value: T) -> Self {
+        Self { value, id: None }
+    }
+}
+
+impl<T: Clone> From<State<T>> for Binding<T> {
+    fn from(state: State<T>) -> Self {
+        Self::from(&state)
+    }
+}
+
+impl<T: Clone> From<&State<T>> for Binding<T> {
+    fn from(state: &State<T>) -> Self {
+        Self {
+            value: (**state).clone(),
+            id: Some(state.get_typed_id()),
+        }
+    }
+}
+
+/// Trait for composables with an editable value, like text inputs and sliders, that can be bound to an external state.
+pub trait BindValue<T>: Sized {
+    /// Returns a mutable reference to the binding of the composable.
+    fn binding(&mut self) -> &mut Option<Binding<T>>;
+
+    /// Binds the value of the composable to the given state. Edits are pushed out to the state, and changes to the
+    /// state are pulled in when the composable is recomposed.
+    fn bind_value(mut self, state: impl Into<Binding<T>>) -> Self {
+        *self.binding() = Some(state.into());
+        self
+    }
+}
+
+/// The editing state of a control that is (optionally) bound to an external state. Returned by
+/// [`use_binding`](Scope::use_binding).
+#[derive(Clone)]
+pub struct BoundState<T> {
+    value: State<T>,
+    setter: BoundSetter<T>,
+}
+
+impl<T> BoundState<T> {
+    /// Returns a setter that updates both the local and the bound state. It is `Copy`, so it can be moved into
+    /// observers and systems.
+    pub fn setter(&self) -> BoundSetter<T> {
+        self.setter
+    }
+}
+
+impl<T> Deref for BoundState<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> GetStateId<T> for BoundState<T> {
+    fn get_id(&self) -> StateId {
+        self.value.get_id()
+    }
+}
+
+/// Sets the value of a [`BoundState`], pushing the change out to the bound state as well.
+pub struct BoundSetter<T> {
+    local: TypedStateId<T>,
+    external: Option<TypedStateId<T>>,
+}
+
+impl<T> Clone for BoundSetter<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BoundSetter<T> {}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> BoundSetter<T> {
+    /// Sets the value of the local state and the bound state.
+    pub fn set(&self, state: &mut SetState, value: T) {
+        if let Some(external) = self.external {
+            state.set_neq(external, value.clone());
+        }
+
+        state.set_neq(self.local, value);
+    }
+
+    /// Modifies the value of the local state and the bound state with the given function.
+    pub fn modify(
+        &self,
+        state: &mut SetState,
+        value_fn: impl (Fn(&T) -> T) + Clone + Send + Sync + 'static,
+    ) {
+        if let Some(external) = self.external {
+            state.modify(external, value_fn.clone());
+        }
+
+        state.modify(self.local, value_fn);
+    }
+}
+
+impl Scope<'_> {
+    /// Creates the editing state of a control whose value is controlled by its parent, or bound to an external state
+    /// with a [`Binding`].
+    ///
+    /// Without a binding, the control shows the given value, and the local value is replaced with it whenever the
+    /// parent passes in a different one. A value that never changes therefore makes this behave like
+    /// [`use_state`](Scope::use_state). With a binding, the value of the bound state is used instead.
+    ///
+    /// Edits made through the [`setter`](BoundState::setter) are applied to the local state and the bound state. When
+    /// the value of the bound state changes from the outside, the local value is replaced with it on the next
+    /// recomposition. If both states were changed at the same time, the external value wins.
+    pub fn use_binding<T: Any + Clone + PartialEq + Send + Sync>(
+        &mut self,
+        binding: Option<&Binding<T>>,
+        value: T,
+    ) -> BoundState<T> {
+        let binding = match binding {
+            Some(binding) => binding.clone(),
+            None => Binding::from_value(value),
+        };
+
+        let mut value = self.use_state_lazy(|| binding.value.clone());
+        let last_external = self.use_state_lazy(|| binding.value.clone());
+
+        if *last_external != binding.value {
+            self.set_state_unchanged(&last_external, binding.value.clone());
+
+            if *value != binding.value {
+                self.set_state_unchanged(&value, binding.value.clone());
+                value = self.pending_value(&value).unwrap_or(value);
+            }
+        }
+
+        BoundState {
+            setter: BoundSetter {
+                local: value.get_typed_id(),
+                external: binding.id,
+            },
+            value,
+        }
+    }
+}
+
+/// The value of a widget that is either controlled by its parent, or kept in an internal state when the parent doesn't
+/// provide a value. Returned by [`use_controlled`](Scope::use_controlled).
+pub struct Controlled<T> {
+    value: BoundState<T>,
+    is_controlled: bool,
+}
+
+impl<T: Clone> Controlled<T> {
+    /// Whether the value is controlled by the parent.
+    pub fn is_controlled(&self) -> bool {
+        self.is_controlled
+    }
+
+    /// Returns a setter that reports changes through the given callback. When the value is not controlled, the internal
+    /// state is updated as well. When it is controlled, it is up to the parent to pass in the new value.
+    pub fn setter(&self, on_change: Option<ChangeHandler<T>>) -> ControlledSetter<T> {
+        ControlledSetter {
+            setter: self.value.setter(),
+            is_controlled: self.is_controlled,
+            on_change,
+        }
+    }
+}
+
+impl<T> Deref for Controlled<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+/// Sets the value of a [`Controlled`] value. See [`Controlled::setter`].
+#[derive(Clone)]
+pub struct ControlledSetter<T> {
+    setter: BoundSetter<T>,
+    is_controlled: bool,
+    on_change: Option<ChangeHandler<T>>,
+}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> ControlledSetter<T> {
+    /// Sets the value. The internal state is only updated when the value is not controlled, but the change callback is
+    /// called in both cases.
+    pub fn set(&self, state: &mut SetState, value: T) {
+        if !self.is_controlled {
+            self.setter.set(state, value.clone());
+        }
+
+        if let Some(on_change) = &self.on_change {
+            on_change(value, state);
+        }
+    }
+}
+
+impl Scope<'_> {
+    /// Codifies the controlled/uncontrolled pattern for widgets, on top of [`use_binding`](Scope::use_binding). When
+    /// the parent provides a `value`, the widget is controlled and shows that value. Otherwise, the value is kept in an
+    /// internal state that starts out as `default_value`. Either way, edits are made through the
+    /// [`setter`](Controlled::setter), which reports them through the widget's change callback.
+    ///
+    /// # Example
+    /// ```ignore
+    /// impl Compose for Stepper {
+    ///     fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+    ///         let value = cx.use_controlled(self.value.as_ref(), self.default_value);
+    ///         let setter = value.setter(self.on_change.clone());
+    ///         let current = *value;
+    ///
+    ///         Button::new("+").observe(move |_: Trigger<Pointer<Click>>, mut state: SetState| {
+    ///             setter.set(&mut state, current + 1);
+    ///         })
+    ///     }
+    /// }
+    /// ```
+    pub fn use_controlled<T: Any + Clone + PartialEq + Send + Sync>(
+        &mut self,
+        value: Option<&T>,
+        default_value: T,
+    ) -> Controlled<T> {
+        let last_value = self.use_state(default_value);
+
+        // The uncontrolled value starts out as the last controlled value, so that the widget continues from it when the
+        // parent stops controlling it.
+        if let Some(value) = value
+            && *last_value != *value
+        {
+            self.set_state_unchanged(&last_value, value.clone());
+        }
+
+        let shown_value = value.cloned().unwrap_or_else(|| (*last_value).clone());
+
+        Controlled {
+            value: self.use_binding(None, shown_value),
+            is_controlled: value.is_some(),
+        }
+    }
+}
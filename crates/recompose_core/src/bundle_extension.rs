@@ -1,13 +1,25 @@
 use crate::{
     dyn_compose::DynCompose,
-    keyed::Keyed,
-    modify::{Modifier, ModifyFunctions},
-    spawn::Spawn,
-    state::GetStateId,
+    gestures::DragGesture,
+    interaction::InteractionState,
+    keyed::{Keyed, ScopeKeyed},
+    layout::NodeLayout,
+    modify::{ChildrenPolicy, Modifier, ModifyFunctions},
+    pointer_events::{PointerEvent, PointerEventKind},
+    selection::Selection,
+    spawn::{DespawnPolicy, Spawn},
+    state::{GetStateId, SetState},
+    tooltip::Tooltip,
     Compose,
 };
-use bevy_ecs::{bundle::Bundle, event::Event, system::IntoObserverSystem};
-use std::{hash::Hash, marker::PhantomData};
+use bevy_ecs::{
+    bundle::Bundle,
+    entity::Entity,
+    event::Event,
+    system::{EntityCommands, IntoObserverSystem},
+};
+use bevy_math::Vec2;
+use std::{any::Any, hash::Hash, marker::PhantomData, time::Duration};
 
 /// Trait that allows for easier conversion of `Bundle` into `Spawn`.
 pub trait BundleExtension<B: Bundle + Clone>: Sized {
@@ -24,6 +36,10 @@ impl<B: Bundle + Clone, BE: BundleExtension<B>> ModifyFunctions<PhantomData<B>>
         self.to_compose().children(children)
     }
 
+    fn slot(self, name: &'static str, content: impl Compose + 'static) -> Spawn<B> {
+        self.to_compose().slot(name, content)
+    }
+
     fn with_bundle<B2: Bundle + Clone>(self, bundle: B2) -> Self::Target {
         self.to_compose().with_bundle(bundle)
     }
@@ -32,6 +48,46 @@ impl<B: Bundle + Clone, BE: BundleExtension<B>> ModifyFunctions<PhantomData<B>>
         self.to_compose().with_bundle_if(condition, bundle)
     }
 
+    fn when(
+        self,
+        condition: bool,
+        modify: impl FnOnce(Self::Target) -> Self::Target,
+    ) -> Self::Target {
+        self.to_compose().when(condition, modify)
+    }
+
+    fn order(self, order: i32) -> Self::Target {
+        self.to_compose().order(order)
+    }
+
+    fn visible(self, visible: bool) -> Self::Target {
+        self.to_compose().visible(visible)
+    }
+
+    fn pickable(self, pickable: bool) -> Self::Target {
+        self.to_compose().pickable(pickable)
+    }
+
+    fn block_lower(self, block_lower: bool) -> Self::Target {
+        self.to_compose().block_lower(block_lower)
+    }
+
+    fn on_despawn_transition(
+        self,
+        on_start: impl Fn(&mut EntityCommands) + Send + Sync + 'static,
+        duration: Duration,
+    ) -> Self::Target {
+        self.to_compose().on_despawn_transition(on_start, duration)
+    }
+
+    fn despawn_policy(self, policy: DespawnPolicy) -> Spawn<B> {
+        self.to_compose().despawn_policy(policy)
+    }
+
+    fn detached(self) -> Spawn<B> {
+        self.to_compose().detached()
+    }
+
     fn to_dyn(self) -> DynCompose {
         self.to_compose().to_dyn()
     }
@@ -44,6 +100,10 @@ impl<B: Bundle + Clone, BE: BundleExtension<B>> ModifyFunctions<PhantomData<B>>
         self.to_compose().keyed(key)
     }
 
+    fn scope_key(self, key: impl Into<String>) -> ScopeKeyed {
+        self.to_compose().scope_key(key)
+    }
+
     fn observe<E: Event, B2: Bundle, M>(
         self,
         observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
@@ -58,13 +118,137 @@ impl<B: Bundle + Clone, BE: BundleExtension<B>> ModifyFunctions<PhantomData<B>>
         self.to_compose().observe_retained(observer)
     }
 
+    fn observe_global<E: Event, B2: Bundle, M>(
+        self,
+        observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
+    ) -> Spawn<B> {
+        self.to_compose().observe_global(observer)
+    }
+
+    fn observe_global_retained<E: Event, B2: Bundle, M>(
+        self,
+        observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
+    ) -> Spawn<B> {
+        self.to_compose().observe_global_retained(observer)
+    }
+
+    fn observe_target<E: Event, B2: Bundle, M>(
+        self,
+        target: Entity,
+        observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
+    ) -> Spawn<B> {
+        self.to_compose().observe_target(target, observer)
+    }
+
+    fn observe_target_retained<E: Event, B2: Bundle, M>(
+        self,
+        target: Entity,
+        observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
+    ) -> Spawn<B> {
+        self.to_compose().observe_target_retained(target, observer)
+    }
+
     fn bind_hover(self, hover_state: impl GetStateId<bool>) -> Spawn<B> {
         self.to_compose().bind_hover(hover_state)
     }
 
+    fn observe_many<B2: Bundle, M>(
+        self,
+        kinds: impl IntoIterator<Item = PointerEventKind>,
+        observer: impl IntoObserverSystem<PointerEvent, B2, M> + Clone + Sync,
+    ) -> Spawn<B> {
+        self.to_compose().observe_many(kinds, observer)
+    }
+
+    fn observe_many_retained<B2: Bundle, M>(
+        self,
+        kinds: impl IntoIterator<Item = PointerEventKind>,
+        observer: impl IntoObserverSystem<PointerEvent, B2, M> + Clone + Sync,
+    ) -> Spawn<B> {
+        self.to_compose().observe_many_retained(kinds, observer)
+    }
+
+    fn bind_pressed(self, pressed_state: impl GetStateId<bool>) -> Spawn<B> {
+        self.to_compose().bind_pressed(pressed_state)
+    }
+
+    fn on_double_click(
+        self,
+        handler: impl Fn(&mut SetState) + Send + Sync + 'static,
+    ) -> Spawn<B> {
+        self.to_compose().on_double_click(handler)
+    }
+
+    fn on_long_press(
+        self,
+        duration: Duration,
+        handler: impl Fn(&mut SetState) + Send + Sync + 'static,
+    ) -> Spawn<B> {
+        self.to_compose().on_long_press(duration, handler)
+    }
+
+    fn on_drag(
+        self,
+        handler: impl Fn(DragGesture, &mut SetState) + Send + Sync + 'static,
+    ) -> Spawn<B> {
+        self.to_compose().on_drag(handler)
+    }
+
+    fn draggable<P: Any + Send + Sync>(self, payload: P) -> Spawn<B> {
+        self.to_compose().draggable(payload)
+    }
+
+    fn drop_target<P: Any + Send + Sync>(
+        self,
+        handler: impl Fn(&P, &mut SetState) + Send + Sync + 'static,
+    ) -> Spawn<B> {
+        self.to_compose().drop_target(handler)
+    }
+
+    fn tooltip(self, tooltip: impl Into<Tooltip>) -> Spawn<B> {
+        self.to_compose().tooltip(tooltip)
+    }
+
+    fn bind_scroll(self, scroll_state: impl GetStateId<Vec2>) -> Spawn<B> {
+        self.to_compose().bind_scroll(scroll_state)
+    }
+
+    fn bind_layout(self, layout_state: impl GetStateId<Option<NodeLayout>>) -> Spawn<B> {
+        self.to_compose().bind_layout(layout_state)
+    }
+
+    fn focusable(self) -> Spawn<B> {
+        self.to_compose().focusable()
+    }
+
+    fn bind_interaction(self, interaction_state: impl GetStateId<InteractionState>) -> Spawn<B> {
+        self.to_compose().bind_interaction(interaction_state)
+    }
+
+    fn selectable<K: Clone + PartialEq + Send + Sync + 'static>(
+        self,
+        key: K,
+        selection: impl GetStateId<Selection<K>>,
+    ) -> Spawn<B> {
+        self.to_compose().selectable(key, selection)
+    }
+
+    fn disabled(self, disabled: bool) -> Spawn<B> {
+        self.to_compose().disabled(disabled)
+    }
+
     fn use_modifier(self, modifier: &Modifier) -> Self::Target {
         self.to_compose().use_modifier(modifier)
     }
+
+    fn use_modifier_with(
+        self,
+        modifier: &Modifier,
+        children_policy: ChildrenPolicy,
+    ) -> Self::Target {
+        self.to_compose()
+            .use_modifier_with(modifier, children_policy)
+    }
 }
 
 impl<B: Bundle + Clone> BundleExtension<B> for B {
@@ -0,0 +1,141 @@
+use crate::{
+    binding::{BindValue, Binding, ChangeHandler},
+    bundle_extension::BundleExtension,
+    modify::{Modifier, Modify, ModifyFunctions},
+    state::SetState,
+    Compose, Scope,
+};
+use bevy_color::{Color, Luminance, Srgba};
+use bevy_ecs::observer::Trigger;
+use bevy_picking::events::{Click, Pointer};
+use bevy_ui::{
+    AlignItems, BackgroundColor, BorderColor, BorderRadius, JustifyContent, Node, UiRect, Val,
+};
+use std::sync::Arc;
+
+const BOX_SIZE: f32 = 18.0;
+const CHECK_SIZE: f32 = 8.0;
+
+/// A composable that toggles a boolean value when clicked.
+///
+/// The value is controlled by the parent: it is passed in with [`new`](Checkbox::new), and edits are reported through
+/// [`on_toggle`](Checkbox::on_toggle). Alternatively, the value can be bound to a state with
+/// [`bind_value`](BindValue::bind_value).
+///
+/// # Example
+/// ```ignore
+/// let is_enabled = cx.use_state(true);
+///
+/// Checkbox::new(*is_enabled).bind_value(&is_enabled)
+/// ```
+#[derive(Clone)]
+pub struct Checkbox {
+    checked: bool,
+    on_toggle: Option<ChangeHandler<bool>>,
+    binding: Option<Binding<bool>>,
+    modifier: Modifier,
+}
+
+impl Checkbox {
+    /// Creates a new checkbox with the given checked state.
+    pub fn new(checked: bool) -> Self {
+        Self {
+            checked,
+            on_toggle: None,
+            binding: None,
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Sets the function that is called with the new checked state when the checkbox is clicked.
+    pub fn on_toggle(
+        mut self,
+        on_toggle: impl Fn(bool, &mut SetState) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_toggle = Some(Arc::new(on_toggle));
+        self
+    }
+}
+
+impl BindValue<bool> for Checkbox {
+    fn binding(&mut self) -> &mut Option<Binding<bool>> {
+        &mut self.binding
+    }
+}
+
+impl Modify for Checkbox {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for Checkbox {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let checked = cx.use_binding(self.binding.as_ref(), self.checked);
+        let is_hovered = cx.use_state(false);
+        let is_pressed = cx.use_state(false);
+
+        let setter = checked.setter();
+        let is_checked = *checked;
+        let on_toggle = self.on_toggle.clone();
+
+        let accent = Srgba::rgb(0.25, 0.5, 0.95);
+
+        let background = match (is_checked, *is_pressed, *is_hovered) {
+            (true, true, _) => accent.darker(0.1),
+            (true, false, true) => accent.lighter(0.05),
+            (true, false, false) => accent,
+            (false, true, _) => Srgba::rgb(0.3, 0.3, 0.3),
+            (false, false, true) => Srgba::rgb(0.2, 0.2, 0.2),
+            (false, false, false) => Srgba::NONE,
+        };
+
+        let check = (
+            Node {
+                width: Val::Px(CHECK_SIZE),
+                height: Val::Px(CHECK_SIZE),
+                ..Default::default()
+            },
+            BackgroundColor(Color::WHITE),
+            BorderRadius::all(Val::Px(2.0)),
+        )
+            .to_compose()
+            .some_if(is_checked);
+
+        (
+            Node {
+                width: Val::Px(BOX_SIZE),
+                height: Val::Px(BOX_SIZE),
+                border: UiRect::all(Val::Px(2.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(background.into()),
+            BorderColor(
+                if is_checked {
+                    accent
+                } else {
+                    Srgba::rgb(0.5, 0.5, 0.5)
+                }
+                .into(),
+            ),
+            BorderRadius::all(Val::Px(4.0)),
+        )
+            .children(check)
+            .bind_hover(&is_hovered)
+            .bind_pressed(&is_pressed)
+            .observe(move |_: Trigger<Pointer<Click>>, mut state: SetState| {
+                setter.set(&mut state, !is_checked);
+
+                if let Some(on_toggle) = &on_toggle {
+                    on_toggle(!is_checked, &mut state);
+                }
+            })
+            .use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("Checkbox")
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    diagnostics::RecomposeCounters,
+    settings::FrameSettings,
+    state::{GetStateId, StateId, TypedStateId},
+    Root, Scope,
+};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::Arc,
+};
+
+/// The values that a [`Root`] provides to all composables of its tree. It is shared by all scopes of the tree, and by
+/// the trees of the [islands](crate::island::Island) in it.
+#[derive(Clone, Default)]
+pub(crate) struct RootContext {
+    /// The context values, by their type. See [`Root::with_context`].
+    values: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+    /// The initial values of states with manual ids. See [`Root::with_state`].
+    states: HashMap<StateId, Arc<dyn Any + Send + Sync>>,
+    /// The settings of the current frame. See [`FrameSettings`].
+    pub(crate) settings: Arc<FrameSettings>,
+    /// The recompositions of the scopes, for the diagnostics. See [`RecomposeCounters`].
+    pub(crate) counters: Arc<RecomposeCounters>,
+}
+
+impl RootContext {
+    /// Returns the initial value of the state with the given id, if the root presets it with a value of the same type.
+    pub(crate) fn preset_state<T: Any + Send + Sync>(
+        &self,
+        state_id: StateId,
+    ) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.states
+            .get(&state_id)
+            .filter(|value| value.is::<T>())
+            .cloned()
+    }
+}
+
+impl Root {
+    /// Provides the given value to all composables of the root, which read it with
+    /// [`use_context`](Scope::use_context). This makes it possible to share the same composable between roots that are
+    /// configured differently, like a theme or the player that a HUD belongs to. Providing a second value of the same
+    /// type replaces the first.
+    ///
+    /// The context is fixed once the root is spawned. Values that change over time should be shared through a
+    /// [`Store`](crate::store::Store) instead.
+    ///
+    /// # Example
+    /// ```ignore
+    /// commands.spawn((Root::new(hud).with_context(Player(1)), Node::default()));
+    /// commands.spawn((Root::new(hud).with_context(Player(2)), Node::default()));
+    ///
+    /// fn hud(cx: &mut Scope) -> impl Compose {
+    ///     let player = cx.use_context::<Player>().expect("Player not provided.");
+    ///     Text::new(format!("Player {}", player.0))
+    /// }
+    /// ```
+    pub fn with_context<T: Any + Send + Sync>(mut self, value: T) -> Self {
+        Arc::make_mut(&mut self.context)
+            .values
+            .insert(TypeId::of::<T>(), Arc::new(value));
+        self
+    }
+
+    /// Sets the initial value of the state with the given id. The composables of the root that create the state with
+    /// [`use_state_with_id`](Scope::use_state_with_id) start with this value instead of their own initial value.
+    ///
+    /// # Example
+    /// ```ignore
+    /// commands.spawn(Root::new(timer).with_state(ELAPSED_ID, 30.0));
+    /// ```
+    pub fn with_state<T: Any + Send + Sync>(mut self, state_id: TypedStateId<T>, value: T) -> Self {
+        Arc::make_mut(&mut self.context)
+            .states
+            .insert(state_id.get_id(), Arc::new(value));
+        self
+    }
+}
+
+impl Scope<'_> {
+    /// Returns the context value of the given type, which was provided to the root with
+    /// [`with_context`](Root::with_context), or `None` if the root doesn't provide one.
+    pub fn use_context<T: Any + Send + Sync>(&self) -> Option<Arc<T>> {
+        self.context
+            .values
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .and_then(|value| value.downcast::<T>().ok())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    dyn_compose::DynCompose,
+    modify::{ChildrenPolicy, Modifier, Modify, ModifyFunctions},
+    tooltip::TOOLTIP_Z_INDEX,
+    Compose, Scope,
+};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::With,
+    system::{Commands, Query},
+};
+use bevy_hierarchy::{Children, HierarchyQueryExt};
+use bevy_math::Vec2;
+use bevy_picking::{
+    pointer::{PointerId, PointerLocation},
+    PickingBehavior,
+};
+use bevy_ui::{Display, GlobalZIndex, Node, PositionType, Val};
+
+/// The global z-index of cursor attachments, which places them above all other UI, including modals and tooltips.
+pub const CURSOR_Z_INDEX: i32 = TOOLTIP_Z_INDEX + 1;
+
+/// A composable that renders its content next to the pointer and follows it around, like a custom cursor, the ghost of
+/// a dragged item or the preview of a brush.
+///
+/// The content is placed on an overlay above all other UI, and is moved by a system rather than by recomposing, so
+/// following the pointer is cheap. The content is not pickable, which means that it never blocks the pointer from
+/// interacting with the UI below it. It is hidden while the pointer is outside of the window, and removed when the
+/// composable is decomposed.
+///
+/// # Example
+/// ```ignore
+/// let dragged_item = cx.use_state(None::<String>);
+///
+/// (*dragged_item)
+///     .clone()
+///     .map(|item| CursorAttachment::new(TextLabel::new(item)).offset(Vec2::new(12.0, 12.0)))
+/// ```
+#[derive(Clone)]
+pub struct CursorAttachment {
+    content: DynCompose,
+    offset: Vec2,
+    pointer_id: PointerId,
+    modifier: Modifier,
+}
+
+impl CursorAttachment {
+    /// Creates a new cursor attachment with the given content.
+    pub fn new(content: impl Compose + 'static) -> Self {
+        Self {
+            content: DynCompose::new(content),
+            offset: Vec2::ZERO,
+            pointer_id: PointerId::Mouse,
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Sets the offset of the top left corner of the content from the pointer, in logical pixels.
+    pub fn offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the pointer that the content follows. Defaults to the mouse.
+    pub fn pointer(mut self, pointer_id: PointerId) -> Self {
+        self.pointer_id = pointer_id;
+        self
+    }
+}
+
+impl Modify for CursorAttachment {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+/// Makes the node follow the given pointer.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct FollowPointer {
+    pub(crate) pointer_id: PointerId,
+    pub(crate) offset: Vec2,
+}
+
+impl Compose for CursorAttachment {
+    fn compose<'a>(&self, _: &mut Scope) -> impl Compose + 'a {
+        (
+            Node {
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            },
+            GlobalZIndex(CURSOR_Z_INDEX),
+            PickingBehavior::IGNORE,
+            IgnorePickingTree,
+            FollowPointer {
+                pointer_id: self.pointer_id,
+                offset: self.offset,
+            },
+        )
+            .children(self.content.clone())
+            .detached()
+            .use_modifier_with(&self.modifier, ChildrenPolicy::Append)
+    }
+
+    fn name(&self) -> String {
+        String::from("CursorAttachment")
+    }
+}
+
+pub(crate) fn follow_pointers(
+    pointers: Query<(&PointerId, &PointerLocation)>,
+    mut followers: Query<(&FollowPointer, &mut Node)>,
+) {
+    for (follower, mut node) in followers.iter_mut() {
+        let position = pointers
+            .iter()
+            .find(|(pointer_id, _)| **pointer_id == follower.pointer_id)
+            .and_then(|(_, pointer_location)| pointer_location.location.as_ref())
+            .map(|location| location.position + follower.offset);
+
+        let (display, left, top) = match position {
+            Some(position) => (Display::Flex, Val::Px(position.x), Val::Px(position.y)),
+            None => (Display::None, node.left, node.top),
+        };
+
+        // Assigning unconditionally would mark the node as changed every frame, which makes the layout recompute.
+        if node.display != display || node.left != left || node.top != top {
+            node.display = display;
+            node.left = left;
+            node.top = top;
+        }
+    }
+}
+
+/// Makes the descendants of the entity unpickable, so that overlays like cursor attachments and tooltips never block the
+/// pointer.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct IgnorePickingTree;
+
+pub(crate) fn ignore_picking_trees(
+    mut commands: Commands,
+    trees: Query<Entity, With<IgnorePickingTree>>,
+    children: Query<&Children>,
+    picking_behaviors: Query<&PickingBehavior>,
+) {
+    for tree in trees.iter() {
+        for descendant in children.iter_descendants(tree) {
+            if picking_behaviors.get(descendant) == Ok(&PickingBehavior::IGNORE) {
+                continue;
+            }
+
+            let Some(mut ec) = commands.get_entity(descendant) else {
+                continue;
+            };
+
+            ec.try_insert(PickingBehavior::IGNORE);
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{
+    initial_compose, order_children, recompose, spawn::update_spawn_composables, AnyCompose, Root,
+};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, DiagnosticsStore, RegisterDiagnostic};
+use bevy_ecs::{
+    schedule::IntoSystemConfigs,
+    system::{Query, Res, ResMut, Resource},
+};
+use bevy_utils::Instant;
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+/// The prefix of the per-composable diagnostics. The name of the composable is appended to it, e.g.
+/// `recompose/composables/Button`.
+const COMPOSABLES_PREFIX: &str = "recompose/composables";
+
+/// Counts the (re)compositions of the scopes of a root. The counters live in the context of the root, so the roots,
+/// which are recomposed in parallel, don't contend for them, and separate apps don't share them. They are only enabled
+/// by the [`RecomposeDiagnosticsPlugin`], and collected into the [`RecomposeCounts`] after every recomposition.
+#[derive(Default, Debug)]
+pub(crate) struct RecomposeCounters {
+    is_enabled: AtomicBool,
+    per_composable: AtomicBool,
+    scopes: AtomicUsize,
+    composables: Mutex<HashMap<String, usize>>,
+}
+
+impl RecomposeCounters {
+    /// Records that a scope was (re)composed. Called for every scope, so this is kept as cheap as possible when the
+    /// diagnostics are disabled.
+    pub(crate) fn record(&self, compose: &dyn AnyCompose) {
+        if !self.is_enabled.load(Ordering::Relaxed) {
+            return;
+        }
+
+        self.scopes.fetch_add(1, Ordering::Relaxed);
+
+        if !self.per_composable.load(Ordering::Relaxed) {
+            return;
+        }
+
+        *self
+            .composables
+            .lock()
+            .unwrap()
+            .entry(compose.get_name())
+            .or_default() += 1;
+    }
+}
+
+/// Adds diagnostics about the recomposition to Bevy's [`DiagnosticsStore`]:
+///
+/// - [`RECOMPOSED_SCOPES`](Self::RECOMPOSED_SCOPES): the number of scopes (re)composed in a frame.
+/// - [`RECOMPOSE_TIME`](Self::RECOMPOSE_TIME): the time spent recomposing scopes, in milliseconds.
+/// - [`SPAWN_UPDATE_TIME`](Self::SPAWN_UPDATE_TIME): the time spent updating the spawned entities, in milliseconds.
+/// - [`TREE_DEPTH`](Self::TREE_DEPTH): the depth of the deepest scope tree.
+/// - [`SCOPE_COUNT`](Self::SCOPE_COUNT): the total number of scopes of all roots.
+///
+/// When `per_composable` is enabled, the number of recompositions per frame is also recorded for every composable
+/// name, under `recompose/composables/<name>`. This requires calling [`Compose::name`](crate::Compose::name) for every
+/// recomposed scope, which is why it's disabled by default.
+#[derive(Default)]
+pub struct RecomposeDiagnosticsPlugin {
+    pub per_composable: bool,
+}
+
+impl RecomposeDiagnosticsPlugin {
+    pub const RECOMPOSED_SCOPES: DiagnosticPath =
+        DiagnosticPath::const_new("recompose/recomposed_scopes");
+    pub const RECOMPOSE_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("recompose/recompose_time");
+    pub const SPAWN_UPDATE_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("recompose/spawn_update_time");
+    pub const TREE_DEPTH: DiagnosticPath = DiagnosticPath::const_new("recompose/tree_depth");
+    pub const SCOPE_COUNT: DiagnosticPath = DiagnosticPath::const_new("recompose/scope_count");
+
+    /// Returns the path of the diagnostic that records the recompositions of the composable with the given name.
+    pub fn composable_path(name: &str) -> DiagnosticPath {
+        DiagnosticPath::new(format!("{COMPOSABLES_PREFIX}/{name}"))
+    }
+}
+
+impl Plugin for RecomposeDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RecomposeTimings>()
+            .insert_resource(RecomposeCounts {
+                per_composable: self.per_composable,
+                ..Default::default()
+            })
+            .register_diagnostic(Diagnostic::new(Self::RECOMPOSED_SCOPES))
+            .register_diagnostic(Diagnostic::new(Self::RECOMPOSE_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::SPAWN_UPDATE_TIME).with_suffix("ms"))
+            .register_diagnostic(Diagnostic::new(Self::TREE_DEPTH))
+            .register_diagnostic(Diagnostic::new(Self::SCOPE_COUNT))
+            .add_systems(
+                PreUpdate,
+                (
+                    start_recompose_timer.before(recompose),
+                    stop_recompose_timer
+                        .after(recompose)
+                        .before(update_spawn_composables),
+                    stop_spawn_update_timer
+                        .after(update_spawn_composables)
+                        .before(order_children),
+                ),
+            )
+            .add_systems(PreUpdate, enable_recompose_counters.before(initial_compose))
+            .add_systems(
+                PreUpdate,
+                (
+                    collect_recompose_counts,
+                    register_composable_diagnostics,
+                    record_diagnostics,
+                )
+                    .chain()
+                    .after(stop_spawn_update_timer),
+            );
+    }
+}
+
+/// The (re)compositions of all roots since the diagnostics were last recorded.
+#[derive(Resource, Default)]
+struct RecomposeCounts {
+    per_composable: bool,
+    scopes: usize,
+    composables: HashMap<String, usize>,
+}
+
+// The counters are enabled for every root at the start of the frame, which covers the roots that were spawned since.
+fn enable_recompose_counters(counts: Res<RecomposeCounts>, roots: Query<&Root>) {
+    for root in roots.iter() {
+        let counters = &root.context.counters;
+        counters.is_enabled.store(true, Ordering::Relaxed);
+        counters
+            .per_composable
+            .store(counts.per_composable, Ordering::Relaxed);
+    }
+}
+
+fn collect_recompose_counts(mut counts: ResMut<RecomposeCounts>, roots: Query<&Root>) {
+    // Islands share the counters of their root, which is fine, since the counters are reset as they are collected.
+    for root in roots.iter() {
+        let counters = &root.context.counters;
+        counts.scopes += counters.scopes.swap(0, Ordering::Relaxed);
+
+        for (name, count) in counters.composables.lock().unwrap().drain() {
+            *counts.composables.entry(name).or_default() += count;
+        }
+    }
+}
+
+#[derive(Resource, Default)]
+struct RecomposeTimings {
+    started: Option<Instant>,
+    recompose: Duration,
+    spawn_update: Duration,
+}
+
+fn start_recompose_timer(mut timings: ResMut<RecomposeTimings>) {
+    timings.started = Some(Instant::now());
+}
+
+fn stop_recompose_timer(mut timings: ResMut<RecomposeTimings>) {
+    let now = Instant::now();
+    timings.recompose = timings
+        .started
+        .map(|started| now - started)
+        .unwrap_or_default();
+    timings.started = Some(now);
+}
+
+fn stop_spawn_update_timer(mut timings: ResMut<RecomposeTimings>) {
+    timings.spawn_update = timings
+        .started
+        .take()
+        .map(|started| started.elapsed())
+        .unwrap_or_default();
+}
+
+// Composable names are only known at runtime, so their diagnostics are registered as they are first recomposed.
+fn register_composable_diagnostics(
+    counts: Res<RecomposeCounts>,
+    mut store: ResMut<DiagnosticsStore>,
+) {
+    for name in counts.composables.keys() {
+        let path = RecomposeDiagnosticsPlugin::composable_path(name);
+
+        if store.get(&path).is_none() {
+            store.add(Diagnostic::new(path));
+        }
+    }
+}
+
+fn record_diagnostics(
+    mut diagnostics: Diagnostics,
+    timings: Res<RecomposeTimings>,
+    mut counts: ResMut<RecomposeCounts>,
+    roots: Query<&Root>,
+) {
+    let recomposed_scopes = std::mem::take(&mut counts.scopes);
+
+    diagnostics.add_measurement(&RecomposeDiagnosticsPlugin::RECOMPOSED_SCOPES, || {
+        recomposed_scopes as f64
+    });
+    diagnostics.add_measurement(&RecomposeDiagnosticsPlugin::RECOMPOSE_TIME, || {
+        timings.recompose.as_secs_f64() * 1000.0
+    });
+    diagnostics.add_measurement(&RecomposeDiagnosticsPlugin::SPAWN_UPDATE_TIME, || {
+        timings.spawn_update.as_secs_f64() * 1000.0
+    });
+    diagnostics.add_measurement(&RecomposeDiagnosticsPlugin::TREE_DEPTH, || {
+        roots.iter().map(Root::tree_depth).max().unwrap_or_default() as f64
+    });
+    diagnostics.add_measurement(&RecomposeDiagnosticsPlugin::SCOPE_COUNT, || {
+        roots.iter().map(Root::scope_count).sum::<usize>() as f64
+    });
+
+    // The names are kept around with a count of zero, so that frames in which a composable isn't recomposed are
+    // recorded as well.
+    for (name, count) in counts.composables.iter_mut() {
+        let recomposed = std::mem::take(count);
+        diagnostics.add_measurement(&RecomposeDiagnosticsPlugin::composable_path(name), || {
+            recomposed as f64
+        });
+    }
+}
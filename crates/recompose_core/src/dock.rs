@@ -0,0 +1,232 @@
+use crate::{
+    dyn_compose::DynCompose,
+    label::TextLabel,
+    modify::{Modifier, Modify, ModifyFunctions},
+    state::SetState,
+    Compose, Scope,
+};
+use bevy_color::Color;
+use bevy_ecs::{component::Component, observer::Trigger, system::Query};
+use bevy_hierarchy::{HierarchyQueryExt, Parent};
+use bevy_picking::events::{Click, DragDrop, Pointer};
+use bevy_ui::{BackgroundColor, FlexDirection, Node, Overflow, UiRect, Val};
+use std::fmt::Display;
+
+/// A tab of a [`DockArea`]. Tabs are identified by their title, so the titles of the tabs in a dock area should be
+/// unique.
+#[derive(Clone)]
+pub struct DockTab {
+    title: String,
+    content: DynCompose,
+}
+
+impl DockTab {
+    /// Creates a new tab with the given title and content.
+    pub fn new(title: impl Display, content: impl Compose + 'static) -> Self {
+        Self {
+            title: title.to_string(),
+            content: DynCompose::new(content),
+        }
+    }
+}
+
+/// Marks the entity of a tab in the tab bar, so that the tab can be found when it's dropped onto another pane.
+#[derive(Component, Clone)]
+struct DockTabTitle(String);
+
+/// A composable that lays out panes of tabs next to each other. Tabs can be dragged from one pane to another by dropping
+/// them anywhere on the target pane. The arrangement of the tabs is kept in a state, so it persists when the dock area
+/// is recomposed.
+///
+/// # Example
+/// ```ignore
+/// DockArea::new()
+///     .pane([DockTab::new("Hierarchy", hierarchy)])
+///     .pane([DockTab::new("Inspector", inspector), DockTab::new("Console", console)])
+/// ```
+#[derive(Clone, Default)]
+pub struct DockArea {
+    panes: Vec<Vec<DockTab>>,
+    modifier: Modifier,
+}
+
+impl DockArea {
+    /// Creates a new, empty dock area.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a pane with the given tabs to the dock area.
+    pub fn pane(mut self, tabs: impl IntoIterator<Item = DockTab>) -> Self {
+        self.panes.push(tabs.into_iter().collect());
+        self
+    }
+}
+
+impl Modify for DockArea {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for DockArea {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let initial_layout = self
+            .panes
+            .iter()
+            .map(|tabs| tabs.iter().map(|tab| tab.title.clone()).collect())
+            .collect::<Vec<Vec<String>>>();
+
+        let layout = cx.use_widget_state(initial_layout);
+        let active_tabs = cx.use_widget_state(vec![None::<String>; self.panes.len()]);
+        let layout_direction = cx.use_layout_direction();
+
+        let layout_id = layout.get_typed_id();
+        let active_tabs_id = active_tabs.get_typed_id();
+
+        let tabs = self.panes.iter().flatten().collect::<Vec<_>>();
+
+        // Tabs that were added to the dock area since the layout was created are placed in the pane they were added to.
+        let mut current_layout = (*layout).clone();
+        current_layout.resize(current_layout.len().max(self.panes.len()), vec![]);
+
+        for (pane_index, pane_tabs) in self.panes.iter().enumerate() {
+            for tab in pane_tabs {
+                if !current_layout
+                    .iter()
+                    .flatten()
+                    .any(|title| *title == tab.title)
+                {
+                    current_layout[pane_index].push(tab.title.clone());
+                }
+            }
+        }
+
+        let pane_count = current_layout.len();
+
+        let panes = current_layout
+            .iter()
+            .enumerate()
+            .map(|(pane_index, titles)| {
+                // Tabs that were removed from the dock area since the layout was created are skipped.
+                let pane_tabs = titles
+                    .iter()
+                    .filter_map(|title| tabs.iter().find(|tab| tab.title == *title))
+                    .collect::<Vec<_>>();
+
+                let active_title = active_tabs.get(pane_index).cloned().flatten();
+                let active_tab = pane_tabs
+                    .iter()
+                    .find(|tab| Some(&tab.title) == active_title.as_ref())
+                    .or(pane_tabs.first());
+
+                let tab_bar = pane_tabs
+                    .iter()
+                    .map(|tab| {
+                        let is_active = Some(&tab.title) == active_tab.map(|tab| &tab.title);
+                        let title = tab.title.clone();
+
+                        (
+                            Node {
+                                padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                                ..Default::default()
+                            },
+                            BackgroundColor(if is_active {
+                                Color::srgb(0.25, 0.25, 0.25)
+                            } else {
+                                Color::srgb(0.15, 0.15, 0.15)
+                            }),
+                            DockTabTitle(tab.title.clone()),
+                        )
+                            .children(TextLabel::new(&tab.title).font_size(14.0))
+                            .observe(move |_: Trigger<Pointer<Click>>, mut state: SetState| {
+                                let title = title.clone();
+                                state.modify(active_tabs_id, move |active_tabs| {
+                                    let mut active_tabs = active_tabs.clone();
+                                    active_tabs.resize(pane_count, None);
+                                    active_tabs[pane_index] = Some(title.clone());
+                                    active_tabs
+                                });
+                            })
+                            .keyed(tab.title.clone())
+                    })
+                    .collect::<Vec<_>>();
+
+                let content = active_tab
+                    .map(|tab| tab.content.clone())
+                    .unwrap_or_default();
+
+                Node {
+                    flex_grow: 1.0,
+                    flex_basis: Val::Px(0.0),
+                    flex_direction: FlexDirection::Column,
+                    overflow: Overflow::clip(),
+                    ..Default::default()
+                }
+                .children((
+                    Node {
+                        flex_direction: layout_direction.row(),
+                        column_gap: Val::Px(2.0),
+                        ..Default::default()
+                    }
+                    .children(tab_bar),
+                    Node {
+                        flex_grow: 1.0,
+                        ..Default::default()
+                    }
+                    .children(content),
+                ))
+                .observe(
+                    move |trigger: Trigger<Pointer<DragDrop>>,
+                          dropped_tabs: Query<&DockTabTitle>,
+                          parents: Query<&Parent>,
+                          mut state: SetState| {
+                        // The tab may be dragged by its title text, so the dropped entity can be a child of the tab.
+                        let Some(DockTabTitle(title)) = std::iter::once(trigger.dropped)
+                            .chain(parents.iter_ancestors(trigger.dropped))
+                            .find_map(|entity| dropped_tabs.get(entity).ok())
+                        else {
+                            return;
+                        };
+
+                        let moved_title = title.clone();
+                        state.modify(layout_id, move |layout| {
+                            let mut layout = layout.clone();
+                            layout.resize(pane_count, vec![]);
+
+                            for titles in layout.iter_mut() {
+                                titles.retain(|title| *title != moved_title);
+                            }
+
+                            layout[pane_index].push(moved_title.clone());
+                            layout
+                        });
+
+                        let active_title = title.clone();
+                        state.modify(active_tabs_id, move |active_tabs| {
+                            let mut active_tabs = active_tabs.clone();
+                            active_tabs.resize(pane_count, None);
+                            active_tabs[pane_index] = Some(active_title.clone());
+                            active_tabs
+                        });
+                    },
+                )
+                .keyed(pane_index)
+            })
+            .collect::<Vec<_>>();
+
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: layout_direction.row(),
+            column_gap: Val::Px(4.0),
+            ..Default::default()
+        }
+        .children(panes)
+        .use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("DockArea")
+    }
+}
@@ -0,0 +1,240 @@
+use crate::{
+    cursor::CursorAttachment,
+    dyn_compose::DynCompose,
+    modify::{Modifier, Modify, ModifyFunctions},
+    pointer_events::{PointerEvent, PointerEventKind},
+    state::SetState,
+    store::Store,
+    Compose, Scope,
+};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    observer::Trigger,
+    system::{Query, Res, ResMut},
+};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_math::Vec2;
+use bevy_picking::events::{DragDrop, Pointer};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    sync::Arc,
+};
+
+pub(crate) type DragPayload = Arc<dyn Any + Send + Sync>;
+pub(crate) type DropHandler = Arc<dyn Fn(&DragPayload, &mut SetState) -> bool + Send + Sync>;
+
+/// Tracks the payload that is currently being dragged with [`draggable`](crate::modify::ModifyFunctions::draggable).
+/// The context is a [`Store`], so it can be read by systems with `Res<Store<DragContext>>`, and by composables with
+/// [`use_drag_payload`](Scope::use_drag_payload).
+#[derive(Clone, Default)]
+pub struct DragContext {
+    payload: Option<DragPayload>,
+    source: Option<Entity>,
+    /// The entity that the pointer originally pressed. Nested draggables all receive the drag start of the same press,
+    /// and only the innermost one, which receives it first, starts the drag.
+    origin: Option<Entity>,
+}
+
+impl DragContext {
+    /// Whether a payload is currently being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.payload.is_some()
+    }
+
+    /// Returns the dragged payload, if there is one and it is of type `T`.
+    pub fn payload<T: Any + Send + Sync>(&self) -> Option<&T> {
+        self.payload.as_ref()?.downcast_ref::<T>()
+    }
+
+    /// Returns the entity that is being dragged.
+    pub fn source(&self) -> Option<Entity> {
+        self.source
+    }
+
+    /// Cancels the current drag, so that the payload isn't dropped when the pointer is released.
+    pub fn cancel(&mut self) {
+        *self = DragContext::default();
+    }
+}
+
+/// The payload of a draggable entity. It is updated on every recomposition, so the payload is always up to date when a
+/// drag starts.
+#[derive(Component)]
+pub(crate) struct Draggable {
+    pub(crate) payload: DragPayload,
+}
+
+/// The handlers of a drop target, by the type of payload they accept.
+#[derive(Component, Default)]
+pub(crate) struct DropTarget {
+    pub(crate) handlers: HashMap<TypeId, DropHandler>,
+}
+
+pub(crate) fn track_drag(
+    trigger: Trigger<PointerEvent>,
+    draggables: Query<&Draggable>,
+    mut context: ResMut<Store<DragContext>>,
+) {
+    let entity = trigger.entity();
+
+    match trigger.kind {
+        PointerEventKind::DragStart => {
+            if context.is_dragging() && context.origin == Some(trigger.target) {
+                return;
+            }
+
+            let Ok(draggable) = draggables.get(entity) else {
+                return;
+            };
+
+            context.0 = DragContext {
+                payload: Some(draggable.payload.clone()),
+                source: Some(entity),
+                origin: Some(trigger.target),
+            };
+        }
+        PointerEventKind::DragEnd if context.source == Some(entity) => context.cancel(),
+        _ => {}
+    }
+}
+
+pub(crate) fn drop_payload(
+    mut trigger: Trigger<Pointer<DragDrop>>,
+    targets: Query<&DropTarget>,
+    context: Res<Store<DragContext>>,
+    mut state: SetState,
+) {
+    // Drags that were cancelled, or that didn't start on a draggable, have no payload to drop.
+    if context.source != Some(trigger.dropped) {
+        return;
+    }
+
+    let (Ok(target), Some(payload)) = (targets.get(trigger.entity()), &context.payload) else {
+        return;
+    };
+
+    let Some(handler) = target.handlers.get(&(**payload).type_id()) else {
+        return;
+    };
+
+    // Nested drop targets only receive the payload if none of their descendants accepted it.
+    if handler(payload, &mut state) {
+        trigger.propagate(false);
+    }
+}
+
+pub(crate) fn cancel_drag_and_drop_on_escape(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut context: ResMut<Store<DragContext>>,
+) {
+    let Some(keys) = keys else {
+        return;
+    };
+
+    if keys.just_pressed(KeyCode::Escape) && context.is_dragging() {
+        context.cancel();
+    }
+}
+
+/// Compares payloads by their identity, so that selecting them from the [`DragContext`] doesn't require them to
+/// implement `PartialEq`.
+struct SelectedPayload<T>(Arc<T>);
+
+impl<T> Clone for SelectedPayload<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for SelectedPayload<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Scope<'_> {
+    /// Returns the payload that is currently being dragged, if it is of type `T`. The composable is recomposed when a
+    /// drag starts or ends. This is useful for highlighting the drop targets that accept the dragged payload.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let dragged_item = cx.use_drag_payload::<Item>();
+    /// let is_highlighted = dragged_item.is_some_and(|item| item.fits(&slot));
+    /// ```
+    pub fn use_drag_payload<T: Any + Send + Sync>(&mut self) -> Option<Arc<T>> {
+        let payload = self.use_store_selector(|context: &DragContext| {
+            let payload = context.payload.clone()?.downcast::<T>().ok()?;
+            Some(SelectedPayload(payload))
+        });
+
+        (*payload).clone().flatten().map(|payload| payload.0)
+    }
+}
+
+/// A composable that renders a ghost next to the pointer while a payload of type `T` is being dragged. The ghost is
+/// composed with a [`CursorAttachment`], so it never blocks the pointer from reaching the drop targets. Only a single
+/// ghost should be composed for every type of payload, usually near the root of the UI.
+///
+/// # Example
+/// ```ignore
+/// (
+///     Inventory,
+///     DragGhost::new(|item: &Item| ItemIcon::new(item.icon.clone())).offset(Vec2::new(8.0, 8.0)),
+/// )
+/// ```
+pub struct DragGhost<T> {
+    render: Arc<dyn Fn(&T) -> DynCompose + Send + Sync>,
+    offset: Vec2,
+    modifier: Modifier,
+}
+
+impl<T: Any + Send + Sync> DragGhost<T> {
+    /// Creates a new ghost that composes the given function for the dragged payload.
+    pub fn new<C: Compose + 'static>(render: impl Fn(&T) -> C + Send + Sync + 'static) -> Self {
+        Self {
+            render: Arc::new(move |payload| DynCompose::new(render(payload))),
+            offset: Vec2::ZERO,
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Sets the offset of the top left corner of the ghost from the pointer, in logical pixels.
+    pub fn offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+impl<T> Clone for DragGhost<T> {
+    fn clone(&self) -> Self {
+        Self {
+            render: self.render.clone(),
+            offset: self.offset,
+            modifier: self.modifier.clone(),
+        }
+    }
+}
+
+impl<T: Any + Send + Sync> Modify for DragGhost<T> {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl<T: Any + Send + Sync> Compose for DragGhost<T> {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let payload = cx.use_drag_payload::<T>();
+
+        payload.map(|payload| {
+            CursorAttachment::new((self.render)(&payload))
+                .offset(self.offset)
+                .use_modifier(&self.modifier)
+        })
+    }
+
+    fn name(&self) -> String {
+        String::from("DragGhost")
+    }
+}
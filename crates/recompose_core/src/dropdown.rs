@@ -0,0 +1,227 @@
+use crate::{
+    binding::{BindValue, Binding, BoundSetter, ChangeHandler},
+    keyed::Keyed,
+    label::TextLabel,
+    modify::{Modifier, Modify, ModifyFunctions},
+    state::{SetState, TypedStateId},
+    Compose, Scope,
+};
+use bevy_color::{Color, Srgba};
+use bevy_ecs::observer::Trigger;
+use bevy_picking::events::{Click, Pointer};
+use bevy_ui::{
+    BackgroundColor, BorderRadius, FlexDirection, GlobalZIndex, Node, PositionType, UiRect, Val,
+};
+use std::{fmt::Display, sync::Arc};
+
+/// A composable that lets the user select one of a list of options from a popup list.
+///
+/// The selected index is controlled by the parent: it is passed in with [`selected`](Dropdown::selected), and edits
+/// are reported through [`on_select`](Dropdown::on_select). Alternatively, the selected index can be bound to a state
+/// with [`bind_value`](BindValue::bind_value).
+///
+/// # Example
+/// ```ignore
+/// let quality = cx.use_state(Some(1));
+///
+/// Dropdown::new(["Low", "Medium", "High"]).bind_value(&quality)
+/// ```
+#[derive(Clone)]
+pub struct Dropdown {
+    options: Vec<String>,
+    selected: Option<usize>,
+    placeholder: String,
+    on_select: Option<ChangeHandler<Option<usize>>>,
+    binding: Option<Binding<Option<usize>>>,
+    modifier: Modifier,
+}
+
+impl Dropdown {
+    /// Creates a new dropdown with the given options and nothing selected.
+    pub fn new<T: Display>(options: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            options: options
+                .into_iter()
+                .map(|option| option.to_string())
+                .collect(),
+            selected: None,
+            placeholder: String::from("Select..."),
+            on_select: None,
+            binding: None,
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Sets the index of the selected option.
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the text that is shown when no option is selected.
+    pub fn placeholder(mut self, placeholder: impl Display) -> Self {
+        self.placeholder = placeholder.to_string();
+        self
+    }
+
+    /// Sets the function that is called with the index of the new selected option when an option is selected.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(Option<usize>, &mut SetState) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_select = Some(Arc::new(on_select));
+        self
+    }
+}
+
+impl BindValue<Option<usize>> for Dropdown {
+    fn binding(&mut self) -> &mut Option<Binding<Option<usize>>> {
+        &mut self.binding
+    }
+}
+
+impl Modify for Dropdown {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for Dropdown {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let selected = cx.use_binding(self.binding.as_ref(), self.selected);
+        let is_open = cx.use_state(false);
+        let is_hovered = cx.use_state(false);
+        let direction = cx.use_layout_direction();
+
+        let is_open_id = is_open.get_typed_id();
+        let was_open = *is_open;
+
+        let header_text = selected
+            .and_then(|index| self.options.get(index))
+            .unwrap_or(&self.placeholder);
+
+        let header = (
+            Node {
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                ..Default::default()
+            },
+            BackgroundColor(if *is_hovered {
+                Srgba::rgb(0.3, 0.3, 0.3).into()
+            } else {
+                Srgba::rgb(0.2, 0.2, 0.2).into()
+            }),
+            BorderRadius::all(Val::Px(4.0)),
+        )
+            .children(TextLabel::new(header_text).font_size(14.0))
+            .bind_hover(&is_hovered)
+            .observe(move |_: Trigger<Pointer<Click>>, mut state: SetState| {
+                state.set(is_open_id, !was_open);
+            });
+
+        let options = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| {
+                let option = DropdownOption {
+                    label: option.clone(),
+                    index,
+                    is_selected: *selected == Some(index),
+                    setter: selected.setter(),
+                    is_open: is_open_id,
+                    on_select: self.on_select.clone(),
+                };
+
+                Keyed::new(index, option)
+            })
+            .collect::<Vec<_>>();
+
+        let list = (
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Percent(100.0),
+                // The list opens towards the end of the line, so it is aligned with the start of the dropdown.
+                left: if direction.is_rtl() {
+                    Val::Auto
+                } else {
+                    Val::Px(0.0)
+                },
+                right: if direction.is_rtl() {
+                    Val::Px(0.0)
+                } else {
+                    Val::Auto
+                },
+                min_width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::vertical(Val::Px(4.0)),
+                ..Default::default()
+            },
+            BackgroundColor(Srgba::rgb(0.15, 0.15, 0.15).into()),
+            BorderRadius::all(Val::Px(4.0)),
+            // The list is rendered above the rest of the UI, since it overlaps the content below the dropdown.
+            GlobalZIndex(1),
+        )
+            .children(options)
+            .some_if(*is_open);
+
+        Node {
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        }
+        .children((header, list))
+        .use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("Dropdown")
+    }
+}
+
+#[derive(Clone)]
+struct DropdownOption {
+    label: String,
+    index: usize,
+    is_selected: bool,
+    setter: BoundSetter<Option<usize>>,
+    is_open: TypedStateId<bool>,
+    on_select: Option<ChangeHandler<Option<usize>>>,
+}
+
+impl Compose for DropdownOption {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let is_hovered = cx.use_state(false);
+
+        let index = self.index;
+        let setter = self.setter;
+        let is_open = self.is_open;
+        let on_select = self.on_select.clone();
+
+        let background = match (self.is_selected, *is_hovered) {
+            (_, true) => Srgba::rgb(0.3, 0.3, 0.3).into(),
+            (true, false) => Srgba::rgb(0.22, 0.22, 0.22).into(),
+            (false, false) => Color::NONE,
+        };
+
+        (
+            Node {
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+                ..Default::default()
+            },
+            BackgroundColor(background),
+        )
+            .children(TextLabel::new(&self.label).font_size(14.0))
+            .bind_hover(&is_hovered)
+            .observe(move |_: Trigger<Pointer<Click>>, mut state: SetState| {
+                setter.set(&mut state, Some(index));
+                state.set(is_open, false);
+
+                if let Some(on_select) = &on_select {
+                    on_select(Some(index), &mut state);
+                }
+            })
+    }
+
+    fn name(&self) -> String {
+        String::from("DropdownOption")
+    }
+}
@@ -1,4 +1,12 @@
-use crate::{AnyCompose, ChildIndex, Compose, Scope};
+use crate::{keyed::Keyed, AnyCompose, AnyKey, ChildIndex, Compose, Scope};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::Without,
+    system::{Commands, Query},
+};
+use bevy_render::view::Visibility;
+use bevy_ui::{Display, Node};
 use std::{any::Any, any::TypeId, sync::Arc};
 
 /// A dynamic composition structure that holds a type-erased composer. This allows for "dynamic dispatch" of the
@@ -9,6 +17,13 @@ pub struct DynCompose {
     /// decompose the previous scope and create a new one.
     type_id: TypeId,
     compose: Arc<dyn AnyCompose>,
+    /// The key of the composable, for the elements of a keyed `Vec`. It keeps the identity of the children intact when
+    /// they are mapped with [`map_children`](DynCompose::map_children).
+    key: Option<AnyKey>,
+    /// The stable key that is assigned to the scope of the composable. See [`ScopeKeyed`](crate::keyed::ScopeKeyed).
+    scope_key: Option<String>,
+    /// The number of inactive branches that are kept alive. See [`cached`](DynCompose::cached).
+    cache_size: usize,
 }
 
 impl Default for DynCompose {
@@ -16,6 +31,9 @@ impl Default for DynCompose {
         Self {
             type_id: TypeId::of::<()>(),
             compose: Arc::new(()),
+            key: None,
+            scope_key: None,
+            cache_size: 0,
         }
     }
 }
@@ -26,12 +44,102 @@ impl DynCompose {
         Self {
             type_id: compose.type_id(),
             compose: Arc::new(compose),
+            key: None,
+            scope_key: None,
+            cache_size: 0,
         }
     }
 
+    /// Keeps up to `cache_size` inactive branches alive when the type of the composable changes, instead of
+    /// decomposing them. Switching back to a cached branch restores it with its states, without running its mount
+    /// effects again. This is useful for tabs and pages that would otherwise lose their state on every switch.
+    ///
+    /// Inactive branches are neither recomposed nor updated, and their entities are hidden, using `Display::None` for
+    /// UI nodes and `Visibility::Hidden` otherwise. States that are set while a branch is inactive are applied once it
+    /// becomes active again. When more than `cache_size` branches are inactive, the least recently active branch is
+    /// decomposed.
+    ///
+    /// Branches are told apart by the type of their composable, so every page should be its own type or function.
+    ///
+    /// # Example
+    /// ```ignore
+    /// match *tab {
+    ///     Tab::Inventory => DynCompose::new(Inventory),
+    ///     Tab::Map => DynCompose::new(Map),
+    ///     Tab::Quests => DynCompose::new(Quests),
+    /// }
+    /// .cached(2)
+    /// ```
+    pub fn cached(mut self, cache_size: usize) -> Self {
+        self.cache_size = cache_size;
+        self
+    }
+
+    /// Creates a new `DynCompose` instance from a composer that is already type-erased.
+    pub(crate) fn from_shared(compose: Arc<dyn AnyCompose>) -> Self {
+        Self {
+            type_id: compose.as_any().type_id(),
+            compose,
+            key: None,
+            scope_key: None,
+            cache_size: 0,
+        }
+    }
+
+    pub(crate) fn with_key(mut self, key: AnyKey) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    pub(crate) fn with_scope_key(mut self, scope_key: String) -> Self {
+        self.scope_key = Some(scope_key);
+        self
+    }
+
     pub fn is_empty(&self) -> bool {
         self.type_id == TypeId::of::<()>()
     }
+
+    /// Returns the children that this composable consists of. Tuples and `Vec`s are split into their elements, empty
+    /// composables have no children, and all other composables are a single child.
+    pub fn children(&self) -> Vec<DynCompose> {
+        self.compose
+            .get_children_list()
+            .unwrap_or_else(|| vec![self.clone()])
+    }
+
+    /// Returns the number of children that this composable consists of. See [`children`](Self::children).
+    pub fn children_count(&self) -> usize {
+        self.children().len()
+    }
+
+    /// Maps each child of this composable to a new composable. This lets containers wrap each of the children they are
+    /// given, for example to add separators between them or to place them in a row of a list. The children of a `Vec`
+    /// keep their keys, and all other children are keyed by their index.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let children = self.modifier.map_children(|index, child| ((index > 0).then_some(Separator), child));
+    /// ```
+    pub fn map_children<C: Compose + 'static>(
+        &self,
+        map: impl Fn(usize, DynCompose) -> C,
+    ) -> DynCompose {
+        let children = self
+            .children()
+            .into_iter()
+            .enumerate()
+            .map(|(index, child)| {
+                let key = child
+                    .key
+                    .clone()
+                    .unwrap_or_else(|| AnyKey::from_value(index));
+                Keyed::new(key, map(index, child))
+            })
+            .collect::<Vec<_>>();
+
+        DynCompose::new(children)
+    }
 }
 
 impl Compose for DynCompose {
@@ -48,45 +156,242 @@ impl Compose for DynCompose {
             None => cx.child_index.clone(),
         };
 
-        let create_new_scope = |cx: &mut Scope| {
-            let mut scope = Scope::new(
-                self.compose.clone(),
-                0,
-                parent_entity,
-                parent_child_index.clone(),
-            );
-            self.compose.recompose_scope(&mut scope);
-            cx.children.push(scope);
-            cx.set_state(&type_id, self.type_id);
-        };
+        let inherited = cx.inherited_by_children();
 
-        if let Some(ref mut existing_scope) = cx.children.first_mut() {
-            if *type_id != self.type_id {
-                existing_scope.will_decompose = true;
-                create_new_scope(cx);
+        let active_index = cx
+            .children
+            .iter()
+            .position(|scope| !scope.will_decompose && !scope.is_inactive);
+
+        if let Some(index) = active_index {
+            if *type_id == self.type_id {
+                let existing_scope = &mut cx.children[index];
+                existing_scope.composer = self.compose.clone();
+                existing_scope.parent_entity = parent_entity;
+                existing_scope.child_index = parent_child_index.child(existing_scope.index);
+                existing_scope.inherited = inherited;
+                existing_scope.set_scope_key(self.scope_key.as_deref());
+                existing_scope
+                    .composer
+                    .clone()
+                    .recompose_scope(existing_scope);
                 return;
             }
 
-            existing_scope.composer = self.compose.clone();
-            existing_scope.parent_entity = parent_entity;
-            let mut child_index = parent_child_index.clone();
-            child_index.push(existing_scope.index);
-            existing_scope.child_index = child_index;
-            existing_scope
-                .composer
-                .clone()
-                .recompose_scope(existing_scope);
-            return;
+            match self.cache_size {
+                0 => cx.children[index].will_decompose = true,
+                _ => {
+                    // The most recently deactivated scopes are kept at the back, so that the oldest ones are evicted
+                    // first.
+                    let mut scope = cx.children.remove(index);
+                    scope.is_inactive = true;
+                    let entities = top_level_entities(&scope);
+                    cx.children.push(scope);
+                    cx.run_system(hide_entities(entities));
+                }
+            }
         }
 
-        create_new_scope(cx);
+        let cached_index = cx.children.iter().position(|scope| {
+            scope.is_inactive
+                && !scope.will_decompose
+                && scope.composer.as_any().type_id() == self.type_id
+        });
+
+        match cached_index {
+            Some(index) => {
+                let mut scope = cx.children.remove(index);
+                scope.is_inactive = false;
+                scope.composer = self.compose.clone();
+                scope.parent_entity = parent_entity;
+                scope.child_index = parent_child_index.child(scope.index);
+                scope.inherited = inherited;
+                scope.set_scope_key(self.scope_key.as_deref());
+                let entities = top_level_entities(&scope);
+                scope.composer.clone().recompose_scope(&mut scope);
+                cx.children.push(scope);
+                cx.run_system(show_entities(entities));
+            }
+            None => {
+                let mut scope = Scope::new(
+                    self.compose.clone(),
+                    0,
+                    parent_entity,
+                    parent_child_index.clone(),
+                    cx,
+                );
+                scope.set_scope_key(self.scope_key.as_deref());
+                self.compose.recompose_scope(&mut scope);
+                cx.children.push(scope);
+            }
+        }
+
+        cx.set_state(&type_id, self.type_id);
+
+        let mut inactive_scopes = cx
+            .children
+            .iter_mut()
+            .filter(|scope| scope.is_inactive && !scope.will_decompose)
+            .collect::<Vec<_>>();
+
+        let evicted_count = inactive_scopes.len().saturating_sub(self.cache_size);
+
+        for scope in inactive_scopes.iter_mut().take(evicted_count) {
+            scope.will_decompose = true;
+        }
     }
 
     fn ignore_children(&self) -> bool {
         true
     }
 
+    fn children_list(&self) -> Option<Vec<DynCompose>> {
+        self.compose.get_children_list()
+    }
+
     fn name(&self) -> String {
         String::from("DynCompose")
     }
 }
+
+/// A type-erased composable that is cheap to clone. It lets APIs accept any composable without being generic over it,
+/// and lets composables be built ahead of time, cached, and shared between scopes.
+///
+/// Unlike [`DynCompose`], a `BoxedCompose` is not recomposed when its parent recomposes with a clone of the same
+/// `BoxedCompose`, since it is known to be unchanged. Building a new `BoxedCompose` recomposes it.
+///
+/// # Example
+/// ```ignore
+/// pub struct Panel {
+///     title: String,
+///     content: BoxedCompose,
+/// }
+///
+/// impl Panel {
+///     pub fn new(title: impl Into<String>, content: impl Compose + 'static) -> Self {
+///         Self {
+///             title: title.into(),
+///             content: BoxedCompose::new(content),
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct BoxedCompose(Arc<dyn AnyCompose>);
+
+impl BoxedCompose {
+    /// Creates a new `BoxedCompose` from the given composable.
+    pub fn new(compose: impl Compose + 'static) -> Self {
+        Self(Arc::new(compose))
+    }
+}
+
+impl From<Arc<dyn AnyCompose>> for BoxedCompose {
+    fn from(compose: Arc<dyn AnyCompose>) -> Self {
+        Self(compose)
+    }
+}
+
+impl Compose for BoxedCompose {
+    fn compose<'a>(&self, _: &mut Scope) -> impl Compose + 'a {
+        DynCompose::from_shared(self.0.clone())
+    }
+
+    fn children_list(&self) -> Option<Vec<DynCompose>> {
+        self.0.get_children_list()
+    }
+
+    fn name(&self) -> String {
+        String::from("BoxedCompose")
+    }
+
+    fn is_unchanged(&self, previous: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &previous.0)
+    }
+}
+
+/// The display or visibility of an entity before it was hidden as part of an inactive branch of a cached `DynCompose`.
+#[derive(Component)]
+pub(crate) struct HiddenBranchEntity {
+    display: Option<Display>,
+    visibility: Option<Visibility>,
+}
+
+/// Returns the entities that are spawned by the scope or its closest descendants. The entities of deeper descendants
+/// are children of these, so they don't have to be hidden separately.
+fn top_level_entities(scope: &Scope) -> Vec<Entity> {
+    let mut entities = Vec::new();
+    let mut scopes = vec![scope];
+
+    while let Some(scope) = scopes.pop() {
+        match scope.entity {
+            Some(entity) => entities.push(entity),
+            None => scopes.extend(scope.children.iter()),
+        }
+    }
+
+    entities
+}
+
+type VisibleEntities<'w, 's> = Query<
+    'w,
+    's,
+    (Option<&'static mut Node>, Option<&'static mut Visibility>),
+    Without<HiddenBranchEntity>,
+>;
+
+type HiddenEntities<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static HiddenBranchEntity,
+        Option<&'static mut Node>,
+        Option<&'static mut Visibility>,
+    ),
+>;
+
+fn hide_entities(entities: Vec<Entity>) -> impl FnMut(Commands, VisibleEntities) {
+    move |mut commands, mut query| {
+        for entity in entities.iter() {
+            let Ok((node, visibility)) = query.get_mut(*entity) else {
+                continue;
+            };
+
+            let mut hidden = HiddenBranchEntity {
+                display: None,
+                visibility: None,
+            };
+
+            if let Some(mut node) = node {
+                hidden.display = Some(node.display);
+                node.display = Display::None;
+            } else if let Some(mut visibility) = visibility {
+                hidden.visibility = Some(*visibility);
+                *visibility = Visibility::Hidden;
+            }
+
+            commands.entity(*entity).insert(hidden);
+        }
+    }
+}
+
+fn show_entities(entities: Vec<Entity>) -> impl FnMut(Commands, HiddenEntities) {
+    move |mut commands, mut query| {
+        for entity in entities.iter() {
+            let Ok((hidden, node, visibility)) = query.get_mut(*entity) else {
+                continue;
+            };
+
+            if let (Some(display), Some(mut node)) = (hidden.display, node) {
+                node.display = display;
+            }
+
+            if let (Some(hidden_visibility), Some(mut visibility)) = (hidden.visibility, visibility)
+            {
+                *visibility = hidden_visibility;
+            }
+
+            commands.entity(*entity).remove::<HiddenBranchEntity>();
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    dyn_compose::DynCompose,
+    state::{SetState, TypedStateId},
+    Compose, Scope,
+};
+use bevy_log::error;
+use std::{error::Error, fmt::Display, sync::Arc};
+
+/// The id of the state that the errors of the descendants of an [`ErrorBoundary`] are reported to.
+pub(crate) type ErrorBoundaryId = TypedStateId<Option<CompositionError>>;
+
+/// An error that happened while composing. Composables surface errors by returning a `Result` from `compose`, which
+/// reports the error to the nearest [`ErrorBoundary`], see [`report_error`](Scope::report_error).
+///
+/// Any [`Error`] can be converted into a composition error, so errors can be propagated with `?` in compose functions
+/// that return `Result<impl Compose, CompositionError>`.
+#[derive(Clone, Debug)]
+pub struct CompositionError {
+    message: String,
+    source: Option<Arc<dyn Error + Send + Sync>>,
+}
+
+impl CompositionError {
+    /// Creates a new error with the given message.
+    pub fn new(message: impl Display) -> Self {
+        Self {
+            message: message.to_string(),
+            source: None,
+        }
+    }
+
+    /// Returns the message of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the error that the composition error was converted from, if there is one.
+    pub fn source(&self) -> Option<&(dyn Error + Send + Sync + 'static)> {
+        self.source.as_deref()
+    }
+}
+
+impl Display for CompositionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl<E: Error + Send + Sync + 'static> From<E> for CompositionError {
+    fn from(error: E) -> Self {
+        Self {
+            message: error.to_string(),
+            source: Some(Arc::new(error)),
+        }
+    }
+}
+
+impl Scope<'_> {
+    /// Reports an error to the nearest [`ErrorBoundary`], which replaces its content with its fallback. Without an error
+    /// boundary, the error is logged instead.
+    pub fn report_error(&mut self, error: impl Into<CompositionError>) {
+        let error = error.into();
+
+        let Some(error_boundary) = self.error_boundary else {
+            error!("{}: {error}", self.composer.get_name());
+            return;
+        };
+
+        self.run_system(move |mut state: SetState| {
+            state.set(error_boundary, Some(error.clone()));
+        });
+    }
+}
+
+type FallbackFn = Arc<dyn Fn(&CompositionError, ErrorReset) -> DynCompose + Send + Sync>;
+
+/// A composable that catches the errors of its descendants. When a descendant reports an error, for example by
+/// returning an `Err` from `compose`, the content is decomposed and the fallback is composed in its place, until the
+/// boundary is reset. Errors of the fallback are reported to the next error boundary up the tree.
+///
+/// # Example
+/// ```ignore
+/// fn level_info(cx: &mut Scope) -> Result<impl Compose, CompositionError> {
+///     let level = std::fs::read_to_string("level.txt")?;
+///     Ok(TextLabel::new(level))
+/// }
+///
+/// ErrorBoundary::new(level_info).fallback(|error, reset| {
+///     TextLabel::new(format!("Failed to load the level: {error}"))
+///         .observe(move |_: Trigger<Pointer<Click>>, mut state: SetState| reset.reset(&mut state))
+/// })
+/// ```
+#[derive(Clone)]
+pub struct ErrorBoundary {
+    content: DynCompose,
+    fallback: FallbackFn,
+}
+
+impl ErrorBoundary {
+    /// Creates a new error boundary around the given content. By default, nothing is composed when an error is caught.
+    pub fn new(content: impl Compose + 'static) -> Self {
+        Self {
+            content: DynCompose::new(content),
+            fallback: Arc::new(|_, _| DynCompose::new(())),
+        }
+    }
+
+    /// Sets the function that composes the fallback for the caught error.
+    pub fn fallback<C: Compose + 'static>(
+        mut self,
+        fallback: impl Fn(&CompositionError, ErrorReset) -> C + Send + Sync + 'static,
+    ) -> Self {
+        self.fallback = Arc::new(move |error, reset| DynCompose::new(fallback(error, reset)));
+        self
+    }
+}
+
+impl Compose for ErrorBoundary {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let error = cx.use_state(None::<CompositionError>);
+        let error_id = error.get_typed_id();
+
+        match &*error {
+            Some(error) => (self.fallback)(error, ErrorReset(error_id)),
+            None => DynCompose::new(ErrorBoundaryContent {
+                content: self.content.clone(),
+                error_boundary: error_id,
+            }),
+        }
+    }
+
+    fn name(&self) -> String {
+        String::from("ErrorBoundary")
+    }
+}
+
+/// A handle that resets an [`ErrorBoundary`], so that its content is composed again.
+#[derive(Clone, Copy)]
+pub struct ErrorReset(ErrorBoundaryId);
+
+impl ErrorReset {
+    /// Clears the caught error of the error boundary.
+    pub fn reset(&self, state: &mut SetState) {
+        state.set(self.0, None);
+    }
+}
+
+/// The content of an [`ErrorBoundary`]. The scopes below it report their errors to the boundary, while the fallback,
+/// which is composed next to it, reports to the next boundary up the tree.
+#[derive(Clone)]
+struct ErrorBoundaryContent {
+    content: DynCompose,
+    error_boundary: ErrorBoundaryId,
+}
+
+impl Compose for ErrorBoundaryContent {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        cx.error_boundary = Some(self.error_boundary);
+        self.content.clone()
+    }
+
+    fn name(&self) -> String {
+        String::from("ErrorBoundaryContent")
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{dyn_compose::DynCompose, modify::ModifyFunctions, Compose, Scope};
+use bevy_ui::{Display, Node};
+use std::collections::HashMap;
+
+/// Runtime feature flags, used to switch between variants of the UI with [`Flagged`]. The flags are app-wide and
+/// stored in a [`Store`](crate::store::Store), so they can be toggled at runtime with `ResMut<Store<FeatureFlags>>`,
+/// for example from a config file or a console command. Flags that have never been set are disabled.
+///
+/// # Example
+/// ```ignore
+/// fn load_flags(mut flags: ResMut<Store<FeatureFlags>>) {
+///     flags.set("new_inventory", true);
+/// }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct FeatureFlags {
+    flags: HashMap<String, bool>,
+}
+
+impl FeatureFlags {
+    /// Returns whether the given flag is enabled.
+    pub fn is_enabled(&self, flag: &str) -> bool {
+        self.flags.get(flag).copied().unwrap_or(false)
+    }
+
+    /// Enables or disables the given flag.
+    pub fn set(&mut self, flag: impl Into<String>, enabled: bool) {
+        self.flags.insert(flag.into(), enabled);
+    }
+
+    /// Enables the given flag.
+    pub fn enable(&mut self, flag: impl Into<String>) {
+        self.set(flag, true);
+    }
+
+    /// Disables the given flag.
+    pub fn disable(&mut self, flag: impl Into<String>) {
+        self.set(flag, false);
+    }
+
+    /// Toggles the given flag, and returns whether it is now enabled.
+    pub fn toggle(&mut self, flag: impl Into<String>) -> bool {
+        let enabled = self.flags.entry(flag.into()).or_insert(false);
+        *enabled = !*enabled;
+        *enabled
+    }
+
+    /// Returns an iterator over all flags that have been set, and whether they are enabled.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, bool)> {
+        self.flags
+            .iter()
+            .map(|(flag, enabled)| (flag.as_str(), *enabled))
+    }
+}
+
+impl Scope<'_> {
+    /// Returns whether the given [feature flag](FeatureFlags) is enabled, or `None` until the flag has been read, which
+    /// happens before the next recomposition. The composable is recomposed when the flag changes.
+    pub fn use_feature_flag(&mut self, flag: impl Into<String>) -> Option<bool> {
+        let flag = flag.into();
+        let enabled = self.use_store_selector(move |flags: &FeatureFlags| flags.is_enabled(&flag));
+        *enabled
+    }
+}
+
+/// What happens to the state of the variants of a [`Flagged`] composable when the flag changes.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum FlagStatePolicy {
+    /// Only the active variant is composed. The inactive variant is decomposed when the flag changes, which resets its
+    /// state.
+    #[default]
+    Reset,
+    /// Both variants stay composed, and the inactive variant is hidden. Each variant is wrapped in a node, which is
+    /// hidden with `Display::None` while the variant is inactive. Switching back to a variant restores it as it was.
+    Preserve,
+}
+
+/// A composable that switches between two variants of the UI based on a runtime [feature flag](FeatureFlags). This
+/// lets experimental UI ship next to the existing UI, and be toggled without changing the call sites.
+///
+/// Nothing is composed until the flag has been read, so the wrong variant is never shown, not even for a single frame.
+///
+/// # Example
+/// ```ignore
+/// Flagged::new("new_inventory", NewInventory, OldInventory).state_policy(FlagStatePolicy::Preserve)
+/// ```
+#[derive(Clone)]
+pub struct Flagged {
+    flag: String,
+    enabled: DynCompose,
+    disabled: DynCompose,
+    state_policy: FlagStatePolicy,
+}
+
+impl Flagged {
+    /// Creates a new flagged composable, which composes `enabled` when the flag is enabled and `disabled` otherwise.
+    pub fn new(
+        flag: impl Into<String>,
+        enabled: impl Compose + 'static,
+        disabled: impl Compose + 'static,
+    ) -> Self {
+        Self {
+            flag: flag.into(),
+            enabled: DynCompose::new(enabled),
+            disabled: DynCompose::new(disabled),
+            state_policy: FlagStatePolicy::default(),
+        }
+    }
+
+    /// Sets what happens to the state of the variants when the flag changes. Defaults to [`FlagStatePolicy::Reset`].
+    pub fn state_policy(mut self, state_policy: FlagStatePolicy) -> Self {
+        self.state_policy = state_policy;
+        self
+    }
+}
+
+impl Compose for Flagged {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let enabled = cx.use_feature_flag(self.flag.clone());
+
+        let Some(enabled) = enabled else {
+            return DynCompose::new(());
+        };
+
+        match self.state_policy {
+            FlagStatePolicy::Reset => DynCompose::new((
+                enabled.then(|| self.enabled.clone()),
+                (!enabled).then(|| self.disabled.clone()),
+            )),
+            FlagStatePolicy::Preserve => {
+                let variant = |content: &DynCompose, active: bool| {
+                    Node {
+                        display: if active { Display::Flex } else { Display::None },
+                        ..Default::default()
+                    }
+                    .children(content.clone())
+                };
+
+                DynCompose::new((
+                    variant(&self.enabled, enabled),
+                    variant(&self.disabled, !enabled),
+                ))
+            }
+        }
+    }
+
+    fn name(&self) -> String {
+        String::from("Flagged")
+    }
+}
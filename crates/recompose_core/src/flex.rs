@@ -0,0 +1,176 @@
+use crate::{
+    modify::{Modifier, Modify, ModifyFunctions},
+    spawn::Spawn,
+    Compose, Scope,
+};
+use bevy_ui::{AlignItems, Display, FlexDirection, FlexWrap, JustifyContent, Node, UiRect, Val};
+
+/// The main axis of a [`Flex`] container.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum FlexAxis {
+    Row,
+    Column,
+}
+
+/// A composable that lays out its children in a row or a column, as a shorthand for a flex `Node`. Rows follow the
+/// [layout direction](crate::layout_direction::LayoutDirection), so they run from right to left in right-to-left
+/// layouts.
+///
+/// Other components can be added with [`with_bundle`](ModifyFunctions::with_bundle).
+///
+/// # Example
+/// ```ignore
+/// Flex::row()
+///     .gap(8.0)
+///     .align_center()
+///     .children((icon, title))
+///     .with_bundle(BackgroundColor(Color::BLACK))
+/// ```
+#[derive(Clone)]
+pub struct Flex {
+    axis: FlexAxis,
+    node: Node,
+    modifier: Modifier,
+}
+
+impl Flex {
+    fn new(axis: FlexAxis) -> Self {
+        Self {
+            axis,
+            node: Node {
+                display: Display::Flex,
+                ..Default::default()
+            },
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Creates a container that lays out its children next to each other.
+    pub fn row() -> Self {
+        Self::new(FlexAxis::Row)
+    }
+
+    /// Creates a container that lays out its children on top of each other.
+    pub fn column() -> Self {
+        Self::new(FlexAxis::Column)
+    }
+
+    /// Sets the space between the children, in logical pixels.
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.node.row_gap = Val::Px(gap);
+        self.node.column_gap = Val::Px(gap);
+        self
+    }
+
+    /// Sets the padding on all sides, in logical pixels.
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.node.padding = UiRect::all(Val::Px(padding));
+        self
+    }
+
+    /// Sets the width of the container.
+    pub fn width(mut self, width: Val) -> Self {
+        self.node.width = width;
+        self
+    }
+
+    /// Sets the height of the container.
+    pub fn height(mut self, height: Val) -> Self {
+        self.node.height = height;
+        self
+    }
+
+    /// Wraps the children onto multiple lines when they don't fit.
+    pub fn wrap(mut self) -> Self {
+        self.node.flex_wrap = FlexWrap::Wrap;
+        self
+    }
+
+    /// Sets how the children are aligned on the cross axis.
+    pub fn align_items(mut self, align_items: AlignItems) -> Self {
+        self.node.align_items = align_items;
+        self
+    }
+
+    /// Aligns the children to the start of the cross axis.
+    pub fn align_start(self) -> Self {
+        self.align_items(AlignItems::FlexStart)
+    }
+
+    /// Centers the children on the cross axis.
+    pub fn align_center(self) -> Self {
+        self.align_items(AlignItems::Center)
+    }
+
+    /// Aligns the children to the end of the cross axis.
+    pub fn align_end(self) -> Self {
+        self.align_items(AlignItems::FlexEnd)
+    }
+
+    /// Stretches the children to fill the cross axis.
+    pub fn align_stretch(self) -> Self {
+        self.align_items(AlignItems::Stretch)
+    }
+
+    /// Sets how the children are distributed on the main axis.
+    pub fn justify_content(mut self, justify_content: JustifyContent) -> Self {
+        self.node.justify_content = justify_content;
+        self
+    }
+
+    /// Packs the children towards the start of the main axis.
+    pub fn justify_start(self) -> Self {
+        self.justify_content(JustifyContent::FlexStart)
+    }
+
+    /// Centers the children on the main axis.
+    pub fn justify_center(self) -> Self {
+        self.justify_content(JustifyContent::Center)
+    }
+
+    /// Packs the children towards the end of the main axis.
+    pub fn justify_end(self) -> Self {
+        self.justify_content(JustifyContent::FlexEnd)
+    }
+
+    /// Distributes the children evenly, with the first and last child at the edges of the container.
+    pub fn justify_between(self) -> Self {
+        self.justify_content(JustifyContent::SpaceBetween)
+    }
+
+    /// Distributes the children evenly, with equal space around each child.
+    pub fn justify_around(self) -> Self {
+        self.justify_content(JustifyContent::SpaceAround)
+    }
+
+    /// Distributes the children evenly, with equal space between the children and the edges of the container.
+    pub fn justify_evenly(self) -> Self {
+        self.justify_content(JustifyContent::SpaceEvenly)
+    }
+}
+
+impl Modify for Flex {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for Flex {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let direction = cx.use_layout_direction();
+
+        let node = Node {
+            flex_direction: match self.axis {
+                FlexAxis::Row => direction.row(),
+                FlexAxis::Column => FlexDirection::Column,
+            },
+            ..self.node.clone()
+        };
+
+        Spawn::new(node).use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("Flex")
+    }
+}
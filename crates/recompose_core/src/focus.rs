@@ -0,0 +1,59 @@
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    observer::Trigger,
+    query::With,
+    system::{Query, ResMut, Resource},
+};
+use bevy_hierarchy::Parent;
+use bevy_picking::{
+    events::{Down, Pointer},
+    pointer::PointerButton,
+};
+
+/// The entity that has input focus, if any. Pressing an entity that has been made focusable with
+/// [`focusable`](crate::modify::ModifyFunctions::focusable), or one of its descendants, focuses it, and pressing any other
+/// entity clears the focus. The resource can also be changed directly to move the focus, for example for keyboard
+/// navigation.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct InputFocus(pub Option<Entity>);
+
+impl InputFocus {
+    /// Returns `true` if the given entity has focus.
+    pub fn is_focused(&self, entity: Entity) -> bool {
+        self.0 == Some(entity)
+    }
+}
+
+/// Marks an entity that can receive input focus. Inserted by [`focusable`](crate::modify::ModifyFunctions::focusable).
+#[derive(Component, Clone, Copy)]
+pub(crate) struct Focusable;
+
+/// Moves the focus to the closest focusable ancestor of the pressed entity.
+pub(crate) fn focus_on_press(
+    trigger: Trigger<Pointer<Down>>,
+    focusables: Query<(), With<Focusable>>,
+    parents: Query<&Parent>,
+    mut focus: ResMut<InputFocus>,
+) {
+    let pointer = trigger.event();
+
+    // The event bubbles up through all ancestors of the pressed entity, but the focus only has to be moved once.
+    if trigger.entity() != pointer.target || pointer.button != PointerButton::Primary {
+        return;
+    }
+
+    let mut entity = Some(pointer.target);
+
+    while let Some(current) = entity {
+        if focusables.contains(current) {
+            break;
+        }
+
+        entity = parents.get(current).ok().map(|parent| parent.get());
+    }
+
+    if focus.0 != entity {
+        focus.0 = entity;
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{dyn_compose::DynCompose, Compose, Scope};
+
+/// A composable that groups its children without spawning an entity. The children are spawned as children of the
+/// closest ancestor with an entity, in the position of the fragment. Combined with the
+/// [`order`](crate::modify::ModifyFunctions::order) modifier, this lets us interleave conditional children at specific
+/// positions among their siblings.
+///
+/// # Example
+/// ```ignore
+/// Node::default().children((
+///     Text::new("Header").to_compose(),
+///     Fragment::new((
+///         Text::new("Only shown when expanded").to_compose().some_if(*is_expanded),
+///         Text::new("Always last").to_compose().order(1),
+///     )),
+///     Text::new("Footer").to_compose(),
+/// ))
+/// ```
+#[derive(Clone, Default)]
+pub struct Fragment {
+    children: DynCompose,
+}
+
+impl Fragment {
+    /// Creates a new fragment with the given children.
+    pub fn new(children: impl Compose + 'static) -> Self {
+        Self {
+            children: DynCompose::new(children),
+        }
+    }
+}
+
+impl Compose for Fragment {
+    fn compose<'a>(&self, _: &mut Scope) -> impl Compose + 'a {
+        self.children.clone()
+    }
+
+    fn name(&self) -> String {
+        String::from("Fragment")
+    }
+}
@@ -0,0 +1,268 @@
+use crate::{
+    pointer_events::{PointerEvent, PointerEventKind},
+    state::SetState,
+};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    observer::Trigger,
+    system::{EntityCommands, Query, Res, Resource},
+    world::World,
+};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_math::Vec2;
+use bevy_picking::pointer::PointerButton;
+use bevy_time::{Real, Time};
+use std::{sync::Arc, time::Duration};
+
+pub(crate) type GestureHandler = Arc<dyn Fn(&mut SetState) + Send + Sync>;
+pub(crate) type DragHandler = Arc<dyn Fn(DragGesture, &mut SetState) + Send + Sync>;
+
+/// App-wide settings for recognizing gestures, like the double-click interval of an operating system. The settings are
+/// read when the gestures are recognized, so they can be changed at runtime.
+#[derive(Resource, Clone, Debug)]
+pub struct GestureSettings {
+    /// The maximum time between the two clicks of a double-click.
+    pub double_click_interval: Duration,
+    /// The distance, in logical pixels, that the pointer has to move while pressed before a drag starts. Moving further
+    /// than this during a long press cancels the long press.
+    pub drag_threshold: f32,
+}
+
+impl Default for GestureSettings {
+    fn default() -> Self {
+        Self {
+            double_click_interval: Duration::from_millis(400),
+            drag_threshold: 4.0,
+        }
+    }
+}
+
+/// The phase of a drag gesture.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum GesturePhase {
+    /// The pointer has moved further than the drag threshold.
+    Started,
+    /// The pointer has moved since the last event.
+    Moved,
+    /// The pointer was released.
+    Ended,
+    /// The drag was cancelled by pressing Escape. No more events are sent until the pointer is released.
+    Cancelled,
+}
+
+/// A drag gesture, recognized by [`on_drag`](crate::modify::ModifyFunctions::on_drag).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct DragGesture {
+    pub phase: GesturePhase,
+    /// The movement of the pointer since the last event. For the `Started` phase, this is the movement since the
+    /// pointer was pressed, so that no movement is lost to the threshold.
+    pub delta: Vec2,
+    /// The movement of the pointer since it was pressed.
+    pub distance: Vec2,
+}
+
+/// Inserts the gesture component on the entity, or updates the existing one. Gesture components track the progress of
+/// the gesture, which would be lost if the component was replaced on every recomposition.
+pub(crate) fn upsert_gesture<G: Component>(
+    entity: &mut EntityCommands,
+    insert: impl FnOnce() -> G + Send + 'static,
+    update: impl FnOnce(&mut G) + Send + 'static,
+) {
+    entity.queue(move |entity: Entity, world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+
+        match entity.get_mut::<G>() {
+            Some(mut gesture) => update(&mut gesture),
+            None => {
+                entity.insert(insert());
+            }
+        }
+    });
+}
+
+#[derive(Component)]
+pub(crate) struct DoubleClickGesture {
+    pub(crate) handler: GestureHandler,
+    pub(crate) last_click: Option<Duration>,
+}
+
+#[derive(Component)]
+pub(crate) struct LongPressGesture {
+    pub(crate) handler: GestureHandler,
+    pub(crate) duration: Duration,
+    /// When the pointer was pressed. `None` when the entity isn't pressed, or when the long press has already been
+    /// recognized or cancelled.
+    pub(crate) pressed_at: Option<Duration>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum DragState {
+    #[default]
+    Idle,
+    Pending,
+    Dragging,
+    Cancelled,
+}
+
+#[derive(Component)]
+pub(crate) struct DragGestureTracker {
+    pub(crate) handler: DragHandler,
+    pub(crate) state: DragState,
+    pub(crate) distance: Vec2,
+}
+
+fn now(time: Option<Res<Time<Real>>>) -> Duration {
+    time.map_or(Duration::ZERO, |time| time.elapsed())
+}
+
+pub(crate) fn detect_double_click(
+    trigger: Trigger<PointerEvent>,
+    mut gestures: Query<&mut DoubleClickGesture>,
+    settings: Res<GestureSettings>,
+    time: Option<Res<Time<Real>>>,
+    mut state: SetState,
+) {
+    if trigger.kind != PointerEventKind::Click || trigger.button != Some(PointerButton::Primary) {
+        return;
+    }
+
+    let Ok(mut gesture) = gestures.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    let now = now(time);
+
+    let is_double_click = gesture
+        .last_click
+        .is_some_and(|last_click| now.saturating_sub(last_click) <= settings.double_click_interval);
+
+    if is_double_click {
+        // The click that completes a double-click doesn't start a new one, so that triple-clicks don't count as two
+        // double-clicks.
+        gesture.last_click = None;
+        (gesture.handler)(&mut state);
+    } else {
+        gesture.last_click = Some(now);
+    }
+}
+
+pub(crate) fn detect_long_press(
+    trigger: Trigger<PointerEvent>,
+    mut gestures: Query<&mut LongPressGesture>,
+    settings: Res<GestureSettings>,
+    time: Option<Res<Time<Real>>>,
+) {
+    let Ok(mut gesture) = gestures.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    match trigger.kind {
+        PointerEventKind::Down if trigger.button == Some(PointerButton::Primary) => {
+            gesture.pressed_at = Some(now(time));
+        }
+        PointerEventKind::Drag if trigger.distance.length() > settings.drag_threshold => {
+            gesture.pressed_at = None;
+        }
+        PointerEventKind::Up | PointerEventKind::Out => gesture.pressed_at = None,
+        _ => {}
+    }
+}
+
+pub(crate) fn recognize_long_presses(
+    mut gestures: Query<&mut LongPressGesture>,
+    time: Option<Res<Time<Real>>>,
+    mut state: SetState,
+) {
+    let now = now(time);
+
+    for mut gesture in gestures.iter_mut() {
+        let Some(pressed_at) = gesture.pressed_at else {
+            continue;
+        };
+
+        if now.saturating_sub(pressed_at) < gesture.duration {
+            continue;
+        }
+
+        gesture.pressed_at = None;
+        (gesture.handler)(&mut state);
+    }
+}
+
+pub(crate) fn detect_drag(
+    trigger: Trigger<PointerEvent>,
+    mut gestures: Query<&mut DragGestureTracker>,
+    settings: Res<GestureSettings>,
+    mut state: SetState,
+) {
+    let Ok(mut gesture) = gestures.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    let distance = trigger.distance;
+
+    let (next_state, phase, delta) = match (trigger.kind, gesture.state) {
+        (PointerEventKind::DragStart, _) => (DragState::Pending, None, Vec2::ZERO),
+        (PointerEventKind::Drag, DragState::Pending)
+            if distance.length() >= settings.drag_threshold =>
+        {
+            (DragState::Dragging, Some(GesturePhase::Started), distance)
+        }
+        (PointerEventKind::Drag, DragState::Dragging) => (
+            DragState::Dragging,
+            Some(GesturePhase::Moved),
+            trigger.delta,
+        ),
+        (PointerEventKind::DragEnd, DragState::Dragging) => {
+            (DragState::Idle, Some(GesturePhase::Ended), Vec2::ZERO)
+        }
+        (PointerEventKind::DragEnd, _) => (DragState::Idle, None, Vec2::ZERO),
+        (_, current_state) => (current_state, None, Vec2::ZERO),
+    };
+
+    gesture.state = next_state;
+    gesture.distance = distance;
+
+    if let Some(phase) = phase {
+        let drag = DragGesture {
+            phase,
+            delta,
+            distance,
+        };
+
+        (gesture.handler)(drag, &mut state);
+    }
+}
+
+pub(crate) fn cancel_drags_on_escape(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mut gestures: Query<&mut DragGestureTracker>,
+    mut state: SetState,
+) {
+    let Some(keys) = keys else {
+        return;
+    };
+
+    if !keys.just_pressed(KeyCode::Escape) {
+        return;
+    }
+
+    for mut gesture in gestures.iter_mut() {
+        if gesture.state != DragState::Dragging {
+            continue;
+        }
+
+        gesture.state = DragState::Cancelled;
+
+        let drag = DragGesture {
+            phase: GesturePhase::Cancelled,
+            delta: Vec2::ZERO,
+            distance: gesture.distance,
+        };
+
+        (gesture.handler)(drag, &mut state);
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{
+    modify::{Modifier, Modify, ModifyFunctions},
+    spawn::Spawn,
+    Compose, Scope,
+};
+use bevy_ui::{AlignItems, Display, JustifyItems, Node, RepeatedGridTrack, UiRect, Val};
+
+/// A composable that lays out its children in a grid, as a shorthand for a grid `Node`. The children fill the grid row
+/// by row.
+///
+/// Other components can be added with [`with_bundle`](ModifyFunctions::with_bundle).
+///
+/// # Example
+/// ```ignore
+/// Grid::cols(3)
+///     .gap(8.0)
+///     .children(items.iter().map(|item| ItemSlot::new(item).keyed(item.id)).collect::<Vec<_>>())
+/// ```
+#[derive(Clone)]
+pub struct Grid {
+    node: Node,
+    modifier: Modifier,
+}
+
+impl Grid {
+    /// Creates a grid with the given column tracks.
+    pub fn new(columns: Vec<RepeatedGridTrack>) -> Self {
+        Self {
+            node: Node {
+                display: Display::Grid,
+                grid_template_columns: columns,
+                ..Default::default()
+            },
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Creates a grid with the given number of columns of equal width.
+    pub fn cols(columns: u16) -> Self {
+        Self::new(RepeatedGridTrack::flex(columns, 1.0))
+    }
+
+    /// Sets the rows of the grid to the given number of rows of equal height. By default, rows are added as needed and
+    /// sized to fit their children.
+    pub fn rows(mut self, rows: u16) -> Self {
+        self.node.grid_template_rows = RepeatedGridTrack::flex(rows, 1.0);
+        self
+    }
+
+    /// Sets the space between the rows and between the columns, in logical pixels.
+    pub fn gap(mut self, gap: f32) -> Self {
+        self.node.row_gap = Val::Px(gap);
+        self.node.column_gap = Val::Px(gap);
+        self
+    }
+
+    /// Sets the space between the rows, in logical pixels.
+    pub fn row_gap(mut self, gap: f32) -> Self {
+        self.node.row_gap = Val::Px(gap);
+        self
+    }
+
+    /// Sets the space between the columns, in logical pixels.
+    pub fn column_gap(mut self, gap: f32) -> Self {
+        self.node.column_gap = Val::Px(gap);
+        self
+    }
+
+    /// Sets the padding on all sides, in logical pixels.
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.node.padding = UiRect::all(Val::Px(padding));
+        self
+    }
+
+    /// Sets the width of the grid.
+    pub fn width(mut self, width: Val) -> Self {
+        self.node.width = width;
+        self
+    }
+
+    /// Sets the height of the grid.
+    pub fn height(mut self, height: Val) -> Self {
+        self.node.height = height;
+        self
+    }
+
+    /// Sets how the children are aligned vertically within their cells.
+    pub fn align_items(mut self, align_items: AlignItems) -> Self {
+        self.node.align_items = align_items;
+        self
+    }
+
+    /// Sets how the children are aligned horizontally within their cells.
+    pub fn justify_items(mut self, justify_items: JustifyItems) -> Self {
+        self.node.justify_items = justify_items;
+        self
+    }
+
+    /// Centers the children within their cells.
+    pub fn center_items(self) -> Self {
+        self.align_items(AlignItems::Center)
+            .justify_items(JustifyItems::Center)
+    }
+}
+
+impl Modify for Grid {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for Grid {
+    fn compose<'a>(&self, _: &mut Scope) -> impl Compose + 'a {
+        Spawn::new(self.node.clone()).use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("Grid")
+    }
+}
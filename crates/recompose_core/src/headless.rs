@@ -0,0 +1,76 @@
+use crate::{state::StateSetter, Compose, RecomposePlugin, Root};
+use bevy_app::App;
+use bevy_ecs::{entity::Entity, world::World};
+use bevy_hierarchy::{Children, HierarchyPlugin};
+use bevy_scene::{DynamicScene, DynamicSceneBuilder};
+
+/// The maximum number of updates [`compose_to_scene`] runs before extracting the scene. Composables that keep changing
+/// their states never settle, so the scene is extracted after this many updates regardless.
+const MAX_UPDATES: usize = 64;
+
+/// Extracts the entity hierarchy spawned by the root on the given entity as a [`DynamicScene`]. The root entity and all
+/// of its descendants are extracted. Only components that are registered in the app's type registry (and reflect
+/// `Component`) are included.
+pub fn extract_scene(world: &World, root: Entity) -> DynamicScene {
+    let mut entities = vec![];
+    let mut stack = vec![root];
+
+    while let Some(entity) = stack.pop() {
+        entities.push(entity);
+
+        if let Some(children) = world.get::<Children>(entity) {
+            stack.extend(children.iter().rev());
+        }
+    }
+
+    DynamicSceneBuilder::from_world(world)
+        .extract_entities(entities.into_iter())
+        .build()
+}
+
+/// Composes the given composable in the given app and returns the resulting entity hierarchy as a [`DynamicScene`]. The
+/// app doesn't need a window, rendering or UI, so this is useful for tools that use the compose tree to build
+/// hierarchical data offline, for example to generate scenes or prefabs. The [`RecomposePlugin`] and [`HierarchyPlugin`]
+/// are added to the app if they haven't been added yet.
+///
+/// The app is updated until all states are settled. Components that should end up in the scene have to be registered
+/// in the app's type registry.
+///
+/// # Example
+/// ```ignore
+/// let mut app = App::new();
+/// app.register_type::<Transform>();
+///
+/// let scene = compose_to_scene(&mut app, level);
+/// ```
+pub fn compose_to_scene<C: Compose + 'static>(app: &mut App, compose: C) -> DynamicScene {
+    if !app.is_plugin_added::<HierarchyPlugin>() {
+        app.add_plugins(HierarchyPlugin);
+    }
+
+    if !app.is_plugin_added::<RecomposePlugin>() {
+        app.add_plugins(RecomposePlugin);
+    }
+
+    let root = app.world_mut().spawn(Root::new(compose)).id();
+
+    for _ in 0..MAX_UPDATES {
+        app.update();
+
+        if is_settled(app.world(), root) {
+            break;
+        }
+    }
+
+    extract_scene(app.world(), root)
+}
+
+fn is_settled(world: &World, root: Entity) -> bool {
+    let has_queued_states = world
+        .get_resource::<StateSetter>()
+        .is_some_and(|setter| !setter.queued.is_empty());
+
+    let is_root_settled = world.get::<Root>(root).is_some_and(Root::is_settled);
+
+    !has_queued_states && is_root_settled
+}
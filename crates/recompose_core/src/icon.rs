@@ -0,0 +1,127 @@
+use crate::{
+    modify::{Modifier, Modify, ModifyFunctions},
+    spawn::Spawn,
+    Compose, Scope,
+};
+use bevy_asset::Handle;
+use bevy_color::Color;
+use bevy_image::Image;
+use bevy_sprite::{TextureAtlas, TextureAtlasLayout};
+use bevy_ui::{widget::ImageNode, BackgroundColor, Node, Val};
+
+/// A composable that shows an image, like an icon from an icon sheet. It is a shorthand for composing an `ImageNode`
+/// with a fixed size, that is only shown once the image has been loaded.
+///
+/// While the image is loading, or when it fails to load, a sized icon keeps its size, so that the layout doesn't jump. A
+/// [`placeholder`](Icon::placeholder) color can be shown in the meantime.
+///
+/// # Example
+/// ```ignore
+/// Icon::new(self.icon_sheet.clone())
+///     .atlas(self.icon_layout.clone(), ICON_SAVE)
+///     .size(24.0)
+///     .tint(Srgba::rgb(0.9, 0.9, 0.9))
+/// ```
+#[derive(Clone)]
+pub struct Icon {
+    image: Handle<Image>,
+    tint: Color,
+    size: Option<f32>,
+    atlas: Option<TextureAtlas>,
+    flip_x: bool,
+    flip_y: bool,
+    placeholder: Option<Color>,
+    modifier: Modifier,
+}
+
+impl Icon {
+    /// Creates a new icon that shows the given image at its own size.
+    pub fn new(image: Handle<Image>) -> Self {
+        Self {
+            image,
+            tint: Color::WHITE,
+            size: None,
+            atlas: None,
+            flip_x: false,
+            flip_y: false,
+            placeholder: None,
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Sets the width and height of the icon in pixels.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the color that the image is multiplied with. White shows the image unchanged.
+    pub fn tint(mut self, tint: impl Into<Color>) -> Self {
+        self.tint = tint.into();
+        self
+    }
+
+    /// Shows the image at the given index of a texture atlas, like an icon of an icon sheet.
+    pub fn atlas(mut self, layout: Handle<TextureAtlasLayout>, index: usize) -> Self {
+        self.atlas = Some(TextureAtlas { layout, index });
+        self
+    }
+
+    /// Flips the image horizontally.
+    pub fn flip_x(mut self, flip_x: bool) -> Self {
+        self.flip_x = flip_x;
+        self
+    }
+
+    /// Flips the image vertically.
+    pub fn flip_y(mut self, flip_y: bool) -> Self {
+        self.flip_y = flip_y;
+        self
+    }
+
+    /// Sets the background color that is shown while the image is loading, or when it failed to load.
+    pub fn placeholder(mut self, color: impl Into<Color>) -> Self {
+        self.placeholder = Some(color.into());
+        self
+    }
+}
+
+impl Modify for Icon {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for Icon {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let is_loaded = cx.use_load_state(&self.image).is_loaded();
+        let size = self.size.map_or(Val::Auto, Val::Px);
+
+        let image = ImageNode {
+            color: self.tint,
+            texture_atlas: self.atlas.clone(),
+            flip_x: self.flip_x,
+            flip_y: self.flip_y,
+            ..ImageNode::new(self.image.clone())
+        };
+
+        let node = Node {
+            width: size,
+            height: size,
+            ..Default::default()
+        };
+
+        // The image is only inserted once it is loaded, so that it doesn't cover the placeholder with an empty texture.
+        let spawn = Spawn::new(node).with_bundle_if(is_loaded, image);
+
+        match self.placeholder {
+            Some(placeholder) => spawn.with_bundle_if(!is_loaded, BackgroundColor(placeholder)),
+            None => spawn,
+        }
+        .use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("Icon")
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    focus::InputFocus,
+    pointer_events::{PointerEvent, PointerEventKind},
+    state::{SetState, State, TypedStateId},
+    Scope,
+};
+use bevy_ecs::{
+    bundle::Bundle,
+    component::Component,
+    entity::Entity,
+    observer::Trigger,
+    query::Has,
+    system::{EntityCommands, Query, Res, Resource},
+    world::{EntityWorldMut, World},
+};
+use bevy_picking::PickingBehavior;
+use std::sync::Arc;
+
+/// The interaction state of a widget, which combines whether it is hovered, pressed, focused or disabled. When several
+/// of these apply at once, the one listed first takes precedence, so a disabled widget is always `Disabled`, and a
+/// pressed widget is `Pressed` even though it is also hovered.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum InteractionState {
+    /// The entity has the [`Disabled`] component.
+    Disabled,
+    /// A pointer is pressed down on the entity.
+    Pressed,
+    /// A pointer is over the entity.
+    Hovered,
+    /// The entity has [`InputFocus`].
+    Focused,
+    /// None of the above.
+    #[default]
+    Idle,
+}
+
+/// Marks an entity as disabled, which makes its [`InteractionState`] `Disabled`. It is inserted by
+/// [`disabled`](crate::modify::ModifyFunctions::disabled), but can also be inserted manually.
+#[derive(Component, Clone, Copy, Default)]
+pub struct Disabled;
+
+/// The visual treatment of entities that are disabled with [`disabled`](crate::modify::ModifyFunctions::disabled). The
+/// bundle is inserted into the disabled entity, after its own bundle, and removed again once it is enabled. Only the
+/// entity that is disabled is styled, not the entities composed inside of it. By default, no bundle is inserted.
+///
+/// # Example
+/// ```ignore
+/// app.insert_resource(DisabledStyle::new(BackgroundColor(Color::srgb(0.3, 0.3, 0.3))));
+/// ```
+#[derive(Resource, Clone, Default)]
+pub struct DisabledStyle {
+    #[allow(clippy::type_complexity)]
+    style: Option<(
+        Arc<dyn Fn(&mut EntityWorldMut) + Send + Sync>,
+        Arc<dyn Fn(&mut EntityWorldMut) + Send + Sync>,
+    )>,
+}
+
+impl DisabledStyle {
+    /// Creates a style that inserts the given bundle into disabled entities.
+    pub fn new<B: Bundle + Clone>(bundle: B) -> Self {
+        Self {
+            style: Some((
+                Arc::new(move |entity: &mut EntityWorldMut| {
+                    entity.insert(bundle.clone());
+                }),
+                Arc::new(|entity: &mut EntityWorldMut| {
+                    entity.remove::<B>();
+                }),
+            )),
+        }
+    }
+}
+
+/// Marks an entity that is styled with the [`DisabledStyle`], so that the style is only removed from entities that
+/// have it.
+#[derive(Component)]
+pub(crate) struct DisabledStyled;
+
+/// Tracks the interaction of an entity and mirrors it into a state. Inserted by
+/// [`bind_interaction`](crate::modify::ModifyFunctions::bind_interaction).
+#[derive(Component)]
+pub(crate) struct InteractionTracker {
+    pub(crate) state: TypedStateId<InteractionState>,
+    pub(crate) is_hovered: bool,
+    pub(crate) is_pressed: bool,
+}
+
+impl Scope<'_> {
+    /// Creates a state that holds the [`InteractionState`] of an entity. The state is bound to an entity with
+    /// [`bind_interaction`](crate::modify::ModifyFunctions::bind_interaction), and replaces separate states for hover,
+    /// press, focus and disabled.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let interaction = cx.use_interaction_state();
+    ///
+    /// let color = match *interaction {
+    ///     InteractionState::Disabled => Color::srgb(0.3, 0.3, 0.3),
+    ///     InteractionState::Pressed => Color::srgb(0.1, 0.4, 0.8),
+    ///     InteractionState::Hovered | InteractionState::Focused => Color::srgb(0.2, 0.5, 0.9),
+    ///     InteractionState::Idle => Color::srgb(0.2, 0.2, 0.2),
+    /// };
+    ///
+    /// (Node::default(), BackgroundColor(color)).bind_interaction(&interaction)
+    /// ```
+    pub fn use_interaction_state(&mut self) -> State<InteractionState> {
+        self.use_state(InteractionState::Idle)
+    }
+}
+
+/// Undoes what [`disable_entity`] did to an entity that is no longer disabled, or no longer styled. This has to happen
+/// before the bundle of the entity is inserted, since the style may have replaced components of the bundle.
+pub(crate) fn enable_entity(
+    entity: &mut EntityCommands,
+    is_disabled: bool,
+    is_styled: bool,
+    pickable: Option<bool>,
+) {
+    entity.queue(move |entity: Entity, world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+
+        if !is_styled && entity.take::<DisabledStyled>().is_some() {
+            let style = entity.world().get_resource::<DisabledStyle>().cloned();
+
+            if let Some((_, remove)) = style.and_then(|style| style.style) {
+                remove(&mut entity);
+            }
+        }
+
+        if is_disabled || entity.take::<Disabled>().is_none() {
+            return;
+        }
+
+        if let Some(mut behavior) = entity.get_mut::<PickingBehavior>() {
+            behavior.is_hoverable = pickable.unwrap_or(true);
+        }
+    });
+}
+
+/// Inserts the [`Disabled`] component into an entity, and stops it from being hovered. Entities that are disabled
+/// themselves, rather than by an ancestor, are also styled with the [`DisabledStyle`].
+pub(crate) fn disable_entity(entity: &mut EntityCommands, is_styled: bool) {
+    entity.queue(move |entity: Entity, world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+
+        if !entity.contains::<Disabled>() {
+            entity.insert(Disabled);
+        }
+
+        // The entity still blocks the entities below it, so that clicks don't fall through a disabled button.
+        let mut behavior = entity.get::<PickingBehavior>().cloned().unwrap_or_default();
+        behavior.is_hoverable = false;
+
+        if entity.get::<PickingBehavior>() != Some(&behavior) {
+            entity.insert(behavior);
+        }
+
+        if !is_styled {
+            return;
+        }
+
+        // The bundle of the entity is inserted on every update, so the style is inserted again after it.
+        let style = entity.world().get_resource::<DisabledStyle>().cloned();
+
+        if let Some((insert, _)) = style.and_then(|style| style.style) {
+            insert(&mut entity);
+            entity.insert(DisabledStyled);
+        }
+    });
+}
+
+pub(crate) fn track_interaction(
+    trigger: Trigger<PointerEvent>,
+    mut trackers: Query<&mut InteractionTracker>,
+) {
+    let Ok(mut tracker) = trackers.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    match trigger.kind {
+        PointerEventKind::Over => tracker.is_hovered = true,
+        PointerEventKind::Out => tracker.is_hovered = false,
+        PointerEventKind::Down => tracker.is_pressed = true,
+        PointerEventKind::Up | PointerEventKind::DragEnd => tracker.is_pressed = false,
+        _ => {}
+    }
+}
+
+pub(crate) fn update_interaction_states(
+    trackers: Query<(Entity, &InteractionTracker, Has<Disabled>)>,
+    focus: Res<InputFocus>,
+    mut state: SetState,
+) {
+    for (entity, tracker, is_disabled) in trackers.iter() {
+        let interaction = if is_disabled {
+            InteractionState::Disabled
+        } else if tracker.is_pressed {
+            InteractionState::Pressed
+        } else if tracker.is_hovered {
+            InteractionState::Hovered
+        } else if focus.is_focused(entity) {
+            InteractionState::Focused
+        } else {
+            InteractionState::Idle
+        };
+
+        state.set_neq(tracker.state, interaction);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    modify::{Modifier, Modify, ModifyFunctions},
+    spawn::Spawn,
+    AnyCompose, Compose, Root, Scope,
+};
+use bevy_ecs::system::EntityCommands;
+use bevy_ui::Node;
+use std::sync::Arc;
+
+/// A composable that hosts its content in a nested [`Root`] with its own, independent scope tree. State changes inside
+/// the island only recompose the island, and recompositions of the surrounding composables never recompose the content
+/// of the island. This is useful for embedding heavy, frequently updating widgets, like minimaps or logs, inside of a
+/// mostly static UI.
+///
+/// The island spawns a `Node` that holds the root, so its content is laid out as part of the surrounding UI. The content
+/// is only taken when the island is first composed. To replace it, the island can be keyed with
+/// [`Keyed`](crate::keyed::Keyed), and data can be shared with the content through [stores](crate::store::Store).
+///
+/// # Example
+/// ```ignore
+/// Flex::row().children((
+///     Sidebar,
+///     Keyed::new(map_id, Island::new(Minimap::new(map_id))),
+/// ))
+/// ```
+#[derive(Clone)]
+pub struct Island {
+    content: Arc<dyn AnyCompose>,
+    modifier: Modifier,
+}
+
+impl Island {
+    /// Creates a new island that composes the given content.
+    pub fn new(content: impl Compose + 'static) -> Self {
+        Self {
+            content: Arc::new(content),
+            modifier: Modifier::default(),
+        }
+    }
+}
+
+impl Modify for Island {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for Island {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let content = self.content.clone();
+        let context = cx.context.clone();
+
+        let mut spawn = Spawn::new(Node::default()).use_modifier(&self.modifier);
+
+        // The root is only inserted once, as replacing it would decompose the content and compose it from scratch. It
+        // inherits the context of the surrounding root, so that the content can read the same context values.
+        spawn
+            .modifier
+            .bundle_modifiers
+            .push(Arc::new(move |entity: &mut EntityCommands| {
+                let mut root = Root::from_shared(content.clone());
+                root.context = context.clone();
+                entity.try_insert_if_new(root);
+            }));
+
+        spawn
+    }
+
+    fn name(&self) -> String {
+        String::from("Island")
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{dyn_compose::DynCompose, Compose, Key, Scope};
+use crate::{dyn_compose::DynCompose, state::stable_hash, Compose, Key, KeyValue, KeyValueRef, Scope};
 use std::hash::Hash;
 
 #[derive(Clone)]
@@ -18,6 +18,11 @@ impl<H: Hash + Send + Sync> Keyed<H> {
 
 impl<H: Hash + Send + Sync> Compose for Keyed<H> {
     fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        // Keyed composables are addressed by their key rather than their index, which keeps their stable path intact
+        // when the position of the composable changes. The hash is saved with the states of the scope, so it has to
+        // stay the same across runs and toolchain versions.
+        cx.set_key(stable_hash(&self.key));
+
         self.compose.compose(cx)
     }
 
@@ -34,8 +39,53 @@ impl<H: Hash + Send + Sync> Compose for Keyed<H> {
     }
 }
 
-impl<H: Hash + Send + Sync + Clone> Key for Keyed<H> {
+impl<H: KeyValue> Key for Keyed<H> {
     fn key(&self) -> &impl Hash {
         &self.key
     }
+
+    fn key_value(&self) -> Option<KeyValueRef<'_>> {
+        Some(KeyValueRef::new(&self.key))
+    }
+}
+
+/// A composable that tags its scope with a stable, user-assigned key. Unlike [`ScopeId`](crate::scope::ScopeId)s, which
+/// are generated at runtime, scope keys stay the same across runs, recompiles and toolchain versions, which makes them
+/// suitable for tooling, external references and state restoration. See
+/// [`ScopeRef::find`](crate::scope::ScopeRef::find) and [`Scopes::find`](crate::scope::Scopes::find).
+///
+/// The key is assigned to the scope of the wrapped composable, so its states can be addressed through the
+/// [handle](crate::scope::ScopeRef::handle) of the found scope. Like for [`Keyed`], the key is also used in the
+/// [stable path](crate::scope::StablePath) of the scope. Scope keys should be unique within a root.
+#[derive(Clone)]
+pub struct ScopeKeyed {
+    key: String,
+    compose: DynCompose,
+}
+
+impl ScopeKeyed {
+    pub fn new<C: Compose + 'static>(key: impl Into<String>, compose: C) -> Self {
+        let key = key.into();
+
+        Self {
+            compose: DynCompose::new(compose).with_scope_key(key.clone()),
+            key,
+        }
+    }
+}
+
+impl Compose for ScopeKeyed {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        cx.set_key(stable_hash(&self.key));
+
+        self.compose.compose(cx)
+    }
+
+    fn ignore_children(&self) -> bool {
+        self.compose.ignore_children()
+    }
+
+    fn name(&self) -> String {
+        String::from("ScopeKeyedCompose")
+    }
 }
@@ -0,0 +1,152 @@
+use crate::{
+    layout_direction::justify_text,
+    modify::{Modifier, Modify, ModifyFunctions},
+    spawn::Spawn,
+    Compose, Scope,
+};
+use bevy_color::Color;
+use bevy_ecs::{component::Component, system::EntityCommands, world::EntityWorldMut};
+use bevy_text::{TextColor, TextFont};
+use bevy_ui::widget::Text;
+use std::{
+    borrow::Cow,
+    fmt::Display,
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::Arc,
+};
+
+/// A composable that spawns a text node from anything that implements `Display`. It is a shorthand for composing a
+/// `(Text, TextFont, TextColor)` bundle with sensible defaults.
+///
+/// # Example
+/// ```ignore
+/// fn counter(cx: &mut Scope) -> impl Compose {
+///     let count = cx.use_state(0);
+///     label(*count).font_size(24.0)
+/// }
+/// ```
+#[derive(Clone)]
+pub struct TextLabel {
+    text: String,
+    font: TextFont,
+    color: TextColor,
+    modifier: Modifier,
+}
+
+impl TextLabel {
+    /// Creates a new label from the given value.
+    pub fn new(text: impl Display) -> Self {
+        Self {
+            text: text.to_string(),
+            font: TextFont::default(),
+            color: TextColor::default(),
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Sets the font size of the label.
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font.font_size = font_size;
+        self
+    }
+
+    /// Sets the font of the label.
+    pub fn font(mut self, font: TextFont) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the color of the label.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = TextColor(color.into());
+        self
+    }
+}
+
+impl Modify for TextLabel {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for TextLabel {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let justify = cx.use_layout_direction().justify_text();
+        let text_hash = TextHash::new(&self.text, &self.font);
+        let text = Text::new(self.text.clone());
+        let font = self.font.clone();
+
+        let mut spawn = Spawn::new(self.color);
+
+        // Inserting `Text` or `TextFont` makes Bevy shape the text again, even if the values are the same. That's why
+        // they are only inserted when the hash of the text and font differs from the one on the entity.
+        spawn
+            .modifier
+            .bundle_modifiers
+            .push(Arc::new(move |entity: &mut EntityCommands| {
+                let text = text.clone();
+                let font = font.clone();
+
+                entity.queue(move |mut entity: EntityWorldMut| {
+                    justify_text(&mut entity, justify);
+
+                    if entity.get::<TextHash>() == Some(&text_hash) {
+                        return;
+                    }
+
+                    entity.insert((text, font, text_hash));
+                });
+            }));
+
+        spawn.use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("TextLabel")
+    }
+}
+
+/// Creates a new [`TextLabel`] from the given value.
+pub fn label(text: impl Display) -> TextLabel {
+    TextLabel::new(text)
+}
+
+/// Trait that allows for easier conversion of strings into a [`TextLabel`]. Other values that implement `Display` can
+/// be turned into a label with [`label`] or [`TextLabel::new`].
+pub trait LabelExtension {
+    /// Converts this string into a `TextLabel`.
+    fn to_label(&self) -> TextLabel;
+}
+
+impl LabelExtension for str {
+    fn to_label(&self) -> TextLabel {
+        TextLabel::new(self)
+    }
+}
+
+impl LabelExtension for String {
+    fn to_label(&self) -> TextLabel {
+        TextLabel::new(self)
+    }
+}
+
+impl LabelExtension for Cow<'_, str> {
+    fn to_label(&self) -> TextLabel {
+        TextLabel::new(self)
+    }
+}
+
+/// The hash of the text and the font properties that affect the shaping of a [`TextLabel`].
+#[derive(Component, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct TextHash(u64);
+
+impl TextHash {
+    fn new(text: &str, font: &TextFont) -> Self {
+        let mut hasher = DefaultHasher::new();
+        text.hash(&mut hasher);
+        font.font.hash(&mut hasher);
+        font.font_size.to_bits().hash(&mut hasher);
+        font.font_smoothing.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+}
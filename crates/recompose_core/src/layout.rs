@@ -0,0 +1,87 @@
+use crate::{
+    state::{SetState, State, TypedStateId},
+    Scope,
+};
+use bevy_ecs::{
+    component::Component,
+    query::{Changed, Or},
+    system::Query,
+};
+use bevy_math::{Rect, Vec2};
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::ComputedNode;
+
+/// The computed layout of a UI node, in logical pixels.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct NodeLayout {
+    /// The size of the node.
+    pub size: Vec2,
+    /// The position of the top left corner of the node, relative to the top left corner of the window.
+    pub position: Vec2,
+}
+
+impl NodeLayout {
+    /// Returns the rectangle that the node covers.
+    pub fn rect(&self) -> Rect {
+        Rect::from_corners(self.position, self.position + self.size)
+    }
+}
+
+/// Mirrors the layout of the entity into a state. Inserted by [`bind_layout`](crate::modify::ModifyFunctions::bind_layout).
+#[derive(Component, Clone, Copy)]
+pub(crate) struct LayoutWatcher(pub(crate) TypedStateId<Option<NodeLayout>>);
+
+impl Scope<'_> {
+    /// Creates a state that holds the computed layout of a UI node. The state is bound to a node with
+    /// [`bind_layout`](crate::modify::ModifyFunctions::bind_layout), and is `None` until the node has been laid out. The
+    /// composable is recomposed whenever the size or position of the node changes, which lets composables react to
+    /// the actual layout, for example by switching to a different layout below a certain width.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let layout = cx.use_layout();
+    /// let is_narrow = layout.is_some_and(|layout| layout.size.x < 600.0);
+    ///
+    /// Node {
+    ///     flex_direction: if is_narrow { FlexDirection::Column } else { FlexDirection::Row },
+    ///     ..default()
+    /// }
+    /// .bind_layout(&layout)
+    /// ```
+    pub fn use_layout(&mut self) -> State<Option<NodeLayout>> {
+        self.use_state(None)
+    }
+}
+
+// Watchers are also updated when they change, so that a state bound to a node that was already laid out gets its
+// layout.
+type ChangedLayouts<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static LayoutWatcher,
+        &'static ComputedNode,
+        &'static GlobalTransform,
+    ),
+    Or<(
+        Changed<LayoutWatcher>,
+        Changed<ComputedNode>,
+        Changed<GlobalTransform>,
+    )>,
+>;
+
+pub(crate) fn update_node_layouts(watchers: ChangedLayouts, mut state: SetState) {
+    for (LayoutWatcher(state_id), node, transform) in watchers.iter() {
+        let scale = node.inverse_scale_factor();
+        let size = node.size() * scale;
+        // The transform of a UI node is at the center of the node, in physical pixels.
+        let center = transform.translation().truncate() * scale;
+
+        let layout = NodeLayout {
+            size,
+            position: center - size / 2.0,
+        };
+
+        state.set_neq(*state_id, Some(layout));
+    }
+}
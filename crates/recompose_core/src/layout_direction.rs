@@ -0,0 +1,201 @@
+use crate::{store::Store, Root, Scope};
+use bevy_ecs::{
+    component::Component,
+    system::{Query, Res},
+    world::EntityWorldMut,
+};
+use bevy_text::{JustifyText, LineBreak, TextLayout};
+use bevy_ui::{AlignItems, FlexDirection, UiRect, Val};
+
+/// The reading direction of the UI. The direction is app-wide and stored in a [`Store`](crate::store::Store), so it
+/// can be switched at runtime with `ResMut<Store<LayoutDirection>>`. Composables read it with
+/// [`use_layout_direction`](Scope::use_layout_direction), and are recomposed when it changes.
+///
+/// Bevy's layout has no notion of a reading direction, so composables have to resolve logical directions themselves.
+/// [`LogicalRect`] and the methods of this type help with that. The built-in composables respect the direction: for
+/// example, sliders increase towards the left and dropdowns open towards the left in right-to-left layouts.
+///
+/// # Example
+/// ```ignore
+/// fn toolbar(cx: &mut Scope) -> impl Compose {
+///     let direction = cx.use_layout_direction();
+///
+///     Node {
+///         flex_direction: direction.row(),
+///         padding: LogicalRect::start(Val::Px(16.0)).resolve(direction),
+///         ..default()
+///     }
+///     .children(..)
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum LayoutDirection {
+    #[default]
+    LeftToRight,
+    RightToLeft,
+}
+
+impl LayoutDirection {
+    /// Whether the direction is right-to-left.
+    pub fn is_rtl(self) -> bool {
+        self == LayoutDirection::RightToLeft
+    }
+
+    /// Returns the flex direction that lays out children in reading order.
+    pub fn row(self) -> FlexDirection {
+        match self {
+            LayoutDirection::LeftToRight => FlexDirection::Row,
+            LayoutDirection::RightToLeft => FlexDirection::RowReverse,
+        }
+    }
+
+    /// Returns the cross axis alignment that aligns the children of a column to the start of the line.
+    pub fn align_start(self) -> AlignItems {
+        match self {
+            LayoutDirection::LeftToRight => AlignItems::FlexStart,
+            LayoutDirection::RightToLeft => AlignItems::FlexEnd,
+        }
+    }
+
+    /// Returns the text justification that aligns text to the start of the line.
+    pub fn justify_text(self) -> JustifyText {
+        match self {
+            LayoutDirection::LeftToRight => JustifyText::Left,
+            LayoutDirection::RightToLeft => JustifyText::Right,
+        }
+    }
+
+    /// Returns `1.0` for left-to-right and `-1.0` for right-to-left. Multiplying a horizontal distance, like the delta of
+    /// a drag, by the sign converts it from physical to logical pixels.
+    pub fn sign(self) -> f32 {
+        match self {
+            LayoutDirection::LeftToRight => 1.0,
+            LayoutDirection::RightToLeft => -1.0,
+        }
+    }
+}
+
+/// Like [`UiRect`], but with logical `start` and `end` edges instead of `left` and `right`. It is resolved into a
+/// `UiRect` for a given [`LayoutDirection`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct LogicalRect {
+    pub start: Val,
+    pub end: Val,
+    pub top: Val,
+    pub bottom: Val,
+}
+
+impl Default for LogicalRect {
+    fn default() -> Self {
+        Self::all(Val::ZERO)
+    }
+}
+
+impl LogicalRect {
+    /// Creates a rect with the same value on all edges.
+    pub const fn all(value: Val) -> Self {
+        Self {
+            start: value,
+            end: value,
+            top: value,
+            bottom: value,
+        }
+    }
+
+    /// Creates a rect with the given value on the start and end edges, and the given value on the top and bottom edges.
+    pub const fn axes(horizontal: Val, vertical: Val) -> Self {
+        Self {
+            start: horizontal,
+            end: horizontal,
+            top: vertical,
+            bottom: vertical,
+        }
+    }
+
+    /// Creates a rect with the given value on the start edge, and zero on all other edges.
+    pub const fn start(value: Val) -> Self {
+        Self {
+            start: value,
+            ..Self::all(Val::ZERO)
+        }
+    }
+
+    /// Creates a rect with the given value on the end edge, and zero on all other edges.
+    pub const fn end(value: Val) -> Self {
+        Self {
+            end: value,
+            ..Self::all(Val::ZERO)
+        }
+    }
+
+    /// Resolves the logical edges into physical edges for the given direction.
+    pub fn resolve(self, direction: LayoutDirection) -> UiRect {
+        let (left, right) = match direction {
+            LayoutDirection::LeftToRight => (self.start, self.end),
+            LayoutDirection::RightToLeft => (self.end, self.start),
+        };
+
+        UiRect {
+            left,
+            right,
+            top: self.top,
+            bottom: self.bottom,
+        }
+    }
+}
+
+impl Scope<'_> {
+    /// Returns the current [`LayoutDirection`] of the app. The composable is recomposed when the direction changes.
+    ///
+    /// The direction is read once per root and passed down the tree, so unlike a store selector, reading it is free.
+    pub fn use_layout_direction(&self) -> LayoutDirection {
+        self.inherited.layout_direction
+    }
+}
+
+/// Passes the [`LayoutDirection`] to the roots whenever it changes, which recomposes every scope of the roots.
+pub(crate) fn apply_layout_direction(
+    direction: Res<Store<LayoutDirection>>,
+    mut roots: Query<&mut Root>,
+) {
+    let direction = **direction;
+
+    for mut root in roots.iter_mut() {
+        let root = &mut *root;
+
+        let Some(scope) = root.scope.as_mut() else {
+            continue;
+        };
+
+        if scope.inherited.layout_direction != direction {
+            scope.inherited.layout_direction = direction;
+            root.compose.recompose_scope(scope);
+        }
+    }
+}
+
+/// The justification that a text composable inserted into its [`TextLayout`], so that it can tell whether the layout
+/// was set by the user, in which case it is left alone.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct DirectionalJustify(JustifyText);
+
+/// Aligns the text of the entity to the start of the line, unless the user has set a [`TextLayout`] of their own.
+pub(crate) fn justify_text(entity: &mut EntityWorldMut, justify: JustifyText) {
+    let is_own_layout = match (
+        entity.get::<TextLayout>(),
+        entity.get::<DirectionalJustify>(),
+    ) {
+        (None, _) => true,
+        (Some(layout), Some(DirectionalJustify(inserted))) => {
+            layout.justify == *inserted && layout.linebreak == LineBreak::default()
+        }
+        (Some(_), None) => false,
+    };
+
+    if is_own_layout && entity.get::<TextLayout>().map(|layout| layout.justify) != Some(justify) {
+        entity.insert((
+            TextLayout::new_with_justify(justify),
+            DirectionalJustify(justify),
+        ));
+    }
+}
@@ -1,59 +1,299 @@
-use bevy_app::{App, Plugin, PreUpdate};
+use bevy_app::{App, Plugin, PostUpdate, PreUpdate};
 use bevy_ecs::{
     component::{Component, ComponentHooks, ComponentId, StorageType},
     entity::Entity,
-    query::{Added, Changed},
-    schedule::IntoSystemConfigs,
-    system::{Commands, Query, SystemState},
+    query::{Added, Changed, Or, QueryState, With},
+    schedule::{IntoSystemConfigs, ScheduleLabel},
+    system::{BoxedSystem, Commands, Query, Res},
     world::{DeferredWorld, World},
 };
-use bevy_hierarchy::{BuildChildren, Parent};
+use bevy_hierarchy::{ChildBuild, ChildBuilder, Children, Parent};
+use bevy_input::InputSystem;
+use bevy_log::error;
+use bevy_picking::{
+    events::{Click, Down, Drag, DragEnd, DragStart, Move, Out, Over, Up},
+    PickSet,
+};
 use bevy_reflect::Reflect;
+use bevy_time::{Real, Time};
+use bevy_transform::TransformSystem;
+use bevy_ui::{Node, TargetCamera, UiSystem};
+use bevy_utils::Instant;
+use action::{dispatch_actions, ActionSubscriptions, InputMap};
+use asset::{update_asset_subscriptions, AssetSubscriptions};
+use context::RootContext;
+use cursor::{follow_pointers, ignore_picking_trees};
+use drag_drop::{cancel_drag_and_drop_on_escape, DragContext};
 use dyn_compose::DynCompose;
+use error_boundary::CompositionError;
+use feature_flags::FeatureFlags;
+use focus::{focus_on_press, InputFocus};
+use gestures::{cancel_drags_on_escape, recognize_long_presses, GestureSettings};
+use interaction::{update_interaction_states, DisabledStyle};
+use layout::update_node_layouts;
+use layout_direction::{apply_layout_direction, LayoutDirection};
+use localization::Localization;
+use modal::{close_modals_on_escape, restore_modal_focus, trap_modal_focus};
 use paste::paste;
-use scope::{Scope, ScopeId};
-use spawn::update_spawn_composables;
-use state::{SetState, StateChanged, StateSetter, StateSetterAction};
+use pointer_events::forward_pointer_event;
+use scope::{Inherited, Scope, ScopeId, ScopeRef};
+use scroll_view::{
+    apply_scroll_bindings, sync_scroll_bindings, trigger_scroll_wheel, update_scroll_metrics,
+};
+#[cfg(feature = "session")]
+use session::{
+    restore_session_states, stash_persistent_root, unstash_persistent_roots, PersistentRoots,
+    UiSession,
+};
+use settings::{begin_recompose_frame, RecomposeSettings};
+use smallvec::{smallvec, SmallVec};
+use spawn::{despawn_after_transition, update_spawn_composables};
+use state::{SetState, StateChanged, StateErrorMode, StateId, StateSetter, StateSetterAction};
+use state_mirror::{sync_state_mirrors, StateMirrors};
+use store::{Store, StoreAppExtension};
+use tooltip::{place_tooltips, update_tooltip_triggers};
+use watch::{update_component_subscriptions, ComponentSubscriptions};
+use window::{track_primary_window, WindowInfo};
 use std::{
+    any::Any,
+    borrow::Cow,
+    cmp::Reverse,
     collections::{HashMap, HashSet, VecDeque},
     hash::{DefaultHasher, Hash, Hasher},
     sync::{
         atomic::{AtomicUsize, Ordering},
-        Arc,
+        Arc, Mutex,
     },
+    time::Duration,
 };
 
+pub mod action;
+pub mod animated_list;
+pub mod asset;
+pub mod binding;
 pub mod bundle_extension;
+pub mod checkbox;
+pub mod context;
+pub mod cursor;
+pub mod diagnostics;
+pub mod dock;
+pub mod drag_drop;
+pub mod dropdown;
 pub mod dyn_compose;
+pub mod error_boundary;
+pub mod feature_flags;
+pub mod flex;
+pub mod focus;
+pub mod fragment;
+pub mod gestures;
+pub mod grid;
+pub mod headless;
+pub mod icon;
+pub mod interaction;
+pub mod island;
 pub mod keyed;
+pub mod label;
+pub mod layout;
+pub mod layout_direction;
+pub mod localization;
+pub mod memo;
+pub mod modal;
 pub mod modify;
+pub mod perf_overlay;
+pub mod pointer_events;
+pub mod radio_group;
+pub mod reducer;
+pub mod reorderable_list;
 pub mod scope;
+pub mod scroll_view;
+pub mod selection;
+#[cfg(feature = "session")]
+pub mod session;
+pub mod settings;
+pub mod show;
+pub mod slider;
 pub mod spawn;
+pub mod split_pane;
 pub mod state;
+pub mod state_mirror;
+pub mod store;
+pub mod styled_text;
+pub mod tabs;
+pub mod tooltip;
+pub mod watch;
+pub mod window;
 
 pub struct RecomposePlugin;
 
 impl Plugin for RecomposePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<StateSetter>()
+            .init_resource::<ComponentSubscriptions>()
+            .init_resource::<AssetSubscriptions>()
+            .init_resource::<GestureSettings>()
+            .init_resource::<InputFocus>()
+            .init_resource::<StateErrorMode>()
+            .init_resource::<RecomposeSettings>()
+            .init_resource::<InputMap>()
+            .init_resource::<ActionSubscriptions>()
+            .init_resource::<StateMirrors>()
+            .init_resource::<DisabledStyle>()
+            .init_store::<LayoutDirection>()
+            .init_store::<Localization>()
+            .init_store::<FeatureFlags>()
+            .init_store::<DragContext>()
+            .init_store::<WindowInfo>()
             .register_type::<ChildOrder>()
+            .add_observer(forward_pointer_event::<Over>)
+            .add_observer(forward_pointer_event::<Out>)
+            .add_observer(forward_pointer_event::<Down>)
+            .add_observer(forward_pointer_event::<Up>)
+            .add_observer(forward_pointer_event::<Click>)
+            .add_observer(forward_pointer_event::<Move>)
+            .add_observer(forward_pointer_event::<DragStart>)
+            .add_observer(forward_pointer_event::<Drag>)
+            .add_observer(forward_pointer_event::<DragEnd>)
+            .add_observer(focus_on_press)
+            .add_observer(restore_modal_focus)
+            .add_systems(
+                PreUpdate,
+                (begin_recompose_frame, track_primary_window).before(initial_compose),
+            )
             .add_systems(
                 PreUpdate,
                 (
-                    initial_compose,
-                    run_queued_systems,
-                    drop_decomposed_scopes,
-                    set_states,
-                    recompose,
-                    update_spawn_composables,
-                    order_children,
-                    decompose,
+                    close_modals_on_escape,
+                    cancel_drags_on_escape,
+                    cancel_drag_and_drop_on_escape,
+                    trap_modal_focus.before(dispatch_actions),
+                    dispatch_actions,
                 )
-                    .chain(),
+                    .after(InputSystem)
+                    .before(set_states),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    update_tooltip_triggers,
+                    recognize_long_presses,
+                    update_interaction_states,
+                )
+                    .after(PickSet::Focus)
+                    .before(set_states),
+            )
+            .add_systems(
+                PreUpdate,
+                trigger_scroll_wheel
+                    .after(PickSet::Focus)
+                    .before(set_states),
+            )
+            .add_systems(
+                PostUpdate,
+                (update_scroll_metrics, sync_scroll_bindings).after(UiSystem::PostLayout),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    follow_pointers,
+                    place_tooltips,
+                    ignore_picking_trees,
+                    apply_scroll_bindings,
+                )
+                    .before(UiSystem::Layout),
+            )
+            .add_systems(
+                PostUpdate,
+                update_node_layouts.after(TransformSystem::TransformPropagate),
             );
+
+        add_composition_systems(app, PreUpdate);
+        add_composition_systems(app, RecomposeFlush);
+
+        // Systems that are queued during a flush are run right away, rather than in the next frame. This is a separate
+        // instance of the system, so that the systems above can still be ordered against `run_queued_systems`.
+        app.add_systems(
+            RecomposeFlush,
+            (|world: &mut World, roots: &mut QueryState<&mut Root>| run_queued_systems(world, roots))
+                .after(decompose),
+        );
+
+        #[cfg(feature = "session")]
+        app.init_resource::<UiSession>()
+            .init_resource::<PersistentRoots>();
+
+        #[cfg(debug_assertions)]
+        app.init_resource::<state::ManualStateIds>();
     }
 }
 
+/// Adds the systems that compose the roots to the given schedule. They run in `PreUpdate` every frame, and in the
+/// [`RecomposeFlush`] schedule whenever it is run.
+fn add_composition_systems(app: &mut App, schedule: impl ScheduleLabel + Clone) {
+    app.add_systems(
+        schedule.clone(),
+        (
+            initial_compose,
+            run_queued_systems,
+            drop_decomposed_scopes,
+            set_states,
+            apply_layout_direction,
+            recompose,
+            update_spawn_composables,
+            order_children,
+            decompose,
+        )
+            .chain(),
+    )
+    .add_systems(
+        schedule.clone(),
+        (
+            update_component_subscriptions,
+            update_asset_subscriptions,
+            sync_state_mirrors,
+        )
+            .after(run_queued_systems)
+            .before(set_states),
+    )
+    .add_systems(
+        schedule.clone(),
+        apply_target_cameras.after(update_spawn_composables),
+    )
+    .add_systems(schedule.clone(), despawn_after_transition.after(decompose));
+
+    #[cfg(feature = "session")]
+    app.add_systems(
+        schedule.clone(),
+        unstash_persistent_roots.before(initial_compose),
+    )
+    .add_systems(
+        schedule,
+        restore_session_states.after(set_states).before(recompose),
+    );
+}
+
+/// The schedule that is run by [`recompose_flush`]. It contains the systems that compose the roots in `PreUpdate`.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecomposeFlush;
+
+/// Applies the states that were set since the last composition, and recomposes the affected composables immediately.
+///
+/// States that are set with [`SetState`] are applied once per frame, in `PreUpdate`, before the systems of `Update`
+/// run. States set by systems in `Update` are therefore only shown in the next frame. Ordering this system after them
+/// applies their changes in the same frame, which is useful for latency-sensitive UI, like nodes that follow the cursor
+/// or drag previews. It can be added multiple times, to different schedules.
+///
+/// Recompositions of a flush count towards the
+/// [`max_recompositions_per_frame`](settings::RecomposeSettings::max_recompositions_per_frame) of the frame, so
+/// recompositions above the limit are deferred to the next frame.
+///
+/// # Example
+/// ```ignore
+/// app.add_systems(Update, (update_drag_preview, recompose_flush).chain());
+/// ```
+pub fn recompose_flush(world: &mut World) {
+    world.run_schedule(RecomposeFlush);
+}
+
 // ===
 // UniqueId
 // ===
@@ -87,6 +327,28 @@ pub trait Compose: Send + Sync {
     fn name(&self) -> String {
         String::from("AnonymousCompose")
     }
+
+    /// Returns the composables that this composable groups together, like the elements of a tuple or a `Vec`, or `None`
+    /// if the composable is a single child. This lets containers work with each of their children separately, see
+    /// [`DynCompose::map_children`]. This is mostly used internally.
+    fn children_list(&self) -> Option<Vec<DynCompose>> {
+        None
+    }
+
+    /// Whether the composable is equal to the composable of the previous composition in the same place. Unchanged
+    /// composables are neither cloned nor recomposed when their parent recomposes, and neither are their children,
+    /// unless they have changed states of their own. This is a large win for deep trees that rarely change.
+    ///
+    /// Returns `false` by default. Composables that implement `PartialEq` can opt in by returning `self == previous`,
+    /// as long as their output only depends on their fields and their states. This is what the `ComposeView` derive
+    /// does, and [`MemoCompose`](memo::MemoCompose) does the same for composables that don't implement it themselves.
+    fn is_unchanged(&self, previous: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        let _ = previous;
+        false
+    }
 }
 
 impl Compose for () {
@@ -96,6 +358,10 @@ impl Compose for () {
         true
     }
 
+    fn children_list(&self) -> Option<Vec<DynCompose>> {
+        Some(Vec::new())
+    }
+
     fn name(&self) -> String {
         String::from("EmptyCompose")
     }
@@ -109,6 +375,13 @@ impl<C: Compose + Clone + 'static> Compose for Option<C> {
         }
     }
 
+    fn children_list(&self) -> Option<Vec<DynCompose>> {
+        match self {
+            Some(_) => None,
+            None => Some(Vec::new()),
+        }
+    }
+
     fn name(&self) -> String {
         match self {
             Some(_) => String::from("Some"),
@@ -117,6 +390,108 @@ impl<C: Compose + Clone + 'static> Compose for Option<C> {
     }
 }
 
+impl<C: Compose + Clone + 'static, E: Into<CompositionError> + Clone + Send + Sync + 'static>
+    Compose for Result<C, E>
+{
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        match self {
+            Ok(inner) => DynCompose::new(inner.clone()),
+            Err(error) => {
+                cx.report_error(error.clone());
+                DynCompose::new(())
+            }
+        }
+    }
+
+    fn children_list(&self) -> Option<Vec<DynCompose>> {
+        match self {
+            Ok(_) => None,
+            Err(_) => Some(Vec::new()),
+        }
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Ok(_) => String::from("Ok"),
+            Err(_) => String::from("Err"),
+        }
+    }
+}
+
+/// A `Cow` composes the composable it holds. This lets props that are stored elsewhere, like in a `static` or a
+/// `LazyLock`, be passed to children as `Cow::Borrowed` without being deep-cloned on every recomposition. Since scopes
+/// outlive the composition that created them, only `Cow<'static, C>` can be used as a child.
+///
+/// References can't implement `Compose` themselves, since `&F` is a function and would overlap with the implementation
+/// for functions.
+///
+/// # Example
+/// ```ignore
+/// static CREDITS: LazyLock<Credits> = LazyLock::new(Credits::load);
+///
+/// Flex::column().children((Title::new("Credits"), Cow::Borrowed(&*CREDITS)))
+/// ```
+impl<C: Compose + Clone> Compose for Cow<'_, C> {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        self.as_ref().compose(cx)
+    }
+
+    fn decompose(&self, cx: &mut Scope) {
+        self.as_ref().decompose(cx);
+    }
+
+    fn ignore_children(&self) -> bool {
+        self.as_ref().ignore_children()
+    }
+
+    fn name(&self) -> String {
+        self.as_ref().name()
+    }
+
+    fn children_list(&self) -> Option<Vec<DynCompose>> {
+        self.as_ref().children_list()
+    }
+
+    fn is_unchanged(&self, previous: &Self) -> bool {
+        self.as_ref().is_unchanged(previous.as_ref())
+    }
+}
+
+/// An `Arc` composes the composable it holds, so that a composable can be built once and shared between scopes
+/// without cloning it. When the parent recomposes with the same `Arc` as before, the composable is not recomposed.
+///
+/// # Example
+/// ```ignore
+/// let legend = Arc::new(Legend::new(&map_markers));
+///
+/// (Map::new(legend.clone()), Sidebar::new(legend))
+/// ```
+impl<C: Compose> Compose for Arc<C> {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        self.as_ref().compose(cx)
+    }
+
+    fn decompose(&self, cx: &mut Scope) {
+        self.as_ref().decompose(cx);
+    }
+
+    fn ignore_children(&self) -> bool {
+        self.as_ref().ignore_children()
+    }
+
+    fn name(&self) -> String {
+        self.as_ref().name()
+    }
+
+    fn children_list(&self) -> Option<Vec<DynCompose>> {
+        self.as_ref().children_list()
+    }
+
+    fn is_unchanged(&self, previous: &Self) -> bool {
+        Arc::ptr_eq(self, previous) || self.as_ref().is_unchanged(previous.as_ref())
+    }
+}
+
 impl<C: Compose + 'static, F: (Fn(&mut Scope) -> C) + Send + Sync> Compose for F {
     fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
         self(cx)
@@ -129,17 +504,17 @@ impl<C: Compose + 'static, F: (Fn(&mut Scope) -> C) + Send + Sync> Compose for F
 
 impl<K: Compose + Key + Clone + 'static> Compose for Vec<K> {
     fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
-        let scope_ids = cx.use_state(HashMap::<u64, ScopeId>::new());
+        let scope_ids = cx.use_state_lazy(HashMap::<AnyKey, ScopeId>::new);
 
         let mut modified_scope_ids = (*scope_ids).clone();
 
-        let keys = self.iter().map(|k| k.get_hashed()).collect::<Vec<_>>();
+        let keys = self.iter().map(AnyKey::new).collect::<Vec<_>>();
 
         let mut unique_keys = HashSet::new();
         let duplicate_key = keys.iter().find(|key| !unique_keys.insert(*key));
 
         if let Some(duplicate_key) = duplicate_key {
-            panic!("Duplicate key with hash {:?} found.", duplicate_key);
+            panic!("Duplicate key with hash {:?} found.", duplicate_key.hash);
         }
 
         let parent_entity = match cx.entity {
@@ -152,9 +527,10 @@ impl<K: Compose + Key + Clone + 'static> Compose for Vec<K> {
             None => cx.child_index.clone(),
         };
 
-        for (index, key_compose) in self.iter().enumerate() {
-            let key = key_compose.get_hashed();
-            let scope_id = scope_ids.get(&key);
+        let inherited = cx.inherited_by_children();
+
+        for (index, (key_compose, key)) in self.iter().zip(keys.iter()).enumerate() {
+            let scope_id = scope_ids.get(key);
             let scope =
                 scope_id.and_then(|scope_id| cx.children.iter_mut().find(|s| s.id == *scope_id));
 
@@ -162,23 +538,29 @@ impl<K: Compose + Key + Clone + 'static> Compose for Vec<K> {
                 scope.index = index;
                 scope.composer = Arc::new(key_compose.clone());
                 scope.parent_entity = parent_entity;
-                let mut child_index = parent_child_index.clone();
-                child_index.push(index);
-                scope.child_index = child_index;
+                scope.child_index = parent_child_index.child(index);
+                scope.inherited = inherited;
                 scope.composer.clone().recompose_scope(scope);
                 continue;
             }
 
             let compose = Arc::new(key_compose.clone());
-            let mut scope = Scope::new(compose, index, parent_entity, parent_child_index.clone());
+            let mut scope = Scope::new(
+                compose,
+                index,
+                parent_entity,
+                parent_child_index.clone(),
+                cx,
+            );
+            scope.set_key(key.hash);
             key_compose.recompose_scope(&mut scope);
 
-            modified_scope_ids.insert(key, scope.id);
+            modified_scope_ids.insert(key.clone(), scope.id);
             cx.children.push(scope);
         }
 
         for (key, scope_id) in modified_scope_ids.clone().iter() {
-            if keys.contains(key) {
+            if unique_keys.contains(key) {
                 continue;
             }
 
@@ -198,6 +580,15 @@ impl<K: Compose + Key + Clone + 'static> Compose for Vec<K> {
         true
     }
 
+    fn children_list(&self) -> Option<Vec<DynCompose>> {
+        let children = self
+            .iter()
+            .map(|child| DynCompose::new(child.clone()).with_key(AnyKey::new(child)))
+            .collect();
+
+        Some(children)
+    }
+
     fn name(&self) -> String {
         String::from("VecCompose")
     }
@@ -218,20 +609,26 @@ macro_rules! impl_compose_for_tuple {
                         None => cx.child_index.clone(),
                     };
 
+                    let inherited = cx.inherited_by_children();
+
                     $(
                         if let Some(existing_scope) = cx.children.get_mut($c) {
-                            existing_scope.composer = Arc::new(self.$c.clone());
-                            existing_scope.parent_entity = parent_entity;
-                            let mut child_index = parent_child_index.clone();
-                            child_index.push($c);
-                            existing_scope.child_index = child_index;
-                            existing_scope
-                                .composer
-                                .clone()
-                                .recompose_scope(existing_scope);
+                            recompose_child_scope(
+                                existing_scope,
+                                &self.$c,
+                                parent_entity,
+                                parent_child_index.child($c),
+                                inherited,
+                            );
                         } else {
                             let compose = Arc::new(self.$c.clone());
-                            let mut scope = Scope::new(compose, $c, parent_entity, parent_child_index.clone());
+                            let mut scope = Scope::new(
+                                compose,
+                                $c,
+                                parent_entity,
+                                parent_child_index.clone(),
+                                cx,
+                            );
                             self.$c.recompose_scope(&mut scope);
                             cx.children.push(scope);
                         }
@@ -242,6 +639,10 @@ macro_rules! impl_compose_for_tuple {
                     true
                 }
 
+                fn children_list(&self) -> Option<Vec<DynCompose>> {
+                    Some(vec![$(DynCompose::new(self.$c.clone())),*])
+                }
+
                 fn name(&self) -> String {
                     String::from("TupleCompose")
                 }
@@ -264,9 +665,36 @@ impl_compose_for_tuple!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
 // Key
 // ===
 
+/// A trait for values that can be used as keys. It is implemented for all types that are `Hash`, `Eq` and `Clone`, such
+/// as integers and strings.
+pub trait KeyValue: Hash + Eq + Clone + Send + Sync + 'static {}
+
+impl<T: Hash + Eq + Clone + Send + Sync + 'static> KeyValue for T {}
+
+/// A trait that identifies a composable in a `Vec` between recompositions. Keys must be unique within the same `Vec`.
 pub trait Key: Send + Sync {
     fn key(&self) -> &impl Hash;
 
+    /// Returns the key as a value that is compared by equality, so that two keys whose hashes collide aren't mistaken
+    /// for each other. Returns `None` by default, in which case keys are only compared by their hash.
+    /// [`Keyed`](keyed::Keyed) and the `Key` derive return their key here.
+    ///
+    /// # Example
+    /// ```ignore
+    /// impl Key for Todo {
+    ///     fn key(&self) -> &impl Hash {
+    ///         &self.id
+    ///     }
+    ///
+    ///     fn key_value(&self) -> Option<KeyValueRef<'_>> {
+    ///         Some(KeyValueRef::new(&self.id))
+    ///     }
+    /// }
+    /// ```
+    fn key_value(&self) -> Option<KeyValueRef<'_>> {
+        None
+    }
+
     fn get_hashed(&self) -> u64 {
         let hash = self.key();
         let mut hasher = DefaultHasher::new();
@@ -275,6 +703,85 @@ pub trait Key: Send + Sync {
     }
 }
 
+/// A type-erased wrapper around a [`KeyValue`]. Keys are compared by their value rather than only by their hash, so that
+/// two different keys with the same hash don't collide.
+trait DynKeyValue: Send + Sync {
+    fn as_any(&self) -> &dyn Any;
+
+    fn dyn_eq(&self, other: &dyn DynKeyValue) -> bool;
+
+    fn to_arc(&self) -> Arc<dyn DynKeyValue>;
+}
+
+impl<T: KeyValue> DynKeyValue for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn DynKeyValue) -> bool {
+        other.as_any().downcast_ref::<T>() == Some(self)
+    }
+
+    fn to_arc(&self) -> Arc<dyn DynKeyValue> {
+        Arc::new(self.clone())
+    }
+}
+
+/// A borrowed key that is compared by equality. See [`Key::key_value`].
+pub struct KeyValueRef<'a>(&'a dyn DynKeyValue);
+
+impl<'a> KeyValueRef<'a> {
+    pub fn new(value: &'a impl KeyValue) -> Self {
+        Self(value)
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct AnyKey {
+    pub(crate) hash: u64,
+    /// The value of the key, or `None` if the key is only compared by its hash. See [`Key::key_value`].
+    value: Option<Arc<dyn DynKeyValue>>,
+}
+
+impl AnyKey {
+    pub(crate) fn new(key: &impl Key) -> Self {
+        Self {
+            hash: key.get_hashed(),
+            value: key.key_value().map(|value| value.0.to_arc()),
+        }
+    }
+
+    /// Creates a key from a value directly. Keys of different types are never equal, even if their hashes are.
+    pub(crate) fn from_value(value: impl KeyValue) -> Self {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+
+        Self {
+            hash: hasher.finish(),
+            value: Some(Arc::new(value)),
+        }
+    }
+}
+
+impl PartialEq for AnyKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash
+            && match (&self.value, &other.value) {
+                (Some(value), Some(other)) => value.dyn_eq(other.as_ref()),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+}
+
+impl Eq for AnyKey {}
+
+impl Hash for AnyKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.hash.hash(state);
+    }
+}
+
 /// A trait that (re)composes and decomposes a scope. It is used to act as a "wrapper" for the `Compose` trait, which
 /// itself is not dyn-compatible. Since this trait is dyn-compatible, it can be stored in a `Box` or `Arc`.
 pub trait AnyCompose: Send + Sync {
@@ -288,12 +795,36 @@ pub trait AnyCompose: Send + Sync {
 
     /// Returns the name of the composable. This is mostly intended for debugging purposes.
     fn get_name(&self) -> String;
+
+    /// Returns the composables that the composable groups together. See [`Compose::children_list`].
+    fn get_children_list(&self) -> Option<Vec<DynCompose>>;
+
+    /// Returns the composable as `Any`, so that it can be downcast to its concrete type.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns the composable as mutable `Any`, so that it can be downcast to its concrete type.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
 }
 
-impl<C: Compose> AnyCompose for C {
+impl<C: Compose + 'static> AnyCompose for C {
     // TODO: Make this take in the new compose value and index, since we basicall always need to set it anyways
     fn recompose_scope(&self, scope: &mut Scope) {
+        #[cfg(feature = "trace")]
+        let _span = tracing::info_span!("recompose", composable = %self.name(), scope = ?scope.id)
+            .entered();
+
+        scope.context.counters.record(self);
+
+        scope.update_recompose_reason();
+        scope.track_recomposition();
         scope.state_index = 0;
+        scope.unmount_systems.clear();
+        scope.despawn_guards.clear();
+        scope.is_poisoned = false;
+        scope.allows_recompose_loop = false;
+
+        #[cfg(feature = "debug_assert_hooks")]
+        scope.hook_calls.clear();
 
         for state in scope.states.iter_mut() {
             if matches!(state.changed, StateChanged::Queued) {
@@ -303,6 +834,11 @@ impl<C: Compose> AnyCompose for C {
 
         let child = self.compose(scope);
 
+        #[cfg(feature = "debug_assert_hooks")]
+        scope.finish_hook_signature();
+
+        scope.detect_recompose_loop();
+
         for state in scope.states.iter_mut() {
             if matches!(state.changed, StateChanged::Changed) {
                 state.changed = StateChanged::Unchanged;
@@ -313,6 +849,30 @@ impl<C: Compose> AnyCompose for C {
             return;
         }
 
+        // A poisoned scope composes nothing until it composes without state errors again.
+        if scope.is_poisoned {
+            for child_scope in scope.children.iter_mut() {
+                child_scope.will_decompose = true;
+            }
+            return;
+        }
+
+        let max_depth = scope.context.settings.max_depth();
+
+        if scope.path.segments().len() > max_depth {
+            error!(
+                "`{}` is nested deeper than {} scopes, so its children are not composed. This usually means that a \
+                composable composes itself without an end.",
+                self.name(),
+                max_depth
+            );
+
+            for child_scope in scope.children.iter_mut() {
+                child_scope.will_decompose = true;
+            }
+            return;
+        }
+
         let parent_entity = match scope.entity {
             Some(entity) => entity,
             None => scope.parent_entity,
@@ -323,20 +883,27 @@ impl<C: Compose> AnyCompose for C {
             None => scope.child_index.clone(),
         };
 
+        let inherited = scope.inherited_by_children();
+
         if let Some(child_scope) = scope.children.first_mut() {
-            child_scope.composer = Arc::new(child);
-            // TODO: Can we do this in a way that doesn't require us to remember to set these values?
-            child_scope.parent_entity = parent_entity;
-            let mut child_index = parent_child_index;
-            child_index.push(0);
-            child_scope.child_index = child_index;
-            child_scope.composer.clone().recompose_scope(child_scope);
+            recompose_child_scope(
+                child_scope,
+                child,
+                parent_entity,
+                parent_child_index.child(0),
+                inherited,
+            );
             return;
         };
 
         let child_compose = Arc::new(child);
-        let mut child_scope =
-            Scope::new(child_compose.clone(), 0, parent_entity, parent_child_index);
+        let mut child_scope = Scope::new(
+            child_compose.clone(),
+            0,
+            parent_entity,
+            parent_child_index,
+            scope,
+        );
 
         child_compose.recompose_scope(&mut child_scope);
 
@@ -344,35 +911,189 @@ impl<C: Compose> AnyCompose for C {
     }
 
     fn decompose_scope(&self, scope: &mut Scope) {
+        #[cfg(feature = "trace")]
+        let _span = tracing::info_span!("decompose", composable = %self.name(), scope = ?scope.id)
+            .entered();
+
         self.decompose(scope);
+        scope.queued_systems.append(&mut scope.unmount_systems);
+        scope
+            .queued_systems
+            .append(&mut scope.retained_unmount_systems);
     }
 
     fn get_name(&self) -> String {
         self.name()
     }
+
+    fn get_children_list(&self) -> Option<Vec<DynCompose>> {
+        self.children_list()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// A new composable for an existing child scope, either owned or borrowed from the parent composable.
+trait ChildCompose<C> {
+    fn get(&self) -> &C;
+
+    /// Writes the composable into the previous composable of the scope.
+    fn write_into(self, previous: &mut C);
+
+    fn into_composer(self) -> Arc<dyn AnyCompose>;
+}
+
+impl<C: Compose + 'static> ChildCompose<C> for C {
+    fn get(&self) -> &C {
+        self
+    }
+
+    fn write_into(self, previous: &mut C) {
+        *previous = self;
+    }
+
+    fn into_composer(self) -> Arc<dyn AnyCompose> {
+        Arc::new(self)
+    }
+}
+
+impl<C: Compose + Clone + 'static> ChildCompose<C> for &C {
+    fn get(&self) -> &C {
+        self
+    }
+
+    fn write_into(self, previous: &mut C) {
+        // Unlike `clone`, `clone_from` can reuse the allocations of the previous composable, like its strings.
+        previous.clone_from(self);
+    }
+
+    fn into_composer(self) -> Arc<dyn AnyCompose> {
+        Arc::new(self.clone())
+    }
+}
+
+/// Recomposes an existing child scope with a new composable. When the composable is unchanged, see
+/// [`Compose::is_unchanged`], and the scope is still in the same place, only the descendants with changed states are
+/// recomposed. Otherwise, the new composable is written into the previous one when it has the same type and isn't
+/// shared, so that recomposing a tree doesn't allocate a new composer for every scope.
+fn recompose_child_scope<C: Compose + 'static>(
+    child_scope: &mut Scope,
+    compose: impl ChildCompose<C>,
+    parent_entity: Entity,
+    child_index: ChildIndex,
+    inherited: Inherited,
+) {
+    let previous = child_scope.composer.as_any().downcast_ref::<C>();
+    let is_unchanged = previous.is_some_and(|previous| compose.get().is_unchanged(previous));
+
+    if is_unchanged
+        && child_scope.parent_entity == parent_entity
+        && child_scope.child_index == child_index
+        && child_scope.inherited == inherited
+    {
+        let now = child_scope.context.settings.now();
+        let has_queued_states = child_scope
+            .states
+            .iter()
+            .any(|state| matches!(state.changed, StateChanged::Queued))
+            && !child_scope.is_throttled(now)
+            && !child_scope.has_reached_recompose_limit();
+
+        match has_queued_states {
+            true => {
+                child_scope.last_recomposed = now;
+                child_scope.composer.clone().recompose_scope(child_scope);
+            }
+            false => recompose_queued_descendants(child_scope, now),
+        }
+
+        return;
+    }
+
+    match Arc::get_mut(&mut child_scope.composer)
+        .and_then(|composer| composer.as_any_mut().downcast_mut::<C>())
+    {
+        Some(previous) => compose.write_into(previous),
+        None => child_scope.composer = compose.into_composer(),
+    }
+
+    // TODO: Can we do this in a way that doesn't require us to remember to set these values?
+    child_scope.parent_entity = parent_entity;
+    child_scope.child_index = child_index;
+    child_scope.inherited = inherited;
+    child_scope.composer.clone().recompose_scope(child_scope);
+}
+
+/// Recomposes the descendants of the scope that have changed states. This is needed when the children of a scope are
+/// skipped, since the `recompose` system doesn't look into the subtrees of the scopes it recomposes.
+fn recompose_queued_descendants(scope: &mut Scope, now: Option<Duration>) {
+    let mut scopes = VecDeque::from_iter(scope.children.iter_mut());
+
+    while let Some(scope) = scopes.pop_front() {
+        if scope.is_inactive {
+            continue;
+        }
+
+        if should_recompose(scope, now) {
+            scope.last_recomposed = now;
+            let composer = scope.composer.clone();
+
+            composer.recompose_scope(scope);
+            continue;
+        }
+
+        for child in scope.children.iter_mut().rev() {
+            scopes.push_front(child);
+        }
+    }
 }
 
 // ===
 // Systems
 // ===
 
-fn initial_compose(mut roots: Query<(Entity, &mut Root), Added<Root>>) {
-    for (entity, mut root) in roots.iter_mut() {
-        let mut scope = Scope::as_root_scope(entity, root.compose.clone());
+// The scope trees of the roots are independent of each other, so most of the systems below process the roots in
+// parallel.
+pub(crate) fn initial_compose(
+    direction: Res<Store<LayoutDirection>>,
+    mut roots: Query<(Entity, &mut Root), Added<Root>>,
+) {
+    roots.par_iter_mut().for_each(|(entity, mut root)| {
+        // The system also runs in the `RecomposeFlush` schedule, which sees the roots that were added since its own
+        // last run, even if they were already composed in `PreUpdate`.
+        if root.scope.is_some() {
+            return;
+        }
+
+        let mut scope = Scope::as_root_scope(entity, root.compose.clone(), root.context.clone());
+        scope.inherited.layout_direction = **direction;
 
         root.compose.recompose_scope(&mut scope);
 
         root.scope = Some(scope);
-    }
+    });
 }
 
-fn run_queued_systems(world: &mut World) {
-    let mut roots_system_state = SystemState::<Query<&mut Root>>::new(world);
-    let mut roots = roots_system_state.get_mut(world);
-
+/// Runs the systems that were queued by the scopes during the last composition. The query of the roots is cached
+/// between frames. By default, the deferred buffers of every system, like `Commands`, are applied right after it has
+/// run, so each system sees the commands of the systems before it. Some systems rely on this, like the ones that hide
+/// and show the entities of a [`DynCompose`].
+///
+/// With [`batch_queued_systems`](settings::RecomposeSettings::batch_queued_systems), the systems are run in batches
+/// instead: all systems are initialized first, then they are run, and their buffers are applied together afterwards.
+/// This avoids applying the buffers to the `World` after every single system, which is slow when many scopes use
+/// [`run_system`](Scope::run_system). Exclusive systems end the current batch, so they see the commands of all systems
+/// that were queued before them.
+pub(crate) fn run_queued_systems(world: &mut World, roots: &mut QueryState<&mut Root>) {
     let mut queued_systems = vec![];
 
-    for mut root in roots.iter_mut() {
+    for mut root in roots.iter_mut(world) {
         let Some(scope) = &mut root.scope else {
             continue;
         };
@@ -380,7 +1101,20 @@ fn run_queued_systems(world: &mut World) {
         let mut scopes = VecDeque::from([scope]);
 
         while let Some(scope) = scopes.pop_front() {
-            queued_systems.append(&mut scope.queued_systems);
+            if !scope.queued_systems.is_empty() {
+                #[cfg(feature = "trace")]
+                let span = tracing::info_span!(
+                    "queued_system",
+                    composable = %scope.composer.get_name(),
+                    scope = ?scope.id
+                );
+
+                queued_systems.extend(scope.queued_systems.drain(..).map(|system| QueuedSystem {
+                    system,
+                    #[cfg(feature = "trace")]
+                    span: span.clone(),
+                }));
+            }
 
             for child in scope.children.iter_mut().rev() {
                 scopes.push_front(child);
@@ -388,172 +1122,546 @@ fn run_queued_systems(world: &mut World) {
         }
     }
 
-    for mut system in queued_systems {
-        system.initialize(world);
-        system.run((), world);
-        system.apply_deferred(world);
+    if queued_systems.is_empty() {
+        return;
+    }
+
+    let batch = world
+        .get_resource::<RecomposeSettings>()
+        .is_some_and(|settings| settings.batch_queued_systems);
+
+    if !batch {
+        for mut queued in queued_systems {
+            #[cfg(feature = "trace")]
+            let _span = queued.span.enter();
+
+            queued.system.initialize(world);
+            queued.system.run((), world);
+        }
+
+        return;
+    }
+
+    for queued in queued_systems.iter_mut() {
+        queued.system.initialize(world);
+    }
+
+    let mut batch_start = 0;
+
+    for index in 0..queued_systems.len() {
+        let queued = &mut queued_systems[index];
+
+        #[cfg(feature = "trace")]
+        let _span = queued.span.clone().entered();
+
+        if queued.system.is_exclusive() {
+            apply_queued_systems(&mut queued_systems[batch_start..index], world);
+            queued_systems[index].system.run((), world);
+            batch_start = index + 1;
+            continue;
+        }
+
+        let world_cell = world.as_unsafe_world_cell();
+        queued.system.update_archetype_component_access(world_cell);
+        // SAFETY: We have exclusive access to the world, and the archetype component access has just been updated.
+        unsafe { queued.system.run_unsafe((), world_cell) };
+    }
+
+    apply_queued_systems(&mut queued_systems[batch_start..], world);
+}
+
+/// Applies the deferred buffers of the given systems, in the order that they were queued in.
+fn apply_queued_systems(queued_systems: &mut [QueuedSystem], world: &mut World) {
+    for queued in queued_systems.iter_mut() {
+        queued.system.apply_deferred(world);
     }
 }
 
+/// A system queued by a scope, together with the span of the scope when tracing is enabled.
+struct QueuedSystem {
+    system: BoxedSystem<(), ()>,
+    #[cfg(feature = "trace")]
+    span: tracing::Span,
+}
+
 fn drop_decomposed_scopes(mut roots: Query<&mut Root>) {
-    for mut root in roots.iter_mut() {
+    roots.par_iter_mut().for_each(|mut root| {
         let Some(scope) = &mut root.scope else {
-            continue;
+            return;
         };
 
         let mut scopes = VecDeque::from([scope]);
 
         while let Some(scope) = scopes.pop_front() {
-            scope.children.retain(|child| !child.will_decompose);
+            scope
+                .children
+                .retain(|child| !child.will_decompose || child.is_leaving);
             for child in scope.children.iter_mut().rev() {
                 scopes.push_front(child);
             }
         }
-    }
+    });
 }
 
-fn set_states(mut setter: SetState, mut roots: Query<&mut Root>) {
-    for mut root in roots.iter_mut() {
+pub(crate) fn set_states(mut setter: SetState, mut roots: Query<&mut Root>) {
+    if setter.setter.queued.is_empty() {
+        return;
+    }
+
+    let queued = &setter.setter.queued;
+    let applied_ids = Mutex::new(Vec::new());
+
+    roots.par_iter_mut().for_each(|mut root| {
         let Some(scope) = &mut root.scope else {
-            continue;
+            return;
         };
 
+        let mut root_applied_ids = Vec::new();
         let mut scopes = VecDeque::from([scope]);
 
         while let Some(scope) = scopes.pop_front() {
-            for state in scope.states.iter_mut() {
-                let Some(state_setter_action) = setter.setter.queued.remove(&state.id) else {
+            // The states of inactive scopes are set once they become active again.
+            if scope.is_inactive {
+                continue;
+            }
+
+            for (index, state) in scope.states.iter_mut().enumerate() {
+                let indexed_id = StateId::Indexed(scope.id, index);
+                let Some((id, state_setter_action)) = queued
+                    .get_key_value(&state.id)
+                    .or_else(|| queued.get_key_value(&indexed_id))
+                else {
                     continue;
                 };
 
                 let (value, should_change) = match state_setter_action {
-                    StateSetterAction::Set(value, should_change) => (value, should_change),
+                    StateSetterAction::Set(value, should_change) => (value.clone(), *should_change),
                     StateSetterAction::Modify(f) => f(state.value.clone()),
+                    StateSetterAction::Reset => (state.initial.clone(), true),
                 };
 
                 state.value = value;
 
                 if should_change {
                     state.changed = StateChanged::Queued;
+                    scope.externally_set_states.push(state.id);
                 }
+
+                root_applied_ids.push(*id);
             }
 
             for child in scope.children.iter_mut().rev() {
                 scopes.push_front(child);
             }
         }
+
+        applied_ids.lock().unwrap().append(&mut root_applied_ids);
+    });
+
+    // Actions for states that don't exist (yet) are kept in the queue.
+    for id in applied_ids.into_inner().unwrap() {
+        setter.setter.queued.remove(&id);
     }
 }
 
-fn recompose(mut roots: Query<&mut Root>) {
-    for mut root in roots.iter_mut() {
+pub(crate) fn recompose(mut roots: Query<&mut Root>, time: Option<Res<Time<Real>>>) {
+    let now = time.map(|time| time.elapsed());
+
+    roots.par_iter_mut().for_each(|mut root| {
+        let budget = root.recompose_budget;
+
         let Some(scope) = &mut root.scope else {
-            continue;
+            return;
         };
 
-        let mut scopes = VecDeque::from([scope]);
+        match budget {
+            Some(budget) => recompose_within_budget(scope, now, budget),
+            None => recompose_all(scope, now),
+        }
+    });
+}
 
-        while let Some(scope) = scopes.pop_front() {
-            if scope
-                .states
-                .iter()
-                .any(|state| matches!(state.changed, StateChanged::Queued))
-                && !scope.will_decompose
-            {
-                let composer = scope.composer.clone();
+/// Whether the scope has changed states and should be recomposed in the current frame.
+fn should_recompose(scope: &Scope, now: Option<Duration>) -> bool {
+    scope
+        .states
+        .iter()
+        .any(|state| matches!(state.changed, StateChanged::Queued))
+        && scope.can_recompose()
+        && !scope.is_throttled(now)
+        && !scope.has_reached_recompose_limit()
+}
 
-                composer.recompose_scope(scope);
-                continue;
-            }
+fn recompose_all(scope: &mut Scope, now: Option<Duration>) {
+    let mut scopes = VecDeque::from([scope]);
 
-            for child in scope.children.iter_mut().rev() {
-                scopes.push_front(child);
-            }
+    while let Some(scope) = scopes.pop_front() {
+        if scope.is_inactive {
+            continue;
+        }
+
+        if should_recompose(scope, now) {
+            scope.last_recomposed = now;
+            let composer = scope.composer.clone();
+
+            composer.recompose_scope(scope);
+            continue;
+        }
+
+        for child in scope.children.iter_mut().rev() {
+            scopes.push_front(child);
         }
     }
 }
 
-#[derive(Component, Clone, PartialEq, PartialOrd, Reflect)]
-pub(crate) struct ChildOrder(pub ChildIndex);
+/// Recomposes the changed scopes in the order of their priority, until the budget is used up. The remaining scopes keep
+/// their queued states, so they are recomposed in one of the next frames.
+fn recompose_within_budget(scope: &mut Scope, now: Option<Duration>, budget: Duration) {
+    let started = Instant::now();
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Reflect)]
-pub(crate) struct ChildIndex(pub Vec<usize>);
+    // The changed scopes are collected by their position in the tree. Their subtrees don't overlap, since the subtree
+    // of a changed scope is recomposed together with it, so recomposing one of them doesn't move the others.
+    let mut changed_scopes = Vec::new();
+    let mut scopes = VecDeque::from([(&*scope, Vec::new())]);
 
-impl ChildIndex {
-    pub fn new(index: usize) -> Self {
-        Self(vec![index])
+    while let Some((scope, position)) = scopes.pop_front() {
+        if scope.is_inactive {
+            continue;
+        }
+
+        if should_recompose(scope, now) {
+            changed_scopes.push((scope.recompose_priority, position));
+            continue;
+        }
+
+        for (index, child) in scope.children.iter().enumerate().rev() {
+            let mut child_position = position.clone();
+            child_position.push(index);
+            scopes.push_front((child, child_position));
+        }
     }
 
-    pub fn push(&mut self, index: usize) {
-        self.0.push(index);
+    // The sort is stable, so scopes with the same priority stay in the order of the tree.
+    changed_scopes.sort_by_key(|(priority, _)| Reverse(*priority));
+
+    for (index, (_, position)) in changed_scopes.iter().enumerate() {
+        // At least one scope is recomposed in every frame, so that a scope that takes longer than the budget doesn't
+        // stall the root.
+        if index > 0 && started.elapsed() >= budget {
+            break;
+        }
+
+        let scope = position
+            .iter()
+            .fold(&mut *scope, |scope, index| &mut scope.children[*index]);
+
+        scope.last_recomposed = now;
+        let composer = scope.composer.clone();
+
+        composer.recompose_scope(scope);
     }
 }
 
-fn order_children(
-    mut commands: Commands,
-    parents: Query<(Entity, &Parent, &ChildOrder)>,
-    has_order_changed: Query<(), Changed<ChildOrder>>,
-) {
-    // TODO: Check if this helps with performance
-    if has_order_changed.is_empty() {
-        return;
+/// The order of an entity among its siblings. Composed entities are sorted by the `order` first, which is set by the
+/// [`order`](crate::modify::ModifyFunctions::order) modifier, and by the position of their composable second. Entities
+/// without a `ChildOrder` are sorted before the ordered ones. Only UI nodes are ordered.
+///
+/// The positions of composables are spaced apart, so that entities that are spawned outside of the composition can be
+/// placed between composed siblings, by inserting an order that was created with [`before`](ChildOrder::before) or
+/// [`after`](ChildOrder::after).
+///
+/// # Example
+/// ```ignore
+/// fn insert_banners(
+///     mut commands: Commands,
+///     featured: Query<(&Parent, &ChildOrder), Added<FeaturedItem>>,
+/// ) {
+///     for (parent, order) in featured.iter() {
+///         commands
+///             .spawn((Banner, Node::default(), order.after()))
+///             .set_parent(parent.get());
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Reflect)]
+pub struct ChildOrder {
+    order: i32,
+    index: ChildIndex,
+}
+
+impl ChildOrder {
+    pub(crate) fn new(order: i32, index: ChildIndex) -> Self {
+        Self { order, index }
+    }
+
+    /// Returns an order that places an entity before all of its composed siblings.
+    pub fn first() -> Self {
+        Self::new(i32::MIN, ChildIndex(SmallVec::new()))
+    }
+
+    /// Returns an order that places an entity after all of its composed siblings.
+    pub fn last() -> Self {
+        Self::new(i32::MAX, ChildIndex(smallvec![usize::MAX]))
+    }
+
+    /// Returns the order that was set with the [`order`](crate::modify::ModifyFunctions::order) modifier.
+    pub fn order(&self) -> i32 {
+        self.order
+    }
+
+    /// Returns the position of the composable that spawned the entity.
+    pub fn index(&self) -> &ChildIndex {
+        &self.index
+    }
+
+    /// Returns an order that places an entity directly before the entity with this order, and after its previous
+    /// composed sibling.
+    pub fn before(&self) -> Self {
+        Self::new(
+            self.order,
+            self.index.offset(|position| position.saturating_sub(1)),
+        )
     }
 
-    let mut parent_children = HashMap::<Entity, Vec<(Entity, ChildOrder)>>::new();
+    /// Returns an order that places an entity directly after the entity with this order, and before its next composed
+    /// sibling.
+    pub fn after(&self) -> Self {
+        Self::new(
+            self.order,
+            self.index.offset(|position| position.saturating_add(1)),
+        )
+    }
+}
 
-    for (entity, parent, order) in parents.iter() {
-        let parent_entity = parent.get();
-        let entry = parent_children.get_mut(&parent_entity);
+/// The position of a composable among the composables that spawn entities into the same parent. It is made up of the
+/// positions of the composable and of its ancestors up to the closest ancestor with an entity. The positions are spaced
+/// [`GAP`](ChildIndex::GAP) apart.
+// Like the stable path, the index is cloned for every scope, and it is usually short, so its segments are stored inline.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Reflect)]
+pub struct ChildIndex(SmallVec<[usize; 4]>);
+
+impl ChildIndex {
+    /// The distance between the positions of two neighboring composables, which leaves room for `GAP - 1` entities
+    /// between them.
+    pub const GAP: usize = 1024;
 
-        if let Some(entry) = entry {
-            entry.push((entity, order.clone()));
-        } else {
-            parent_children.insert(parent_entity, vec![(entity, order.clone())]);
+    pub(crate) fn new(index: usize) -> Self {
+        Self(smallvec![Self::position(index)])
+    }
+
+    /// Returns the index of the child with the given index, without cloning and growing the segments separately.
+    pub(crate) fn child(&self, index: usize) -> Self {
+        let mut segments = SmallVec::with_capacity(self.0.len() + 1);
+        segments.extend_from_slice(&self.0);
+        segments.push(Self::position(index));
+        Self(segments)
+    }
+
+    pub(crate) fn push(&mut self, index: usize) {
+        self.0.push(Self::position(index));
+    }
+
+    /// Returns the positions that make up the index, starting from the closest ancestor with an entity.
+    pub fn segments(&self) -> &[usize] {
+        &self.0
+    }
+
+    // The first position is one gap in, so that there is room before the first child as well.
+    fn position(index: usize) -> usize {
+        (index + 1) * Self::GAP
+    }
+
+    fn offset(&self, offset: impl FnOnce(usize) -> usize) -> Self {
+        let mut segments = self.0.clone();
+
+        if let Some(last) = segments.last_mut() {
+            *last = offset(*last);
         }
+
+        Self(segments)
     }
+}
 
-    for (parent_entity, children) in parent_children.iter_mut() {
-        // We never expect to have NaN values, so we can safely unwrap here
-        children.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+// Only UI nodes are ordered, since the order of children doesn't matter for other entities (like the ones in world-space).
+// The children are sorted in place, rather than reparented, so that only the entities that are out of order move, and
+// no hierarchy events are sent.
+#[allow(clippy::type_complexity)]
+pub(crate) fn order_children(
+    changed: Query<&Parent, (With<Node>, Or<(Changed<ChildOrder>, Changed<Parent>)>)>,
+    mut children: Query<&mut Children>,
+    orders: Query<&ChildOrder, With<Node>>,
+) {
+    let parents = changed.iter().map(Parent::get).collect::<HashSet<_>>();
 
-        for (entity, _) in children.iter() {
-            let Some(mut ec) = commands.get_entity(*entity) else {
-                continue;
-            };
+    for parent in parents {
+        let Ok(mut children) = children.get_mut(parent) else {
+            continue;
+        };
 
-            ec.set_parent(*parent_entity);
+        let order = |entity: &Entity| orders.get(*entity).ok();
+
+        // Sorting marks the children as changed, which makes Bevy lay out the parent again.
+        if children.is_sorted_by_key(order) {
+            continue;
         }
+
+        children.sort_by_key(order);
     }
 }
 
 fn decompose(mut roots: Query<&mut Root>) {
-    for mut root in roots.iter_mut() {
+    roots.par_iter_mut().for_each(|mut root| {
         let Some(scope) = &mut root.scope else {
-            continue;
+            return;
         };
 
-        let mut scopes = VecDeque::from([scope]);
+        // The descendants of a decomposed scope have no pending despawn guards, so they aren't checked again.
+        let mut scopes = VecDeque::from([(scope, false)]);
+
+        while let Some((scope, is_ancestor_decomposed)) = scopes.pop_front() {
+            let mut is_decomposed = is_ancestor_decomposed;
 
-        while let Some(scope) = scopes.pop_front() {
             if scope.will_decompose {
-                let composer = scope.composer.clone();
-                composer.decompose_scope(scope);
+                if !is_ancestor_decomposed && scope.has_pending_despawn_guard_in_tree() {
+                    scope.leave();
+                } else {
+                    scope.is_leaving = false;
+                    is_decomposed = true;
+
+                    let composer = scope.composer.clone();
+                    composer.decompose_scope(scope);
+                }
 
-                for child in scope.children.iter_mut() {
-                    child.will_decompose = true;
+                // A scope that waits for its own guards keeps its children, so that it can show an exit animation.
+                if is_decomposed || !scope.has_pending_despawn_guard() {
+                    for child in scope.children.iter_mut() {
+                        child.will_decompose = true;
+                    }
                 }
             }
 
             for child in scope.children.iter_mut().rev() {
-                scopes.push_front(child);
+                scopes.push_front((child, is_decomposed));
             }
         }
-    }
+    });
 }
 
 pub struct Root {
     compose: Arc<dyn AnyCompose>,
     scope: Option<Scope<'static>>,
+    /// Restored values of states that haven't been composed yet. See [`Root::restore_states`].
+    #[cfg(feature = "session")]
+    pending_states: std::collections::BTreeMap<String, String>,
+    /// The maximum time that is spent on recomposing the root in a single frame. See [`Root::recompose_budget`].
+    recompose_budget: Option<Duration>,
+    /// The camera that the UI of the root is rendered to. See [`Root::with_target_camera`].
+    target_camera: Option<Entity>,
+    /// The id under which the saved states of the root are stashed when it is despawned. See [`Root::persistent`].
+    #[cfg(feature = "session")]
+    persistent_id: Option<String>,
+    /// The values that the root provides to its tree. See [`Root::with_context`] and [`Root::with_state`].
+    pub(crate) context: Arc<RootContext>,
+}
+
+impl Root {
+    /// Returns a read-only view of the root scope, or `None` if the root hasn't been composed yet.
+    pub fn scope(&self) -> Option<ScopeRef<'_>> {
+        self.scope.as_ref().map(ScopeRef::new)
+    }
+
+    /// Returns the total number of scopes in the tree of this root.
+    pub(crate) fn scope_count(&self) -> usize {
+        let Some(scope) = &self.scope else {
+            return 0;
+        };
+
+        let mut count = 0;
+        let mut scopes = vec![scope];
+
+        while let Some(scope) = scopes.pop() {
+            count += 1;
+            scopes.extend(scope.children.iter());
+        }
+
+        count
+    }
+
+    /// Returns the depth of the deepest scope in the tree of this root. The root scope has a depth of 1.
+    pub(crate) fn tree_depth(&self) -> usize {
+        let Some(scope) = &self.scope else {
+            return 0;
+        };
+
+        let mut depth = 0;
+        let mut scopes = vec![(scope, 1)];
+
+        while let Some((scope, scope_depth)) = scopes.pop() {
+            depth = depth.max(scope_depth);
+            scopes.extend(scope.children.iter().map(|child| (child, scope_depth + 1)));
+        }
+
+        depth
+    }
+
+    /// Whether the tree of this root has been composed and has no pending recompositions or queued systems.
+    pub(crate) fn is_settled(&self) -> bool {
+        let Some(scope) = &self.scope else {
+            return false;
+        };
+
+        let mut scopes = vec![scope];
+
+        while let Some(scope) = scopes.pop() {
+            let has_queued_states = scope
+                .states
+                .iter()
+                .any(|state| matches!(state.changed, StateChanged::Queued));
+
+            if has_queued_states || !scope.queued_systems.is_empty() {
+                return false;
+            }
+
+            scopes.extend(scope.children.iter());
+        }
+
+        true
+    }
+}
+
+/// Extension trait for spawning [`Root`]s with `Commands` or a `ChildBuilder`.
+pub trait RootCommandsExtension {
+    /// Spawns a UI root that composes the given composable, together with a default `Node`, and returns its entity.
+    /// Spawning the root with a `ChildBuilder` nests it under an existing UI entity, so that its nodes are laid out as
+    /// part of that entity.
+    ///
+    /// Roots that need a custom `Node`, or that compose world-space entities, should be spawned with `Root::new`
+    /// directly.
+    ///
+    /// # Example
+    /// ```ignore
+    /// fn setup(mut commands: Commands) {
+    ///     commands.spawn(Camera2d);
+    ///     commands.spawn_root(counter);
+    ///
+    ///     commands.spawn(Node::default()).with_children(|parent| {
+    ///         parent.spawn_root(sidebar);
+    ///     });
+    /// }
+    /// ```
+    fn spawn_root<C: Compose + 'static>(&mut self, composer: C) -> Entity;
+}
+
+impl RootCommandsExtension for Commands<'_, '_> {
+    fn spawn_root<C: Compose + 'static>(&mut self, composer: C) -> Entity {
+        self.spawn((Root::new(composer), Node::default())).id()
+    }
+}
+
+impl RootCommandsExtension for ChildBuilder<'_> {
+    fn spawn_root<C: Compose + 'static>(&mut self, composer: C) -> Entity {
+        self.spawn((Root::new(composer), Node::default())).id()
+    }
 }
 
 impl Component for Root {
@@ -561,6 +1669,9 @@ impl Component for Root {
 
     fn register_component_hooks(hooks: &mut ComponentHooks) {
         fn decompose_root(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+            #[cfg(feature = "session")]
+            stash_persistent_root(&mut world, entity);
+
             let Some(mut roots) = world.get_mut::<Root>(entity) else {
                 return;
             };
@@ -570,14 +1681,29 @@ impl Component for Root {
             };
 
             let mut scopes = VecDeque::from([scope]);
+            let mut queued_systems = Vec::new();
 
             while let Some(scope) = scopes.pop_front() {
                 let composer = scope.composer.clone();
                 composer.decompose_scope(scope);
+                queued_systems.append(&mut scope.queued_systems);
                 for child in scope.children.iter_mut().rev() {
                     scopes.push_front(child);
                 }
             }
+
+            // The tree is dropped with the root, so the unmount systems have to be run here, or subscriptions of the
+            // tree would never be removed.
+            if queued_systems.is_empty() {
+                return;
+            }
+
+            world.commands().queue(move |world: &mut World| {
+                for mut system in queued_systems {
+                    system.initialize(world);
+                    system.run((), world);
+                }
+            });
         }
 
         hooks.on_replace(decompose_root);
@@ -587,9 +1713,86 @@ impl Component for Root {
 
 impl Root {
     pub fn new<C: Compose + 'static>(composer: C) -> Self {
+        Self::from_shared(Arc::new(composer))
+    }
+
+    /// Creates a root for a composable that may be shared with other roots, like the content of an
+    /// [`Island`](island::Island).
+    pub(crate) fn from_shared(compose: Arc<dyn AnyCompose>) -> Self {
         Self {
-            compose: Arc::new(composer),
+            compose,
             scope: None,
+            #[cfg(feature = "session")]
+            pending_states: Default::default(),
+            recompose_budget: None,
+            target_camera: None,
+            #[cfg(feature = "session")]
+            persistent_id: None,
+            context: Arc::default(),
+        }
+    }
+
+    /// Limits the time that is spent on recomposing the root in a single frame. When a large part of the tree changes
+    /// at once, the changed scopes that don't fit into the budget are deferred to the next frames, which keeps the frame
+    /// times stable at the cost of the UI catching up over a few frames. Scopes are recomposed in the order of their
+    /// [priority](Scope::recompose_priority).
+    ///
+    /// The budget is checked between the recompositions of the changed scopes, so a single scope with a large subtree
+    /// can still exceed it. By default, all changed scopes are recomposed in the same frame.
+    ///
+    /// # Example
+    /// ```ignore
+    /// commands.spawn(Root::new(Inventory).recompose_budget(Duration::from_millis(2)));
+    /// ```
+    pub fn recompose_budget(mut self, budget: Duration) -> Self {
+        self.recompose_budget = Some(budget);
+        self
+    }
+
+    /// Renders the UI of the root to the given camera, which makes it possible to direct roots to secondary windows or
+    /// render targets. The `TargetCamera` component is added to the root entity if it is a UI node, and to the
+    /// top-level UI nodes spawned by the root otherwise.
+    ///
+    /// Roots that are nested under other UI nodes are rendered to the camera of the UI tree they are part of.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let camera = commands
+    ///     .spawn((Camera2d, Camera { target: RenderTarget::Window(WindowRef::Entity(window)), ..default() }))
+    ///     .id();
+    ///
+    /// commands.spawn((Root::new(inspector).with_target_camera(camera), Node::default()));
+    /// ```
+    pub fn with_target_camera(mut self, camera: Entity) -> Self {
+        self.target_camera = Some(camera);
+        self
+    }
+}
+
+/// Adds the `TargetCamera` of the roots to their UI root nodes. Only UI root nodes (nodes without a parent node) are
+/// affected by the `TargetCamera`, so the search stops at the first node of every branch.
+fn apply_target_cameras(
+    mut commands: Commands,
+    roots: Query<(Entity, &Root)>,
+    children: Query<&Children>,
+    nodes: Query<Option<&TargetCamera>, With<Node>>,
+) {
+    for (root_entity, root) in roots.iter() {
+        let Some(camera) = root.target_camera else {
+            continue;
+        };
+
+        let mut entities = vec![root_entity];
+
+        while let Some(entity) = entities.pop() {
+            let Ok(target_camera) = nodes.get(entity) else {
+                entities.extend(children.get(entity).into_iter().flatten());
+                continue;
+            };
+
+            if target_camera.map(|target_camera| target_camera.0) != Some(camera) {
+                commands.entity(entity).insert(TargetCamera(camera));
+            }
         }
     }
 }
@@ -0,0 +1,317 @@
+use crate::{
+    label::TextLabel,
+    modify::{Modifier, Modify, ModifyFunctions},
+    Compose, Scope,
+};
+use bevy_color::Color;
+use bevy_text::{TextColor, TextFont};
+use std::{collections::HashMap, fmt::Display, sync::Arc};
+
+/// The translated strings of the app, by locale, and the locale they are shown in. The localization is app-wide and
+/// stored in a [`Store`](crate::store::Store), so the locale can be switched at runtime with
+/// `ResMut<Store<Localization>>`. Composables read the strings with [`use_translations`](Scope::use_translations) or
+/// [`LocalizedText`], and are recomposed when the locale or the strings of the locale change.
+///
+/// Strings may contain placeholders like `{name}`, which are replaced by the arguments of
+/// [`translate`](Translations::translate).
+///
+/// # Example
+/// ```ignore
+/// App::new()
+///     .add_plugins((DefaultPlugins, RecomposePlugin))
+///     .insert_store(
+///         Localization::new("en")
+///             .with_translations("en", [("menu.play", "Play"), ("greeting", "Hello, {name}!")])
+///             .with_translations("de", [("menu.play", "Spielen"), ("greeting", "Hallo, {name}!")]),
+///     )
+///
+/// fn switch_to_german(mut localization: ResMut<Store<Localization>>) {
+///     localization.set_locale("de");
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Localization {
+    locale: String,
+    fallback_locale: Option<String>,
+    translations: HashMap<String, Arc<HashMap<String, String>>>,
+}
+
+impl Default for Localization {
+    fn default() -> Self {
+        Self::new("en")
+    }
+}
+
+impl Localization {
+    /// Creates a localization without any strings, which shows the given locale.
+    pub fn new(locale: impl Into<String>) -> Self {
+        Self {
+            locale: locale.into(),
+            fallback_locale: None,
+            translations: HashMap::new(),
+        }
+    }
+
+    /// Adds the given strings to the locale, by their keys. See [`add_translations`](Self::add_translations).
+    pub fn with_translations(
+        mut self,
+        locale: impl Into<String>,
+        translations: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) -> Self {
+        self.add_translations(locale, translations);
+        self
+    }
+
+    /// Sets the locale whose strings are shown for keys that the current locale doesn't translate.
+    pub fn with_fallback_locale(mut self, locale: impl Into<String>) -> Self {
+        self.fallback_locale = Some(locale.into());
+        self
+    }
+
+    /// Adds the given strings to the locale, by their keys. Strings that the locale already has for the same keys are
+    /// replaced.
+    pub fn add_translations(
+        &mut self,
+        locale: impl Into<String>,
+        translations: impl IntoIterator<Item = (impl Into<String>, impl Into<String>)>,
+    ) {
+        let strings = self.translations.entry(locale.into()).or_default();
+
+        Arc::make_mut(strings).extend(
+            translations
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into())),
+        );
+    }
+
+    /// Returns the locale that is shown.
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Changes the locale that is shown, which recomposes all composables that read translated strings.
+    pub fn set_locale(&mut self, locale: impl Into<String>) {
+        self.locale = locale.into();
+    }
+
+    /// Returns the locales that have strings, in no particular order.
+    pub fn locales(&self) -> impl Iterator<Item = &str> {
+        self.translations.keys().map(String::as_str)
+    }
+
+    /// Returns the strings of the current locale and the fallback locale.
+    pub fn translations(&self) -> Translations {
+        Translations {
+            is_loaded: true,
+            strings: self.translations.get(&self.locale).cloned(),
+            fallback: self
+                .fallback_locale
+                .as_ref()
+                .and_then(|locale| self.translations.get(locale).cloned()),
+        }
+    }
+}
+
+/// The strings of the current locale, returned by [`use_translations`](Scope::use_translations). The strings are
+/// shared with the [`Localization`], so cloning them is cheap.
+#[derive(Clone, Default, Debug)]
+pub struct Translations {
+    /// Whether the strings were read from the store. See [`use_store_selector`](Scope::use_store_selector).
+    is_loaded: bool,
+    strings: Option<Arc<HashMap<String, String>>>,
+    fallback: Option<Arc<HashMap<String, String>>>,
+}
+
+// The strings are only replaced when they change, so comparing the pointers is enough to tell whether they changed.
+impl PartialEq for Translations {
+    fn eq(&self, other: &Self) -> bool {
+        fn ptr_eq<T>(a: &Option<Arc<T>>, b: &Option<Arc<T>>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+
+        self.is_loaded == other.is_loaded
+            && ptr_eq(&self.strings, &other.strings)
+            && ptr_eq(&self.fallback, &other.fallback)
+    }
+}
+
+impl Translations {
+    /// Returns the string of the given key, from the current locale or the fallback locale.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        [&self.strings, &self.fallback]
+            .into_iter()
+            .flatten()
+            .find_map(|strings| strings.get(key))
+            .map(String::as_str)
+    }
+
+    /// Returns the string of the given key, with its placeholders replaced by the arguments of the same name. Keys that
+    /// aren't translated are returned as they are, so that they stand out. Before the strings are first read from the
+    /// store, an empty string is returned instead, so that keys don't flash up for a frame. See also [`tr!`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// let translations = cx.use_translations();
+    /// translations.translate("greeting", &[("name", &player.name)])
+    /// ```
+    pub fn translate<V: Display>(&self, key: &str, args: &[(&str, V)]) -> String {
+        if !self.is_loaded {
+            return String::new();
+        }
+
+        let Some(mut rest) = self.get(key) else {
+            return key.to_string();
+        };
+
+        let mut text = String::with_capacity(rest.len());
+
+        while let Some(start) = rest.find('{') {
+            text.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let arg = rest.find('}').and_then(|end| {
+                let name = &rest[1..end];
+                let (_, value) = args.iter().find(|(arg_name, _)| *arg_name == name)?;
+                Some((end, value))
+            });
+
+            match arg {
+                Some((end, value)) => {
+                    text.push_str(&value.to_string());
+                    rest = &rest[end + 1..];
+                }
+                // Placeholders without an argument are kept, like any other text.
+                None => {
+                    text.push('{');
+                    rest = &rest[1..];
+                }
+            }
+        }
+
+        text.push_str(rest);
+        text
+    }
+}
+
+/// Translates a key with the given [`Translations`], replacing the placeholders of the string with the named
+/// arguments. The arguments can be anything that implements `Display`. See [`Translations::translate`].
+///
+/// # Example
+/// ```ignore
+/// let translations = cx.use_translations();
+///
+/// (
+///     TextLabel::new(tr!(translations, "menu.play")),
+///     TextLabel::new(tr!(translations, "score", points = *score, rank = rank)),
+/// )
+/// ```
+#[macro_export]
+macro_rules! tr {
+    ($translations:expr, $key:expr $(,)?) => {
+        $translations.translate::<&str>($key, &[])
+    };
+    ($translations:expr, $key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $translations.translate(
+            $key,
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        )
+    };
+}
+
+impl Scope<'_> {
+    /// Returns the strings of the current locale of the [`Localization`]. The composable is recomposed when the locale
+    /// or its strings change.
+    ///
+    /// # Example
+    /// ```ignore
+    /// fn main_menu(cx: &mut Scope) -> impl Compose {
+    ///     let translations = cx.use_translations();
+    ///     TextLabel::new(tr!(translations, "menu.play"))
+    /// }
+    /// ```
+    pub fn use_translations(&mut self) -> Translations {
+        let translations = self.use_store_selector(Localization::translations);
+        (*translations).clone().unwrap_or_default()
+    }
+}
+
+/// A composable that shows the translated string of a key, like a [`TextLabel`]. It is recomposed when the locale of
+/// the [`Localization`] changes.
+///
+/// # Example
+/// ```ignore
+/// (
+///     LocalizedText::new("menu.play").font_size(32.0),
+///     LocalizedText::new("menu.welcome").arg("name", &player.name),
+/// )
+/// ```
+#[derive(Clone)]
+pub struct LocalizedText {
+    key: String,
+    args: Vec<(&'static str, String)>,
+    font: TextFont,
+    color: TextColor,
+    modifier: Modifier,
+}
+
+impl LocalizedText {
+    /// Creates a new text that shows the string of the given key.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            args: Vec::new(),
+            font: TextFont::default(),
+            color: TextColor::default(),
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Replaces the placeholder of the given name with the value.
+    pub fn arg(mut self, name: &'static str, value: impl Display) -> Self {
+        self.args.push((name, value.to_string()));
+        self
+    }
+
+    /// Sets the font size of the text.
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font.font_size = font_size;
+        self
+    }
+
+    /// Sets the font of the text.
+    pub fn font(mut self, font: TextFont) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the color of the text.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = TextColor(color.into());
+        self
+    }
+}
+
+impl Modify for LocalizedText {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for LocalizedText {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let translations = cx.use_translations();
+
+        TextLabel::new(translations.translate(&self.key, &self.args))
+            .font(self.font.clone())
+            .color(self.color.0)
+            .use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("LocalizedText")
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{Compose, Scope};
+
+/// A composable that skips recomposing the wrapped composable when it is equal to the composable of the previous
+/// composition. Neither the wrapped composable nor its children are recomposed then, unless they have changed states of
+/// their own.
+///
+/// This is an opt-in version of [`Compose::is_unchanged`] for composables that implement `PartialEq`, but don't
+/// implement `is_unchanged` themselves. The wrapped composable should only depend on its fields and its states, since
+/// changes to anything else aren't picked up while it is unchanged.
+///
+/// # Example
+/// ```ignore
+/// let rows = items
+///     .iter()
+///     .map(|item| Keyed::new(item.id, MemoCompose::new(ItemRow { name: item.name.clone(), count: item.count })))
+///     .collect::<Vec<_>>();
+/// ```
+#[derive(Clone)]
+pub struct MemoCompose<C> {
+    compose: C,
+}
+
+impl<C: Compose + PartialEq + Clone + 'static> MemoCompose<C> {
+    /// Wraps the given composable.
+    pub fn new(compose: C) -> Self {
+        Self { compose }
+    }
+}
+
+impl<C: Compose + PartialEq + Clone + 'static> Compose for MemoCompose<C> {
+    fn compose<'a>(&self, _: &mut Scope) -> impl Compose + 'a {
+        self.compose.clone()
+    }
+
+    fn is_unchanged(&self, previous: &Self) -> bool {
+        self.compose == previous.compose
+    }
+
+    fn name(&self) -> String {
+        String::from("MemoCompose")
+    }
+}
@@ -0,0 +1,266 @@
+use crate::{
+    dyn_compose::DynCompose,
+    focus::InputFocus,
+    modify::{ChildrenPolicy, Modifier, Modify, ModifyFunctions},
+    state::{SetState, State, TypedStateId},
+    unique_id, Compose, Scope,
+};
+use bevy_color::{Color, Srgba};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    observer::Trigger,
+    query::{With, Without},
+    system::{Commands, Query, Res, ResMut},
+    world::OnRemove,
+};
+use bevy_hierarchy::{HierarchyQueryExt, Parent};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_picking::events::{Click, Pointer};
+use bevy_ui::{AlignItems, BackgroundColor, GlobalZIndex, JustifyContent, Node, PositionType, Val};
+use std::sync::Arc;
+
+/// The global z-index of modal overlays, which places them above all other UI.
+pub const MODAL_Z_INDEX: i32 = 1_000_000;
+
+type ModalHandler = Arc<dyn Fn(&mut SetState) + Send + Sync>;
+
+/// A composable that shows its content in a dialog above all other UI while the given state is `true`.
+///
+/// The dialog is shown on a full-screen overlay that is detached from the parent entity, so that it covers the whole
+/// window regardless of where the modal is composed. The overlay catches all pointer events, which means that the UI
+/// below it can't be interacted with while the modal is open. The modal closes itself, by setting the state to `false`,
+/// when Escape is pressed or when the overlay outside of the content is clicked. Only the most recently opened modal is
+/// closed by Escape.
+///
+/// The [`InputFocus`] is trapped in the most recently opened modal: focus outside of its overlay is cleared when the
+/// modal opens, and whenever it moves out of the overlay while the modal is open. When the modal closes, the focus goes
+/// back to the entity that had it before the modal opened.
+///
+/// # Example
+/// ```ignore
+/// let is_open = cx.use_state(false);
+///
+/// Modal::new(&is_open, TextLabel::new("Are you sure?"))
+///     .on_close(|_| println!("Closed"))
+/// ```
+#[derive(Clone)]
+pub struct Modal {
+    is_open: bool,
+    open_state: TypedStateId<bool>,
+    content: DynCompose,
+    backdrop_color: Color,
+    close_on_escape: bool,
+    close_on_outside_click: bool,
+    on_open: Option<ModalHandler>,
+    on_close: Option<ModalHandler>,
+    modifier: Modifier,
+}
+
+impl Modal {
+    /// Creates a new modal that is open while the given state is `true`.
+    pub fn new(open: &State<bool>, content: impl Compose + 'static) -> Self {
+        Self {
+            is_open: **open,
+            open_state: open.get_typed_id(),
+            content: DynCompose::new(content),
+            backdrop_color: Srgba::new(0.0, 0.0, 0.0, 0.5).into(),
+            close_on_escape: true,
+            close_on_outside_click: true,
+            on_open: None,
+            on_close: None,
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Sets the color of the overlay behind the content.
+    pub fn backdrop_color(mut self, color: impl Into<Color>) -> Self {
+        self.backdrop_color = color.into();
+        self
+    }
+
+    /// Sets whether the modal closes when Escape is pressed. Defaults to `true`.
+    pub fn close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+
+    /// Sets whether the modal closes when the overlay outside of the content is clicked. Defaults to `true`.
+    pub fn close_on_outside_click(mut self, close_on_outside_click: bool) -> Self {
+        self.close_on_outside_click = close_on_outside_click;
+        self
+    }
+
+    /// Sets the function that is called when the modal opens.
+    pub fn on_open(mut self, on_open: impl Fn(&mut SetState) + Send + Sync + 'static) -> Self {
+        self.on_open = Some(Arc::new(on_open));
+        self
+    }
+
+    /// Sets the function that is called when the modal closes, regardless of whether it was closed by the modal itself
+    /// or by setting the state.
+    pub fn on_close(mut self, on_close: impl Fn(&mut SetState) + Send + Sync + 'static) -> Self {
+        self.on_close = Some(Arc::new(on_close));
+        self
+    }
+}
+
+impl Modify for Modal {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+/// The entity that had focus before the modal of the overlay opened, which gets the focus back when the modal closes.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct ModalFocus(Option<Entity>);
+
+/// Marks the overlay of an open modal.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct ModalOverlay {
+    open_state: TypedStateId<bool>,
+    opened_at: usize,
+    close_on_escape: bool,
+}
+
+impl Compose for Modal {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let was_open = cx.use_state(false);
+        let opened_at = cx.use_state(0);
+
+        if self.is_open != *was_open {
+            cx.set_state_unchanged(&was_open, self.is_open);
+
+            if self.is_open {
+                cx.set_state_unchanged(&opened_at, unique_id());
+            }
+
+            let handler = match self.is_open {
+                true => self.on_open.clone(),
+                false => self.on_close.clone(),
+            };
+
+            if let Some(handler) = handler {
+                cx.run_system(move |mut state: SetState| handler(&mut state));
+            }
+        }
+
+        let open_state = self.open_state;
+        let close_on_outside_click = self.close_on_outside_click;
+
+        let overlay = ModalOverlay {
+            open_state,
+            opened_at: *cx.pending_value(&opened_at).unwrap_or(opened_at),
+            close_on_escape: self.close_on_escape,
+        };
+
+        (
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BackgroundColor(self.backdrop_color),
+            GlobalZIndex(MODAL_Z_INDEX),
+            overlay,
+        )
+            .children(self.content.clone())
+            .detached()
+            .observe(
+                move |trigger: Trigger<Pointer<Click>>, mut state: SetState| {
+                    // Clicks on the content bubble up to the overlay as well, so we only close the modal when the
+                    // overlay itself was clicked.
+                    if close_on_outside_click && trigger.event().target == trigger.entity() {
+                        state.set(open_state, false);
+                    }
+                },
+            )
+            .use_modifier_with(&self.modifier, ChildrenPolicy::Append)
+            .some_if(self.is_open)
+    }
+
+    fn name(&self) -> String {
+        String::from("Modal")
+    }
+}
+
+pub(crate) fn close_modals_on_escape(
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    overlays: Query<&ModalOverlay>,
+    mut state: SetState,
+) {
+    let Some(keys) = keys else {
+        return;
+    };
+
+    if !keys.just_pressed(KeyCode::Escape) {
+        return;
+    }
+
+    let topmost = overlays.iter().max_by_key(|overlay| overlay.opened_at);
+
+    if let Some(overlay) = topmost.filter(|overlay| overlay.close_on_escape) {
+        state.set(overlay.open_state, false);
+    }
+}
+
+/// Remembers the focus of newly opened modals, and clears the focus when it is outside of the topmost overlay, so that
+/// keyboard input and actions don't reach the UI below the modal.
+pub(crate) fn trap_modal_focus(
+    mut commands: Commands,
+    opened_overlays: Query<Entity, (With<ModalOverlay>, Without<ModalFocus>)>,
+    overlays: Query<(Entity, &ModalOverlay)>,
+    parents: Query<&Parent>,
+    mut focus: ResMut<InputFocus>,
+) {
+    for entity in opened_overlays.iter() {
+        commands.entity(entity).insert(ModalFocus(focus.0));
+    }
+
+    let Some((topmost, _)) = overlays.iter().max_by_key(|(_, overlay)| overlay.opened_at) else {
+        return;
+    };
+
+    let Some(focused) = focus.0 else {
+        return;
+    };
+
+    if !is_in_overlay(focused, topmost, &parents) {
+        focus.0 = None;
+    }
+}
+
+/// Gives the focus back to the entity that had it before the modal opened, unless the focus was moved out of the
+/// overlay in the meantime.
+pub(crate) fn restore_modal_focus(
+    trigger: Trigger<OnRemove, ModalFocus>,
+    modal_focus: Query<&ModalFocus>,
+    entities: Query<Entity>,
+    parents: Query<&Parent>,
+    mut focus: ResMut<InputFocus>,
+) {
+    let overlay = trigger.entity();
+
+    let Ok(ModalFocus(previous)) = modal_focus.get(overlay) else {
+        return;
+    };
+
+    // The content of the overlay may already be despawned, which also takes the focus with it.
+    let is_focus_in_modal = focus.0.is_none_or(|focused| {
+        !entities.contains(focused) || is_in_overlay(focused, overlay, &parents)
+    });
+
+    if is_focus_in_modal {
+        focus.0 = previous.filter(|previous| entities.contains(*previous));
+    }
+}
+
+fn is_in_overlay(entity: Entity, overlay: Entity, parents: &Query<&Parent>) -> bool {
+    entity == overlay
+        || parents
+            .iter_ancestors(entity)
+            .any(|ancestor| ancestor == overlay)
+}
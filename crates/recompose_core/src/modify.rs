@@ -1,42 +1,134 @@
 use crate::{
+    drag_drop::{drop_payload, track_drag, DragPayload, Draggable, DropHandler, DropTarget},
     dyn_compose::DynCompose,
-    keyed::Keyed,
+    focus::Focusable,
+    gestures::{
+        detect_double_click, detect_drag, detect_long_press, upsert_gesture, DoubleClickGesture,
+        DragGesture, DragGestureTracker, DragHandler, DragState, GestureHandler, LongPressGesture,
+    },
+    interaction::{track_interaction, InteractionState, InteractionTracker},
+    keyed::{Keyed, ScopeKeyed},
+    layout::{LayoutWatcher, NodeLayout},
+    pointer_events::{add_pointer_event_kinds, PointerEvent, PointerEventKind},
+    spawn::DespawnPolicy,
+    scroll_view::{ScrollBinding, ScrollWheel},
+    selection::{select_on_click, Selectable, Selection},
     state::{GetStateId, SetState, TypedStateId},
+    tooltip::{track_tooltip_hover, Tooltip},
     Compose,
 };
 use bevy_ecs::{
     bundle::Bundle,
+    component::Component,
     entity::Entity,
     event::Event,
     observer::{Observer, Trigger},
     system::{EntityCommands, IntoObserverSystem},
+    world::World,
 };
-use bevy_picking::events::{Out, Over, Pointer};
-use std::{hash::Hash, sync::Arc};
+use bevy_math::Vec2;
+use bevy_picking::{
+    events::{Drag, Pointer},
+    PickingBehavior,
+};
+use bevy_render::view::Visibility;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    hash::Hash,
+    sync::Arc,
+    time::Duration,
+};
+
+// Storing observers directly would be better, but it's a little tricky, so for now we store a function that creates
+// the observer for the given spawned entity.
+type ObserverFn = Arc<dyn (Fn(Entity) -> Observer) + Send + Sync>;
+
+#[derive(Clone)]
+pub(crate) struct ObserverGenerator {
+    observer: ObserverFn,
+    signature: ObserverSignature,
+}
 
-// Storing observers directly would be better, but it's a little tricky, so for now we store a function that adds
-// the observer given entity commands.
-type ObserverGeneratorFn = Arc<dyn (Fn(&mut EntityCommands) -> Entity) + Send + Sync>;
+/// Identifies observers that can replace each other on the same observer entity. Observers with the same system type and
+/// target are registered identically, so only their system has to be swapped when they are regenerated.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct ObserverSignature {
+    system: TypeId,
+    target: ObserverTarget,
+}
 
+/// Describes what happens to the spawned entity before it is despawned. See
+/// [`on_despawn_transition`](ModifyFunctions::on_despawn_transition).
 #[derive(Clone)]
-pub(crate) struct ObserverGenerator(ObserverGeneratorFn);
+pub(crate) struct DespawnTransition {
+    pub(crate) on_start: Arc<dyn Fn(&mut EntityCommands) + Send + Sync>,
+    pub(crate) duration: Duration,
+}
+
+/// The `PickingBehavior` that an entity had before it was hidden with [`visible`](ModifyFunctions::visible), which is
+/// restored when the entity is shown again.
+#[derive(Component)]
+pub(crate) struct HiddenPickingBehavior(Option<PickingBehavior>);
+
+/// The entity whose triggers an observer watches.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum ObserverTarget {
+    /// The spawned entity.
+    Spawned,
+    /// Another entity.
+    Entity(Entity),
+    /// No entity, so that the observer watches untargeted triggers.
+    Global,
+}
 
 impl ObserverGenerator {
-    fn new<E: Event, B: Bundle, M>(
-        observer: impl IntoObserverSystem<E, B, M> + Clone + Sync,
+    fn new<E: Event, B: Bundle, M, O: IntoObserverSystem<E, B, M> + Clone + Sync>(
+        observer: O,
+        target: ObserverTarget,
     ) -> Self {
-        let f = Arc::new(move |entity: &mut EntityCommands| {
-            let target_entity = entity.id();
-            let commands = entity.commands_mut();
-            let o = Observer::new(observer.clone()).with_entity(target_entity);
-            commands.spawn(o).id()
+        let f = Arc::new(move |spawned_entity: Entity| {
+            let o = Observer::new(observer.clone());
+
+            match target {
+                ObserverTarget::Spawned => o.with_entity(spawned_entity),
+                ObserverTarget::Entity(target_entity) => o.with_entity(target_entity),
+                ObserverTarget::Global => o,
+            }
         });
 
-        Self(f)
+        Self {
+            observer: f,
+            signature: ObserverSignature {
+                system: TypeId::of::<O::System>(),
+                target,
+            },
+        }
     }
 
+    pub fn signature(&self) -> ObserverSignature {
+        self.signature
+    }
+
+    /// Spawns the observer for the given entity and returns the observer entity.
     pub fn generate(&self, entity: &mut EntityCommands) -> Entity {
-        self.0(entity)
+        let observer = (self.observer)(entity.id());
+        entity.commands_mut().spawn(observer).id()
+    }
+
+    /// Replaces the observer of an existing observer entity that was generated by an observer with the same
+    /// [`ObserverSignature`].
+    ///
+    /// The observer is registered when its `ObserverState` is added, and the state is kept when the `Observer` is
+    /// replaced. Re-adding the `Observer` only initializes the new system, which the existing registration then runs.
+    pub fn regenerate(&self, entity: &mut EntityCommands, observer_entity: Entity) {
+        let observer = (self.observer)(entity.id());
+
+        let Some(mut observer_ec) = entity.commands_mut().get_entity(observer_entity) else {
+            return;
+        };
+
+        observer_ec.remove::<Observer>().try_insert(observer);
     }
 }
 
@@ -82,24 +174,107 @@ impl ObserverGenerator {
 #[allow(clippy::type_complexity)]
 pub struct Modifier {
     pub(crate) children: DynCompose,
+    pub(crate) slots: HashMap<&'static str, DynCompose>,
     pub(crate) bundle_modifiers: Vec<Arc<dyn Fn(&mut EntityCommands) + Send + Sync>>,
     pub(crate) temporary_observers: Vec<ObserverGenerator>,
     pub(crate) retained_observers: Vec<ObserverGenerator>,
+    pub(crate) order: Option<i32>,
+    pub(crate) despawn_transition: Option<DespawnTransition>,
+    pub(crate) despawn_policy: Option<DespawnPolicy>,
+    pub(crate) detached: bool,
+    pub(crate) pickable: Option<bool>,
+    pub(crate) block_lower: Option<bool>,
+    pub(crate) disabled: Option<bool>,
+    pub(crate) tooltip: Option<Tooltip>,
+}
+
+/// Determines what happens to the existing children when a modifier with children is joined into another modifier. See
+/// [`use_modifier_with`](ModifyFunctions::use_modifier_with).
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum ChildrenPolicy {
+    /// The joined children replace the existing children.
+    #[default]
+    Replace,
+    /// The joined children are added after the existing children.
+    Append,
+    /// The joined children are added before the existing children.
+    Prepend,
+    /// The joined children are ignored. This is useful when a composable places the children of its modifier somewhere
+    /// else (like a nested "slot") by using [`Modifier::get_children`].
+    Ignore,
 }
 
 impl Modifier {
+    /// Returns the children of the modifier.
+    pub fn get_children(&self) -> &DynCompose {
+        &self.children
+    }
+
+    /// Returns the number of children of the modifier. See [`DynCompose::children`].
+    pub fn children_count(&self) -> usize {
+        self.children.children_count()
+    }
+
+    /// Maps each child of the modifier to a new composable. See [`DynCompose::map_children`].
+    pub fn map_children<C: Compose + 'static>(
+        &self,
+        map: impl Fn(usize, DynCompose) -> C,
+    ) -> DynCompose {
+        self.children.map_children(map)
+    }
+
+    /// Returns the content of the named slot, or an empty composable if the slot hasn't been filled. See
+    /// [`slot`](ModifyFunctions::slot).
+    pub fn use_slot(&self, name: &str) -> DynCompose {
+        self.slots.get(name).cloned().unwrap_or_default()
+    }
+
+    /// Whether the named slot has been filled. See [`slot`](ModifyFunctions::slot).
+    pub fn has_slot(&self, name: &str) -> bool {
+        self.slots.contains_key(name)
+    }
+
     /// Joins two modifiers together. Note, the the newest children will override the old children.
     pub fn join(&mut self, other: &Modifier) {
-        self.children = match other.children.is_empty() {
-            true => self.children.clone(),
-            false => other.children.clone(),
+        self.join_with(other, ChildrenPolicy::Replace);
+    }
+
+    /// Joins two modifiers together, using the given policy for combining the children.
+    pub fn join_with(&mut self, other: &Modifier, children_policy: ChildrenPolicy) {
+        self.children = match (other.children.is_empty(), children_policy) {
+            (true, _) | (_, ChildrenPolicy::Ignore) => self.children.clone(),
+            (false, _) if self.children.is_empty() => other.children.clone(),
+            (false, ChildrenPolicy::Replace) => other.children.clone(),
+            (false, ChildrenPolicy::Append) => {
+                DynCompose::new((self.children.clone(), other.children.clone()))
+            }
+            (false, ChildrenPolicy::Prepend) => {
+                DynCompose::new((other.children.clone(), self.children.clone()))
+            }
         };
+        self.slots.extend(
+            other
+                .slots
+                .iter()
+                .map(|(name, content)| (*name, content.clone())),
+        );
         self.bundle_modifiers
             .extend(other.bundle_modifiers.iter().cloned());
         self.temporary_observers
             .extend(other.temporary_observers.iter().cloned());
         self.retained_observers
             .extend(other.retained_observers.iter().cloned());
+        self.order = other.order.or(self.order);
+        self.despawn_transition = other
+            .despawn_transition
+            .clone()
+            .or(self.despawn_transition.clone());
+        self.despawn_policy = other.despawn_policy.clone().or(self.despawn_policy.clone());
+        self.detached |= other.detached;
+        self.pickable = other.pickable.or(self.pickable);
+        self.block_lower = other.block_lower.or(self.block_lower);
+        self.disabled = other.disabled.or(self.disabled);
+        self.tooltip = other.tooltip.clone().or(self.tooltip.clone());
     }
 }
 
@@ -117,12 +292,23 @@ impl<T: Modify + Compose> ModifyFunctions<T> for T {
         self
     }
 
+    fn use_modifier_with(mut self, modifier: &Modifier, children_policy: ChildrenPolicy) -> Self {
+        self.modifier().join_with(modifier, children_policy);
+        self
+    }
+
     fn children(mut self, children: impl Compose + 'static) -> Self {
         let modifier = self.modifier();
         modifier.children = DynCompose::new(children);
         self
     }
 
+    fn slot(mut self, name: &'static str, content: impl Compose + 'static) -> Self::Target {
+        let modifier = self.modifier();
+        modifier.slots.insert(name, DynCompose::new(content));
+        self
+    }
+
     fn with_bundle<B: Bundle + Clone>(mut self, bundle: B) -> Self::Target {
         let bundle_modifier = Arc::new(move |entity: &mut EntityCommands| {
             entity.try_insert(bundle.clone());
@@ -149,6 +335,96 @@ impl<T: Modify + Compose> ModifyFunctions<T> for T {
         self
     }
 
+    fn when(self, condition: bool, modify: impl FnOnce(Self) -> Self) -> Self::Target {
+        match condition {
+            true => modify(self),
+            false => self,
+        }
+    }
+
+    fn order(mut self, order: i32) -> Self::Target {
+        let modifier = self.modifier();
+        modifier.order = Some(order);
+        self
+    }
+
+    fn visible(mut self, visible: bool) -> Self::Target {
+        let bundle_modifier = Arc::new(move |entity: &mut EntityCommands| {
+            entity.queue(move |entity: Entity, world: &mut World| {
+                let Ok(mut entity) = world.get_entity_mut(entity) else {
+                    return;
+                };
+
+                if visible {
+                    // Only entities that were hidden here get their previous behavior back, so that a behavior
+                    // inserted elsewhere isn't removed on every update.
+                    if let Some(HiddenPickingBehavior(behavior)) =
+                        entity.take::<HiddenPickingBehavior>()
+                    {
+                        match behavior {
+                            Some(behavior) => entity.insert(behavior),
+                            None => entity.remove::<PickingBehavior>(),
+                        };
+                    }
+
+                    if entity.get::<Visibility>() != Some(&Visibility::Inherited) {
+                        entity.insert(Visibility::Inherited);
+                    }
+                } else {
+                    if !entity.contains::<HiddenPickingBehavior>() {
+                        let behavior = entity.get::<PickingBehavior>().cloned();
+                        entity.insert(HiddenPickingBehavior(behavior));
+                    }
+
+                    entity.insert((Visibility::Hidden, PickingBehavior::IGNORE));
+                }
+            });
+        });
+
+        let modifier = self.modifier();
+        modifier.bundle_modifiers.push(bundle_modifier);
+
+        self
+    }
+
+    fn pickable(mut self, pickable: bool) -> Self::Target {
+        let modifier = self.modifier();
+        modifier.pickable = Some(pickable);
+        self
+    }
+
+    fn block_lower(mut self, block_lower: bool) -> Self::Target {
+        let modifier = self.modifier();
+        modifier.block_lower = Some(block_lower);
+        self
+    }
+
+    fn on_despawn_transition(
+        mut self,
+        on_start: impl Fn(&mut EntityCommands) + Send + Sync + 'static,
+        duration: Duration,
+    ) -> Self::Target {
+        let modifier = self.modifier();
+        modifier.despawn_transition = Some(DespawnTransition {
+            on_start: Arc::new(on_start),
+            duration,
+        });
+
+        self
+    }
+
+    fn despawn_policy(mut self, policy: DespawnPolicy) -> Self::Target {
+        let modifier = self.modifier();
+        modifier.despawn_policy = Some(policy);
+        self
+    }
+
+    fn detached(mut self) -> Self::Target {
+        let modifier = self.modifier();
+        modifier.detached = true;
+        self
+    }
+
     fn to_dyn(self) -> DynCompose
     where
         Self: 'static,
@@ -167,11 +443,18 @@ impl<T: Modify + Compose> ModifyFunctions<T> for T {
         Keyed::new(key, self)
     }
 
+    fn scope_key(self, key: impl Into<String>) -> ScopeKeyed
+    where
+        Self: 'static,
+    {
+        ScopeKeyed::new(key, self)
+    }
+
     fn observe<E: Event, B2: Bundle, M>(
         mut self,
         observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
     ) -> Self {
-        let observer_generator = ObserverGenerator::new(observer);
+        let observer_generator = ObserverGenerator::new(observer, ObserverTarget::Spawned);
         let modifier = self.modifier();
         modifier.temporary_observers.push(observer_generator);
 
@@ -182,22 +465,354 @@ impl<T: Modify + Compose> ModifyFunctions<T> for T {
         mut self,
         observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
     ) -> Self {
-        let observer_generator = ObserverGenerator::new(observer);
+        let observer_generator = ObserverGenerator::new(observer, ObserverTarget::Spawned);
+        let modifier = self.modifier();
+        modifier.retained_observers.push(observer_generator);
+
+        self
+    }
+
+    fn observe_global<E: Event, B2: Bundle, M>(
+        mut self,
+        observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
+    ) -> Self {
+        let observer_generator = ObserverGenerator::new(observer, ObserverTarget::Global);
+        let modifier = self.modifier();
+        modifier.temporary_observers.push(observer_generator);
+
+        self
+    }
+
+    fn observe_global_retained<E: Event, B2: Bundle, M>(
+        mut self,
+        observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
+    ) -> Self {
+        let observer_generator = ObserverGenerator::new(observer, ObserverTarget::Global);
         let modifier = self.modifier();
         modifier.retained_observers.push(observer_generator);
 
         self
     }
 
+    fn observe_target<E: Event, B2: Bundle, M>(
+        mut self,
+        target: Entity,
+        observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
+    ) -> Self {
+        let observer_generator = ObserverGenerator::new(observer, ObserverTarget::Entity(target));
+        let modifier = self.modifier();
+        modifier.temporary_observers.push(observer_generator);
+
+        self
+    }
+
+    fn observe_target_retained<E: Event, B2: Bundle, M>(
+        mut self,
+        target: Entity,
+        observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
+    ) -> Self {
+        let observer_generator = ObserverGenerator::new(observer, ObserverTarget::Entity(target));
+        let modifier = self.modifier();
+        modifier.retained_observers.push(observer_generator);
+
+        self
+    }
+
+    fn observe_many<B2: Bundle, M>(
+        mut self,
+        kinds: impl IntoIterator<Item = PointerEventKind>,
+        observer: impl IntoObserverSystem<PointerEvent, B2, M> + Clone + Sync,
+    ) -> Self {
+        let kinds = kinds.into_iter().collect::<Vec<_>>();
+
+        self.modifier()
+            .bundle_modifiers
+            .push(Arc::new(move |entity| add_pointer_event_kinds(entity, &kinds)));
+
+        self.observe(observer)
+    }
+
+    fn observe_many_retained<B2: Bundle, M>(
+        mut self,
+        kinds: impl IntoIterator<Item = PointerEventKind>,
+        observer: impl IntoObserverSystem<PointerEvent, B2, M> + Clone + Sync,
+    ) -> Self {
+        let kinds = kinds.into_iter().collect::<Vec<_>>();
+
+        self.modifier()
+            .bundle_modifiers
+            .push(Arc::new(move |entity| add_pointer_event_kinds(entity, &kinds)));
+
+        self.observe_retained(observer)
+    }
+
     fn bind_hover(self, hover_state: impl GetStateId<bool>) -> Self {
         let typed_state_id = TypedStateId::from_state_id(hover_state.get_id());
 
-        self.observe_retained(move |_: Trigger<Pointer<Over>>, mut state: SetState| {
-            state.set_neq(typed_state_id, true)
-        })
-        .observe_retained(move |_: Trigger<Pointer<Out>>, mut state: SetState| {
-            state.set_neq(typed_state_id, false)
-        })
+        self.observe_many_retained(
+            [PointerEventKind::Over, PointerEventKind::Out],
+            move |trigger: Trigger<PointerEvent>, mut state: SetState| match trigger.kind {
+                PointerEventKind::Over => state.set_neq(typed_state_id, true),
+                PointerEventKind::Out => state.set_neq(typed_state_id, false),
+                _ => {}
+            },
+        )
+    }
+
+    fn bind_pressed(self, pressed_state: impl GetStateId<bool>) -> Self {
+        let typed_state_id = TypedStateId::from_state_id(pressed_state.get_id());
+
+        self.observe_many_retained(
+            [
+                PointerEventKind::Down,
+                PointerEventKind::Up,
+                PointerEventKind::DragEnd,
+            ],
+            move |trigger: Trigger<PointerEvent>, mut state: SetState| match trigger.kind {
+                PointerEventKind::Down => state.set_neq(typed_state_id, true),
+                PointerEventKind::Up | PointerEventKind::DragEnd => {
+                    state.set_neq(typed_state_id, false)
+                }
+                _ => {}
+            },
+        )
+    }
+
+    fn on_double_click(
+        mut self,
+        handler: impl Fn(&mut SetState) + Send + Sync + 'static,
+    ) -> Self {
+        let handler: GestureHandler = Arc::new(handler);
+
+        self.modifier()
+            .bundle_modifiers
+            .push(Arc::new(move |entity: &mut EntityCommands| {
+                let handler = handler.clone();
+                let updated_handler = handler.clone();
+
+                upsert_gesture(
+                    entity,
+                    move || DoubleClickGesture {
+                        handler,
+                        last_click: None,
+                    },
+                    move |gesture: &mut DoubleClickGesture| gesture.handler = updated_handler,
+                );
+            }));
+
+        self.observe_many_retained([PointerEventKind::Click], detect_double_click)
+    }
+
+    fn on_long_press(
+        mut self,
+        duration: Duration,
+        handler: impl Fn(&mut SetState) + Send + Sync + 'static,
+    ) -> Self {
+        let handler: GestureHandler = Arc::new(handler);
+
+        self.modifier()
+            .bundle_modifiers
+            .push(Arc::new(move |entity: &mut EntityCommands| {
+                let handler = handler.clone();
+                let updated_handler = handler.clone();
+
+                upsert_gesture(
+                    entity,
+                    move || LongPressGesture {
+                        handler,
+                        duration,
+                        pressed_at: None,
+                    },
+                    move |gesture: &mut LongPressGesture| {
+                        gesture.handler = updated_handler;
+                        gesture.duration = duration;
+                    },
+                );
+            }));
+
+        self.observe_many_retained(
+            [
+                PointerEventKind::Down,
+                PointerEventKind::Up,
+                PointerEventKind::Out,
+                PointerEventKind::Drag,
+            ],
+            detect_long_press,
+        )
+    }
+
+    fn on_drag(
+        mut self,
+        handler: impl Fn(DragGesture, &mut SetState) + Send + Sync + 'static,
+    ) -> Self {
+        let handler: DragHandler = Arc::new(handler);
+
+        self.modifier()
+            .bundle_modifiers
+            .push(Arc::new(move |entity: &mut EntityCommands| {
+                let handler = handler.clone();
+                let updated_handler = handler.clone();
+
+                upsert_gesture(
+                    entity,
+                    move || DragGestureTracker {
+                        handler,
+                        state: DragState::Idle,
+                        distance: Vec2::ZERO,
+                    },
+                    move |gesture: &mut DragGestureTracker| gesture.handler = updated_handler,
+                );
+            }));
+
+        self.observe_many_retained(
+            [
+                PointerEventKind::DragStart,
+                PointerEventKind::Drag,
+                PointerEventKind::DragEnd,
+            ],
+            detect_drag,
+        )
+    }
+
+    fn draggable<P: Any + Send + Sync>(mut self, payload: P) -> Self {
+        let payload: DragPayload = Arc::new(payload);
+
+        self.modifier()
+            .bundle_modifiers
+            .push(Arc::new(move |entity: &mut EntityCommands| {
+                let payload = payload.clone();
+                let updated_payload = payload.clone();
+
+                upsert_gesture(
+                    entity,
+                    move || Draggable { payload },
+                    move |draggable: &mut Draggable| draggable.payload = updated_payload,
+                );
+            }));
+
+        self.observe_many_retained(
+            [PointerEventKind::DragStart, PointerEventKind::DragEnd],
+            track_drag,
+        )
+    }
+
+    fn drop_target<P: Any + Send + Sync>(
+        mut self,
+        handler: impl Fn(&P, &mut SetState) + Send + Sync + 'static,
+    ) -> Self {
+        let handler: DropHandler = Arc::new(move |payload: &DragPayload, state: &mut SetState| {
+            let Some(payload) = payload.downcast_ref::<P>() else {
+                return false;
+            };
+
+            handler(payload, state);
+            true
+        });
+
+        self.modifier()
+            .bundle_modifiers
+            .push(Arc::new(move |entity: &mut EntityCommands| {
+                let handler = handler.clone();
+
+                upsert_gesture(
+                    entity,
+                    DropTarget::default,
+                    move |target: &mut DropTarget| {
+                        target.handlers.insert(TypeId::of::<P>(), handler);
+                    },
+                );
+            }));
+
+        self.observe_retained(drop_payload)
+    }
+
+    fn tooltip(mut self, tooltip: impl Into<Tooltip>) -> Self {
+        let modifier = self.modifier();
+        modifier.tooltip = Some(tooltip.into());
+
+        self.observe_many_retained(
+            [PointerEventKind::Over, PointerEventKind::Out],
+            track_tooltip_hover,
+        )
+    }
+
+    fn bind_scroll(self, scroll_state: impl GetStateId<Vec2>) -> Self {
+        let typed_state_id = TypedStateId::from_state_id(scroll_state.get_id());
+
+        // The wheel and drag deltas of the same frame build on each other, so that none of them are lost.
+        self.with_bundle(ScrollBinding(typed_state_id))
+            .observe_retained(
+                move |mut trigger: Trigger<ScrollWheel>, mut state: SetState| {
+                    let delta = trigger.delta;
+                    state.modify_after_queued(typed_state_id, move |offset| {
+                        (*offset + delta).max(Vec2::ZERO)
+                    });
+                    trigger.propagate(false);
+                },
+            )
+            .observe_retained(
+                move |trigger: Trigger<Pointer<Drag>>, mut state: SetState| {
+                    // The content follows the pointer, so it moves in the opposite direction of the scroll offset.
+                    let delta = trigger.delta;
+                    state.modify_after_queued(typed_state_id, move |offset| {
+                        (*offset - delta).max(Vec2::ZERO)
+                    });
+                },
+            )
+    }
+
+    fn bind_layout(self, layout_state: impl GetStateId<Option<NodeLayout>>) -> Self {
+        let typed_state_id = TypedStateId::from_state_id(layout_state.get_id());
+        self.with_bundle(LayoutWatcher(typed_state_id))
+    }
+
+    fn focusable(self) -> Self {
+        self.with_bundle(Focusable)
+    }
+
+    fn bind_interaction(mut self, interaction_state: impl GetStateId<InteractionState>) -> Self {
+        let typed_state_id = TypedStateId::from_state_id(interaction_state.get_id());
+
+        self.modifier()
+            .bundle_modifiers
+            .push(Arc::new(move |entity: &mut EntityCommands| {
+                upsert_gesture(
+                    entity,
+                    move || InteractionTracker {
+                        state: typed_state_id,
+                        is_hovered: false,
+                        is_pressed: false,
+                    },
+                    move |tracker: &mut InteractionTracker| tracker.state = typed_state_id,
+                );
+            }));
+
+        self.observe_many_retained(
+            [
+                PointerEventKind::Over,
+                PointerEventKind::Out,
+                PointerEventKind::Down,
+                PointerEventKind::Up,
+                PointerEventKind::DragEnd,
+            ],
+            track_interaction,
+        )
+    }
+
+    fn selectable<K: Clone + PartialEq + Send + Sync + 'static>(
+        self,
+        key: K,
+        selection: impl GetStateId<Selection<K>>,
+    ) -> Self {
+        let selection = TypedStateId::from_state_id(selection.get_id());
+
+        self.with_bundle(Selectable { key, selection })
+            .observe_many_retained([PointerEventKind::Click], select_on_click::<K>)
+    }
+
+    fn disabled(mut self, disabled: bool) -> Self {
+        self.modifier().disabled = Some(disabled);
+        self
     }
 }
 
@@ -212,9 +827,42 @@ pub trait ModifyFunctions<T>: Sized {
     // Uses given modifier
     fn use_modifier(self, modifier: &Modifier) -> Self::Target;
 
+    /// Uses the given modifier, combining its children with the existing children according to the given policy. This
+    /// lets composables forward their modifier without the externally supplied children replacing their own.
+    fn use_modifier_with(
+        self,
+        modifier: &Modifier,
+        children_policy: ChildrenPolicy,
+    ) -> Self::Target;
+
     /// Sets the children of the spawned entity.
     fn children(self, children: impl Compose + 'static) -> Self::Target;
 
+    /// Fills the named slot of a container composable, which lets containers accept several groups of children, like the
+    /// header, body and footer of a card. The container places the content of its slots with
+    /// [`Modifier::use_slot`]. Filling a slot again replaces its content. Slots are ignored by composables that don't
+    /// use them, like [`Spawn`](crate::spawn::Spawn).
+    ///
+    /// # Example
+    /// ```ignore
+    /// impl Card {
+    ///     pub fn slot_header(self, header: impl Compose + 'static) -> Self {
+    ///         self.slot("header", header)
+    ///     }
+    /// }
+    ///
+    /// impl Compose for Card {
+    ///     fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+    ///         Flex::column().children((
+    ///             self.modifier.use_slot("header"),
+    ///             self.modifier.get_children().clone(),
+    ///             self.modifier.use_slot("footer"),
+    ///         ))
+    ///     }
+    /// }
+    /// ```
+    fn slot(self, name: &'static str, content: impl Compose + 'static) -> Self::Target;
+
     // TODO: When `ObservedBy` is exposed, we should just retain it and SpawnComposable between each rerender and remove
     // all other components, so that we don't need to worry about removing conditional bundle components ourselves. This
     // will make this logic a lot simpler.
@@ -233,6 +881,94 @@ pub trait ModifyFunctions<T>: Sized {
     /// bundle, which means that the "main" bundle (of the same type) will always override the conditional bundles.
     fn with_bundle_if<B: Bundle + Clone>(self, condition: bool, bundle: B) -> Self::Target;
 
+    /// Applies the given modifications only if the condition is true, which lets builders branch without breaking the
+    /// chain.
+    ///
+    /// The modifications are applied when the composable is composed, so bundles that were added while the condition
+    /// was true are not removed once it becomes false. Use [`with_bundle_if`](ModifyFunctions::with_bundle_if) for
+    /// bundles that should be removed again.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Spawn::new(Node::default())
+    ///     .when(is_selected, |item| item.with_bundle(Outline::new(Val::Px(2.0), Val::ZERO, Color::WHITE)))
+    ///     .when(is_pinned, |item| item.order(-1))
+    /// ```
+    fn when(
+        self,
+        condition: bool,
+        modify: impl FnOnce(Self::Target) -> Self::Target,
+    ) -> Self::Target;
+
+    /// Overrides the order of the spawned entity relative to its siblings. By default, entities are ordered by the
+    /// position of their composables, and all of them have the order `0`. Entities with a lower order are placed before
+    /// entities with a higher order, regardless of their position.
+    fn order(self, order: i32) -> Self::Target;
+
+    /// Shows or hides the spawned entity by toggling its `Visibility`. Hidden entities are also ignored by picking, and
+    /// get their previous `PickingBehavior` back when they are shown again.
+    /// Unlike [`some_if`](ModifyFunctions::some_if), the composable stays composed while hidden, which means that its
+    /// state is preserved and its entities are kept alive. This makes it a cheaper alternative for frequently toggled
+    /// composables.
+    ///
+    /// Like other conditional bundles, a `Visibility` in the "main" bundle will override the visibility set here.
+    fn visible(self, visible: bool) -> Self::Target;
+
+    /// Sets whether the spawned entity can be hovered and clicked. Entities that aren't pickable don't receive pointer
+    /// events, but they still block the entities below them unless [`block_lower`](ModifyFunctions::block_lower) is
+    /// `false` as well. This is useful for decorations and overlays that shouldn't react to the pointer.
+    ///
+    /// The value sets the `is_hoverable` field of the `PickingBehavior` of the entity, and is applied after the other
+    /// conditional bundles, so it can be toggled with a state regardless of [`visible`](ModifyFunctions::visible).
+    ///
+    /// # Example
+    /// ```ignore
+    /// Node::default().pickable(*is_interactive)
+    /// ```
+    fn pickable(self, pickable: bool) -> Self::Target;
+
+    /// Sets whether the spawned entity blocks pointer events from reaching the entities below it. Entities that don't
+    /// block lower entities let the pointer pass through, while still receiving pointer events themselves, which is
+    /// useful for tooltips and highlights that are drawn over other UI.
+    ///
+    /// The value sets the `should_block_lower` field of the `PickingBehavior` of the entity. See
+    /// [`pickable`](ModifyFunctions::pickable).
+    ///
+    /// # Example
+    /// ```ignore
+    /// Node::default().pickable(false).block_lower(false)
+    /// ```
+    fn block_lower(self, block_lower: bool) -> Self::Target;
+
+    /// Delays the despawning of the spawned entity when the composable is decomposed. When the transition starts, the
+    /// given function is called and a [`Despawning`](crate::spawn::Despawning) component is added to the entity, which
+    /// can be used by other systems to animate the entity out. The entity is despawned after the given duration, and
+    /// it is not pickable in the meantime.
+    fn on_despawn_transition(
+        self,
+        on_start: impl Fn(&mut EntityCommands) + Send + Sync + 'static,
+        duration: Duration,
+    ) -> Self::Target;
+
+    /// Sets how the spawned entity is despawned when the composable is decomposed. By default, the entity is despawned
+    /// together with all of its descendants. When combined with
+    /// [`on_despawn_transition`](ModifyFunctions::on_despawn_transition), the policy is applied after the transition.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Node::default()
+    ///     .children(Inventory)
+    ///     .despawn_policy(DespawnPolicy::custom(|entity| {
+    ///         entity.insert(Visibility::Hidden);
+    ///     }))
+    /// ```
+    fn despawn_policy(self, policy: DespawnPolicy) -> Self::Target;
+
+    /// Detaches the spawned entity from the entity of the parent composable, which makes it a root node of the UI. This
+    /// lets composables like [`Modal`](crate::modal::Modal) place their entities above all other UI, while still being
+    /// composed in place. The children of the detached entity are spawned as usual.
+    fn detached(self) -> Self::Target;
+
     /// Converts this `Compose` into `DynCompose`.
     fn to_dyn(self) -> DynCompose
     where
@@ -254,6 +990,12 @@ pub trait ModifyFunctions<T>: Sized {
     where
         Self::Target: 'static;
 
+    /// Wraps this `Compose` in a [`ScopeKeyed`] compose, which tags its scope with the given stable key. The key can be
+    /// used to look up the scope with [`Scopes::find`](crate::scope::Scopes::find).
+    fn scope_key(self, key: impl Into<String>) -> ScopeKeyed
+    where
+        Self::Target: 'static;
+
     /// Adds an observer to the spawned entity. Observers are created and removed each time the composable recomposes.
     /// If you want to retain the observer, use the [`observe_retained`](Modify::observe_retained) function.
     fn observe<E: Event, B2: Bundle, M>(
@@ -267,6 +1009,247 @@ pub trait ModifyFunctions<T>: Sized {
         observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
     ) -> Self::Target;
 
+    /// Adds an observer that isn't tied to an entity, for untargeted triggers like gameplay events that are triggered
+    /// with `commands.trigger(...)`. The observer also receives the triggers that target an entity. Like
+    /// [`observe`](ModifyFunctions::observe), the observer is created and removed each time the composable recomposes,
+    /// and it is removed when the composable is decomposed.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let score = cx.use_state(0);
+    ///
+    /// Node::default().observe_global({
+    ///     let score = score.clone();
+    ///     move |trigger: Trigger<ScoreChanged>, mut state: SetState| state.set(&score, trigger.score)
+    /// })
+    /// ```
+    fn observe_global<E: Event, B2: Bundle, M>(
+        self,
+        observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
+    ) -> Self::Target;
+
+    /// Like [`observe_global`](ModifyFunctions::observe_global), but the observer is only added once, when the entity
+    /// is first spawned.
+    fn observe_global_retained<E: Event, B2: Bundle, M>(
+        self,
+        observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
+    ) -> Self::Target;
+
+    /// Adds an observer for the triggers that target the given entity, instead of the spawned entity. This lets a
+    /// composable react to events of an entity elsewhere in the world, like the player entity. Like
+    /// [`observe`](ModifyFunctions::observe), the observer is created and removed each time the composable recomposes,
+    /// and it is removed when the composable is decomposed or when the target is despawned.
+    fn observe_target<E: Event, B2: Bundle, M>(
+        self,
+        target: Entity,
+        observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
+    ) -> Self::Target;
+
+    /// Like [`observe_target`](ModifyFunctions::observe_target), but the observer is only added once, when the entity
+    /// is first spawned.
+    fn observe_target_retained<E: Event, B2: Bundle, M>(
+        self,
+        target: Entity,
+        observer: impl IntoObserverSystem<E, B2, M> + Clone + Sync,
+    ) -> Self::Target;
+
+    /// Adds a single observer to the spawned entity that handles pointer events of all the given kinds. The observer
+    /// receives a [`PointerEvent`], and can tell the kinds apart by matching on [`PointerEvent::kind`]. Compared to
+    /// adding an observer per event type, this spawns a single observer entity and initializes a single system, which
+    /// adds up for interactive composables that need many observers.
+    ///
+    /// Like [`observe`](ModifyFunctions::observe), the observer is created and removed each time the composable
+    /// recomposes.
+    ///
+    /// The kinds are tracked per entity, so the observer also receives the kinds that other `observe_many` calls on the
+    /// same entity have added. Observers should therefore always match on the kind, and ignore the kinds they don't
+    /// handle.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Node::default().observe_many(
+    ///     [PointerEventKind::Over, PointerEventKind::Out, PointerEventKind::Click],
+    ///     move |trigger: Trigger<PointerEvent>| match trigger.kind {
+    ///         PointerEventKind::Over => println!("Over"),
+    ///         PointerEventKind::Out => println!("Out"),
+    ///         PointerEventKind::Click => println!("Click"),
+    ///         _ => {}
+    ///     },
+    /// )
+    /// ```
+    fn observe_many<B2: Bundle, M>(
+        self,
+        kinds: impl IntoIterator<Item = PointerEventKind>,
+        observer: impl IntoObserverSystem<PointerEvent, B2, M> + Clone + Sync,
+    ) -> Self::Target;
+
+    /// Like [`observe_many`](ModifyFunctions::observe_many), but the observer is only added once, when the entity is
+    /// first spawned.
+    fn observe_many_retained<B2: Bundle, M>(
+        self,
+        kinds: impl IntoIterator<Item = PointerEventKind>,
+        observer: impl IntoObserverSystem<PointerEvent, B2, M> + Clone + Sync,
+    ) -> Self::Target;
+
     /// Binds the given state to the hovered state of the entity.
     fn bind_hover(self, hover_state: impl GetStateId<bool>) -> Self::Target;
+
+    /// Binds the given state to the pressed state of the entity. The state is `true` while a pointer is pressed down on
+    /// the entity.
+    fn bind_pressed(self, pressed_state: impl GetStateId<bool>) -> Self::Target;
+
+    /// Calls the handler when the spawned entity is double-clicked with the primary button. The maximum time between
+    /// the clicks is set by the [`GestureSettings`] resource.
+    fn on_double_click(
+        self,
+        handler: impl Fn(&mut SetState) + Send + Sync + 'static,
+    ) -> Self::Target;
+
+    /// Calls the handler when the primary button is held down on the spawned entity for the given duration. The long
+    /// press is cancelled when the button is released, when the pointer leaves the entity, or when the pointer moves
+    /// further than the drag threshold of the [`GestureSettings`] resource.
+    fn on_long_press(
+        self,
+        duration: Duration,
+        handler: impl Fn(&mut SetState) + Send + Sync + 'static,
+    ) -> Self::Target;
+
+    /// Calls the handler while the spawned entity is dragged. Unlike the raw `Pointer<Drag>` events, the drag only starts
+    /// once the pointer has moved further than the drag threshold of the [`GestureSettings`] resource, so that clicks
+    /// with a slightly moving pointer aren't treated as drags. A drag can be cancelled by pressing Escape. See
+    /// [`DragGesture`] for the phases of a drag.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let offset = cx.use_state(Vec2::ZERO);
+    /// let offset_id = offset.get_typed_id();
+    ///
+    /// Node::default().on_drag(move |drag, state| match drag.phase {
+    ///     GesturePhase::Cancelled => state.set(offset_id, Vec2::ZERO),
+    ///     _ => state.modify(offset_id, move |offset| *offset + drag.delta),
+    /// })
+    /// ```
+    fn on_drag(
+        self,
+        handler: impl Fn(DragGesture, &mut SetState) + Send + Sync + 'static,
+    ) -> Self::Target;
+
+    /// Makes the spawned entity draggable, carrying the given payload. While the entity is dragged, the payload is held
+    /// by the [`DragContext`](crate::drag_drop::DragContext), and it is handed to the
+    /// [`drop_target`](ModifyFunctions::drop_target) that the pointer is released over. Pressing Escape cancels the
+    /// drag. The payload is updated on every recomposition.
+    ///
+    /// The entity itself doesn't move. Use a [`DragGhost`](crate::drag_drop::DragGhost) to show the dragged payload
+    /// next to the pointer.
+    ///
+    /// # Example
+    /// ```ignore
+    /// ItemSlot::new(&item).draggable(item.id)
+    /// ```
+    fn draggable<P: Any + Send + Sync>(self, payload: P) -> Self::Target;
+
+    /// Calls the handler when a payload of type `P` is dropped on the spawned entity. See
+    /// [`draggable`](ModifyFunctions::draggable). Drop targets can accept several types of payloads, by calling this
+    /// function once for every type. When drop targets are nested, only the innermost target that accepts the type of
+    /// the payload receives it.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let slots = cx.use_state(vec![None; 9]);
+    /// let slots_id = slots.get_typed_id();
+    ///
+    /// Slot::new(index).drop_target(move |item_id: &ItemId, state| {
+    ///     let item_id = *item_id;
+    ///     state.modify(slots_id, move |slots| {
+    ///         let mut slots = slots.clone();
+    ///         slots[index] = Some(item_id);
+    ///         slots
+    ///     });
+    /// })
+    /// ```
+    fn drop_target<P: Any + Send + Sync>(
+        self,
+        handler: impl Fn(&P, &mut SetState) + Send + Sync + 'static,
+    ) -> Self::Target;
+
+    /// Shows a tooltip next to the spawned entity after it has been hovered for a while. The tooltip is composed on an
+    /// overlay above all other UI, and is removed when the pointer leaves the entity or when the composable is
+    /// decomposed. See [`Tooltip`] for the options.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Button::new("Save").tooltip(TextLabel::new("Saves the document"))
+    /// ```
+    fn tooltip(self, tooltip: impl Into<Tooltip>) -> Self::Target;
+
+    /// Binds the given state to the scroll offset of the entity, in logical pixels. Scrolling the mouse wheel over the
+    /// entity, or dragging it, changes the offset, which is applied to the `ScrollPosition` of the entity. The offset
+    /// is clamped to the size of the content once the layout has been computed, so the entity needs a `Node` with an
+    /// overflow that scrolls along the desired axes. Mouse wheel movements over the entity aren't passed on to its
+    /// ancestors.
+    ///
+    /// Unlike a [`ScrollView`](crate::scroll_view::ScrollView), the entity gets no scrollbars, which makes this the
+    /// building block for custom scrollable panels.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let scroll = cx.use_state(Vec2::ZERO);
+    ///
+    /// Node {
+    ///     height: Val::Px(300.0),
+    ///     overflow: Overflow::scroll_y(),
+    ///     flex_direction: FlexDirection::Column,
+    ///     ..default()
+    /// }
+    /// .children(log_lines)
+    /// .bind_scroll(&scroll)
+    /// ```
+    fn bind_scroll(self, scroll_state: impl GetStateId<Vec2>) -> Self::Target;
+
+    /// Binds the given state to the computed layout of the entity. See [`use_layout`](crate::Scope::use_layout).
+    fn bind_layout(self, layout_state: impl GetStateId<Option<NodeLayout>>) -> Self::Target;
+
+    /// Makes the spawned entity focusable, so that pressing it, or one of its descendants, gives it
+    /// [`InputFocus`](crate::focus::InputFocus).
+    fn focusable(self) -> Self::Target;
+
+    /// Binds the given state to the interaction state of the entity. See
+    /// [`use_interaction_state`](crate::Scope::use_interaction_state).
+    fn bind_interaction(self, interaction_state: impl GetStateId<InteractionState>) -> Self::Target;
+
+    /// Makes the spawned entity an item of the given selection, identified by the given key. Clicking the entity
+    /// selects it, ctrl-clicking toggles it, and shift-clicking selects the range from the last clicked item, according
+    /// to the [`SelectionMode`](crate::selection::SelectionMode). Ranges span the selectable siblings of the entity with
+    /// the same selection. See [`use_selection`](crate::Scope::use_selection).
+    ///
+    /// # Example
+    /// ```ignore
+    /// let selection = cx.use_selection::<usize>(SelectionMode::Multi);
+    ///
+    /// let color = if selection.is_selected(&index) { SELECTED } else { NORMAL };
+    ///
+    /// (Node::default(), BackgroundColor(color))
+    ///     .children(Text::new(name))
+    ///     .selectable(index, &selection)
+    /// ```
+    fn selectable<K: Clone + PartialEq + Send + Sync + 'static>(
+        self,
+        key: K,
+        selection: impl GetStateId<Selection<K>>,
+    ) -> Self::Target;
+
+    /// Disables or enables the spawned entity. A disabled entity and all entities composed inside of it can't be
+    /// hovered or clicked, so their pointer observers don't fire, and they are given the
+    /// [`Disabled`](crate::interaction::Disabled) component. The disabled entity itself is styled with the
+    /// [`DisabledStyle`](crate::interaction::DisabledStyle) resource. Composables inside of it can check whether they
+    /// are disabled with [`is_disabled`](crate::Scope::is_disabled), for example to dim their text.
+    ///
+    /// # Example
+    /// ```ignore
+    /// (Node::default(), BackgroundColor(color))
+    ///     .children(Text::new("Submit"))
+    ///     .observe(submit)
+    ///     .disabled(!*is_form_valid)
+    /// ```
+    fn disabled(self, disabled: bool) -> Self::Target;
 }
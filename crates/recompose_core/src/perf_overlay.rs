@@ -0,0 +1,174 @@
+use crate::{
+    diagnostics::RecomposeDiagnosticsPlugin,
+    label::LabelExtension,
+    modify::{Modifier, Modify, ModifyFunctions},
+    state::SetState,
+    Compose, Root, Scope,
+};
+use bevy_color::{Color, Srgba};
+use bevy_diagnostic::{DiagnosticsStore, EntityCountDiagnosticsPlugin, FrameTimeDiagnosticsPlugin};
+use bevy_ecs::system::{Query, Res};
+use bevy_ui::{AlignItems, BackgroundColor, Display, FlexDirection, Node, PositionType, UiRect, Val};
+
+/// The number of frame times shown in the frame time graph.
+const GRAPH_LENGTH: usize = 60;
+
+/// The frame time (in milliseconds) that fills the whole height of the frame time graph.
+const GRAPH_MAX_FRAME_TIME: f64 = 50.0;
+
+#[derive(Clone, Default, PartialEq)]
+struct PerfStats {
+    fps: Option<f64>,
+    frame_time: Option<f64>,
+    frame_times: Vec<f64>,
+    entity_count: Option<f64>,
+    scope_count: usize,
+    recomposed_scopes: Option<f64>,
+}
+
+/// A composable that displays an overlay with the FPS, a frame time graph, the entity count and the number of composed
+/// scopes. The FPS and frame time require the [`FrameTimeDiagnosticsPlugin`], the entity count requires the
+/// [`EntityCountDiagnosticsPlugin`] and the number of recomposed scopes per frame requires the
+/// [`RecomposeDiagnosticsPlugin`] to be added to the app. Statistics that aren't available are not shown.
+///
+/// # Example
+/// ```ignore
+/// commands.spawn_root(PerfOverlay::default());
+/// ```
+#[derive(Clone)]
+pub struct PerfOverlay {
+    /// How many times per second the overlay is updated. Rates that aren't positive update it every frame.
+    pub refresh_rate: f32,
+    pub modifier: Modifier,
+}
+
+impl Default for PerfOverlay {
+    fn default() -> Self {
+        Self {
+            refresh_rate: 10.0,
+            modifier: Modifier::default(),
+        }
+    }
+}
+
+impl Modify for PerfOverlay {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for PerfOverlay {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let stats = cx.use_state(PerfStats::default());
+        let stats_id = stats.get_typed_id();
+
+        // Updating the stats recomposes the overlay, which in turn runs the system again. The rate of this loop is
+        // limited by the refresh rate.
+        cx.recompose_at_most(self.refresh_rate);
+        cx.run_system(
+            move |diagnostics: Option<Res<DiagnosticsStore>>,
+                  roots: Query<&Root>,
+                  mut state: SetState| {
+                let mut new_stats = PerfStats {
+                    scope_count: roots.iter().map(Root::scope_count).sum(),
+                    ..Default::default()
+                };
+
+                if let Some(diagnostics) = diagnostics {
+                    let fps = diagnostics.get(&FrameTimeDiagnosticsPlugin::FPS);
+                    let frame_time = diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME);
+                    let entity_count = diagnostics.get(&EntityCountDiagnosticsPlugin::ENTITY_COUNT);
+
+                    new_stats.fps = fps.and_then(|fps| fps.smoothed());
+                    new_stats.frame_time = frame_time.and_then(|frame_time| frame_time.smoothed());
+                    new_stats.frame_times = frame_time
+                        .map(|frame_time| frame_time.values().copied().collect::<Vec<_>>())
+                        .unwrap_or_default();
+                    new_stats.entity_count = entity_count.and_then(|count| count.value());
+
+                    let recomposed_scopes =
+                        diagnostics.get(&RecomposeDiagnosticsPlugin::RECOMPOSED_SCOPES);
+                    new_stats.recomposed_scopes =
+                        recomposed_scopes.and_then(|count| count.smoothed());
+                }
+
+                let skip = new_stats.frame_times.len().saturating_sub(GRAPH_LENGTH);
+                new_stats.frame_times.drain(..skip);
+
+                state.set(stats_id, new_stats);
+            },
+        );
+
+        let fps = stats.fps.map(|fps| format!("FPS: {:.0}", fps).to_label());
+        let frame_time = stats
+            .frame_time
+            .map(|frame_time| format!("Frame time: {:.2} ms", frame_time).to_label());
+        let entity_count = stats
+            .entity_count
+            .map(|count| format!("Entities: {:.0}", count).to_label());
+        let scope_count = format!("Scopes: {}", stats.scope_count).to_label();
+        let recomposed_scopes = stats
+            .recomposed_scopes
+            .map(|count| format!("Recomposed: {:.1}/frame", count).to_label());
+
+        let bars = stats
+            .frame_times
+            .iter()
+            .enumerate()
+            .map(|(index, frame_time)| {
+                let height = (frame_time / GRAPH_MAX_FRAME_TIME).min(1.0) * 100.0;
+                let color = match *frame_time {
+                    t if t > 33.3 => Srgba::RED,
+                    t if t > 16.7 => Srgba::rgb(1.0, 0.8, 0.0),
+                    _ => Srgba::GREEN,
+                };
+
+                (
+                    Node {
+                        width: Val::Px(2.0),
+                        height: Val::Percent(height as f32),
+                        ..Default::default()
+                    },
+                    BackgroundColor(color.into()),
+                )
+                    .keyed(index)
+            })
+            .collect::<Vec<_>>();
+
+        let graph = Node {
+            display: Display::Flex,
+            align_items: AlignItems::End,
+            column_gap: Val::Px(1.0),
+            height: Val::Px(32.0),
+            ..Default::default()
+        }
+        .children(bars);
+
+        (
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(8.0),
+                right: Val::Px(8.0),
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                padding: UiRect::all(Val::Px(8.0)),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+        )
+            .children((
+                fps,
+                frame_time,
+                graph,
+                entity_count,
+                scope_count,
+                recomposed_scopes,
+            ))
+            .use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("PerfOverlay")
+    }
+}
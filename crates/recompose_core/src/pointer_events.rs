@@ -0,0 +1,201 @@
+use crate::interaction::Disabled;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::Event,
+    observer::Trigger,
+    query::Has,
+    system::{Commands, EntityCommands, Query},
+    world::World,
+};
+use bevy_math::Vec2;
+use bevy_picking::{
+    events::{Click, Down, Drag, DragEnd, DragStart, Move, Out, Over, Pointer, Up},
+    pointer::{PointerButton, PointerId},
+};
+use bevy_reflect::Reflect;
+use std::fmt::Debug;
+
+/// The kinds of pointer events that can be observed with [`observe_many`](crate::modify::ModifyFunctions::observe_many).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum PointerEventKind {
+    Over,
+    Out,
+    Down,
+    Up,
+    Click,
+    Move,
+    DragStart,
+    Drag,
+    DragEnd,
+}
+
+impl PointerEventKind {
+    fn bit(self) -> u16 {
+        1 << self as u16
+    }
+}
+
+/// A pointer event of one of several kinds, observed with
+/// [`observe_many`](crate::modify::ModifyFunctions::observe_many). It combines the data of the underlying
+/// [`Pointer`] events, so that a single observer can handle all of them by matching on the [`kind`](Self::kind).
+///
+/// Unlike the underlying events, this event doesn't propagate. It is triggered for each entity the underlying event
+/// bubbles through, as long as the entity observes the kind of the event.
+#[derive(Event, Clone, Debug)]
+pub struct PointerEvent {
+    /// The kind of the underlying pointer event.
+    pub kind: PointerEventKind,
+    /// The original target of the underlying pointer event, before bubbling.
+    pub target: Entity,
+    /// The pointer that triggered the event.
+    pub pointer_id: PointerId,
+    /// The position of the pointer, in logical pixels.
+    pub position: Vec2,
+    /// The button of the event, for the kinds that have one.
+    pub button: Option<PointerButton>,
+    /// The change in position since the last event, for `Move` and `Drag` events. Zero for all other kinds.
+    pub delta: Vec2,
+    /// The change in position since the drag started, for `Drag` and `DragEnd` events. Zero for all other kinds.
+    pub distance: Vec2,
+}
+
+/// The kinds of pointer events that are forwarded to the entity as [`PointerEvent`]s.
+#[derive(Component, Clone, Copy, Default)]
+pub(crate) struct PointerEventFilter(u16);
+
+impl PointerEventFilter {
+    fn contains(self, kind: PointerEventKind) -> bool {
+        self.0 & kind.bit() != 0
+    }
+}
+
+/// Adds the given kinds to the [`PointerEventFilter`] of the entity. Kinds are added rather than replaced, so that
+/// multiple `observe_many` calls on the same entity don't override each other.
+pub(crate) fn add_pointer_event_kinds(entity: &mut EntityCommands, kinds: &[PointerEventKind]) {
+    let bits = kinds.iter().fold(0, |bits, kind| bits | kind.bit());
+
+    entity.queue(move |entity: Entity, world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+
+        match entity.get_mut::<PointerEventFilter>() {
+            Some(mut filter) => filter.0 |= bits,
+            None => {
+                entity.insert(PointerEventFilter(bits));
+            }
+        }
+    });
+}
+
+/// The pointer events that can be forwarded as [`PointerEvent`]s.
+pub(crate) trait ForwardedPointerEvent: Debug + Clone + Reflect {
+    const KIND: PointerEventKind;
+
+    fn button(&self) -> Option<PointerButton> {
+        None
+    }
+
+    fn delta(&self) -> Vec2 {
+        Vec2::ZERO
+    }
+
+    fn distance(&self) -> Vec2 {
+        Vec2::ZERO
+    }
+}
+
+macro_rules! impl_forwarded_pointer_event {
+    ($event:ident) => {
+        impl ForwardedPointerEvent for $event {
+            const KIND: PointerEventKind = PointerEventKind::$event;
+        }
+    };
+    ($event:ident, button) => {
+        impl ForwardedPointerEvent for $event {
+            const KIND: PointerEventKind = PointerEventKind::$event;
+
+            fn button(&self) -> Option<PointerButton> {
+                Some(self.button)
+            }
+        }
+    };
+}
+
+impl_forwarded_pointer_event!(Over);
+impl_forwarded_pointer_event!(Out);
+impl_forwarded_pointer_event!(Down, button);
+impl_forwarded_pointer_event!(Up, button);
+impl_forwarded_pointer_event!(Click, button);
+impl_forwarded_pointer_event!(DragStart, button);
+
+impl ForwardedPointerEvent for Move {
+    const KIND: PointerEventKind = PointerEventKind::Move;
+
+    fn delta(&self) -> Vec2 {
+        self.delta
+    }
+}
+
+impl ForwardedPointerEvent for Drag {
+    const KIND: PointerEventKind = PointerEventKind::Drag;
+
+    fn button(&self) -> Option<PointerButton> {
+        Some(self.button)
+    }
+
+    fn delta(&self) -> Vec2 {
+        self.delta
+    }
+
+    fn distance(&self) -> Vec2 {
+        self.distance
+    }
+}
+
+impl ForwardedPointerEvent for DragEnd {
+    const KIND: PointerEventKind = PointerEventKind::DragEnd;
+
+    fn button(&self) -> Option<PointerButton> {
+        Some(self.button)
+    }
+
+    fn distance(&self) -> Vec2 {
+        self.distance
+    }
+}
+
+/// A global observer that forwards the pointer events of type `E` to the entities that observe them with
+/// `observe_many`. Pointer events are triggered for every entity they bubble through, which is why the current entity
+/// of the trigger is used as the target.
+pub(crate) fn forward_pointer_event<E: ForwardedPointerEvent>(
+    trigger: Trigger<Pointer<E>>,
+    filters: Query<(&PointerEventFilter, Has<Disabled>)>,
+    mut commands: Commands,
+) {
+    let entity = trigger.entity();
+
+    let Ok((filter, is_disabled)) = filters.get(entity) else {
+        return;
+    };
+
+    // Disabled entities aren't hoverable, but they still receive the events that bubble up from other entities.
+    if !filter.contains(E::KIND) || is_disabled {
+        return;
+    }
+
+    let pointer = trigger.event();
+
+    let event = PointerEvent {
+        kind: E::KIND,
+        target: pointer.target,
+        pointer_id: pointer.pointer_id,
+        position: pointer.pointer_location.position,
+        button: pointer.event.button(),
+        delta: pointer.event.delta(),
+        distance: pointer.event.distance(),
+    };
+
+    commands.trigger_targets(event, entity);
+}
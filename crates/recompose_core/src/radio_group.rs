@@ -0,0 +1,191 @@
+use crate::{
+    binding::{BindValue, Binding, BoundSetter, ChangeHandler},
+    bundle_extension::BundleExtension,
+    keyed::Keyed,
+    label::TextLabel,
+    modify::{Modifier, Modify, ModifyFunctions},
+    state::SetState,
+    Compose, Scope,
+};
+use bevy_color::Srgba;
+use bevy_ecs::observer::Trigger;
+use bevy_picking::events::{Click, Pointer};
+use bevy_ui::{
+    AlignItems, BackgroundColor, BorderColor, BorderRadius, FlexDirection, JustifyContent, Node,
+    UiRect, Val,
+};
+use std::{fmt::Display, sync::Arc};
+
+const INDICATOR_SIZE: f32 = 18.0;
+const DOT_SIZE: f32 = 8.0;
+
+/// A composable that lets the user select one of a list of options, shown as a column of radio buttons.
+///
+/// The selected index is controlled by the parent: it is passed in with [`selected`](RadioGroup::selected), and edits
+/// are reported through [`on_select`](RadioGroup::on_select). Alternatively, the selected index can be bound to a
+/// state with [`bind_value`](BindValue::bind_value).
+///
+/// # Example
+/// ```ignore
+/// let difficulty = cx.use_state(Some(1));
+///
+/// RadioGroup::new(["Easy", "Normal", "Hard"]).bind_value(&difficulty)
+/// ```
+#[derive(Clone)]
+pub struct RadioGroup {
+    options: Vec<String>,
+    selected: Option<usize>,
+    on_select: Option<ChangeHandler<Option<usize>>>,
+    binding: Option<Binding<Option<usize>>>,
+    modifier: Modifier,
+}
+
+impl RadioGroup {
+    /// Creates a new radio group with the given options and nothing selected.
+    pub fn new<T: Display>(options: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            options: options
+                .into_iter()
+                .map(|option| option.to_string())
+                .collect(),
+            selected: None,
+            on_select: None,
+            binding: None,
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Sets the index of the selected option.
+    pub fn selected(mut self, selected: Option<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the function that is called with the index of the new selected option when an option is selected.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(Option<usize>, &mut SetState) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_select = Some(Arc::new(on_select));
+        self
+    }
+}
+
+impl BindValue<Option<usize>> for RadioGroup {
+    fn binding(&mut self) -> &mut Option<Binding<Option<usize>>> {
+        &mut self.binding
+    }
+}
+
+impl Modify for RadioGroup {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for RadioGroup {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let selected = cx.use_binding(self.binding.as_ref(), self.selected);
+
+        let options = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| {
+                let option = RadioOption {
+                    label: option.clone(),
+                    index,
+                    is_selected: *selected == Some(index),
+                    setter: selected.setter(),
+                    on_select: self.on_select.clone(),
+                };
+
+                Keyed::new(index, option)
+            })
+            .collect::<Vec<_>>();
+
+        Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(6.0),
+            ..Default::default()
+        }
+        .children(options)
+        .use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("RadioGroup")
+    }
+}
+
+#[derive(Clone)]
+struct RadioOption {
+    label: String,
+    index: usize,
+    is_selected: bool,
+    setter: BoundSetter<Option<usize>>,
+    on_select: Option<ChangeHandler<Option<usize>>>,
+}
+
+impl Compose for RadioOption {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let is_hovered = cx.use_state(false);
+
+        let index = self.index;
+        let setter = self.setter;
+        let on_select = self.on_select.clone();
+
+        let accent = Srgba::rgb(0.25, 0.5, 0.95);
+
+        let border = match (self.is_selected, *is_hovered) {
+            (true, _) => accent,
+            (false, true) => Srgba::rgb(0.7, 0.7, 0.7),
+            (false, false) => Srgba::rgb(0.5, 0.5, 0.5),
+        };
+
+        let dot = (
+            Node {
+                width: Val::Px(DOT_SIZE),
+                height: Val::Px(DOT_SIZE),
+                ..Default::default()
+            },
+            BackgroundColor(accent.into()),
+            BorderRadius::MAX,
+        )
+            .to_compose()
+            .some_if(self.is_selected);
+
+        let indicator = (
+            Node {
+                width: Val::Px(INDICATOR_SIZE),
+                height: Val::Px(INDICATOR_SIZE),
+                border: UiRect::all(Val::Px(2.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..Default::default()
+            },
+            BorderColor(border.into()),
+            BorderRadius::MAX,
+        )
+            .children(dot);
+
+        Node {
+            column_gap: Val::Px(8.0),
+            align_items: AlignItems::Center,
+            ..Default::default()
+        }
+        .children((indicator, TextLabel::new(&self.label).font_size(14.0)))
+        .bind_hover(&is_hovered)
+        .observe(move |_: Trigger<Pointer<Click>>, mut state: SetState| {
+            setter.set(&mut state, Some(index));
+
+            if let Some(on_select) = &on_select {
+                on_select(Some(index), &mut state);
+            }
+        })
+    }
+
+    fn name(&self) -> String {
+        String::from("RadioOption")
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{
+    state::{GetStateId, SetState, State, TypedStateId},
+    Scope,
+};
+use std::sync::Arc;
+
+/// Dispatches actions to the reducer of a state, from observers and systems. See
+/// [`use_reducer`](Scope::use_reducer).
+pub struct Dispatcher<A> {
+    #[allow(clippy::type_complexity)]
+    dispatch: Arc<dyn Fn(&mut SetState, A) + Send + Sync>,
+}
+
+impl<A> Clone for Dispatcher<A> {
+    fn clone(&self) -> Self {
+        Self {
+            dispatch: self.dispatch.clone(),
+        }
+    }
+}
+
+impl<A: Send + Sync + 'static> Dispatcher<A> {
+    /// Queues the given action. The reducer is applied to the state when the queued state changes are applied, which
+    /// recomposes the composable. Actions that are dispatched within the same frame are all applied, in the order they
+    /// were dispatched.
+    pub fn dispatch(&self, state: &mut SetState, action: A) {
+        (self.dispatch)(state, action);
+    }
+}
+
+impl Scope<'_> {
+    /// Creates a state whose changes are described by actions. Dispatching an action with the returned [`Dispatcher`]
+    /// queues the reducer, which computes the next value of the state from the current value and the action. This
+    /// keeps all transitions of complex widgets, like wizards and editors, in a single function that is easy to reason
+    /// about and to test.
+    ///
+    /// # Example
+    /// ```ignore
+    /// enum WizardAction {
+    ///     Next,
+    ///     Back,
+    /// }
+    ///
+    /// fn reduce(step: &usize, action: &WizardAction) -> usize {
+    ///     match action {
+    ///         WizardAction::Next => (step + 1).min(LAST_STEP),
+    ///         WizardAction::Back => step.saturating_sub(1),
+    ///     }
+    /// }
+    ///
+    /// let (step, dispatcher) = cx.use_reducer(0, reduce);
+    ///
+    /// Text::new("Next").observe(move |_: Trigger<Pointer<Click>>, mut state: SetState| {
+    ///     dispatcher.dispatch(&mut state, WizardAction::Next);
+    /// })
+    /// ```
+    pub fn use_reducer<S: Send + Sync + 'static, A: Send + Sync + 'static>(
+        &mut self,
+        initial_value: S,
+        reducer: impl Fn(&S, &A) -> S + Send + Sync + 'static,
+    ) -> (State<S>, Dispatcher<A>) {
+        let state = self.use_state(initial_value);
+        let state_id = TypedStateId::<S>::from_state_id(state.get_id());
+        let reducer = Arc::new(reducer);
+
+        let dispatcher = Dispatcher {
+            dispatch: Arc::new(move |setter: &mut SetState, action: A| {
+                let reducer = reducer.clone();
+                setter.modify_after_queued(state_id, move |value| reducer(value, &action));
+            }),
+        };
+
+        (state, dispatcher)
+    }
+}
@@ -0,0 +1,270 @@
+use crate::{
+    animated_list::advance_transition, drag_drop::DragContext, dyn_compose::DynCompose,
+    keyed::Keyed, modify::ModifyFunctions, scope::ScopeId, state::SetState, store::Store, AnyKey,
+    Compose, Key, Scope,
+};
+use bevy_ecs::{observer::Trigger, system::Res};
+use bevy_picking::events::{DragEnter, Pointer};
+use bevy_time::Time;
+use bevy_ui::Node;
+use std::{sync::Arc, time::Duration};
+
+/// The phase of an item in a [`ReorderableList`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReorderItemPhase {
+    /// The item is in its place.
+    Idle,
+    /// The item is being dragged to a new position.
+    Dragging,
+}
+
+/// The drag payload of the items of a [`ReorderableList`]. It can be used to render a
+/// [`DragGhost`](crate::drag_drop::DragGhost) for the dragged item.
+///
+/// # Example
+/// ```ignore
+/// DragGhost::new(|payload: &ReorderPayload<Todo>| TodoItem::new(payload.item().clone()))
+/// ```
+#[derive(Clone)]
+pub struct ReorderPayload<T> {
+    list: ScopeId,
+    index: usize,
+    item: T,
+}
+
+impl<T> ReorderPayload<T> {
+    /// Returns the index of the dragged item.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the dragged item.
+    pub fn item(&self) -> &T {
+        &self.item
+    }
+}
+
+/// The children of a `ReorderableList` are either items or the placeholder.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum ReorderKey {
+    Item(AnyKey),
+    Placeholder,
+}
+
+#[derive(Clone, Copy, Default)]
+struct PlaceholderTransition {
+    /// The index that the dragged item would be moved to.
+    target: Option<usize>,
+    /// The elapsed time of the app when the placeholder appeared at the target.
+    started: Option<Duration>,
+    progress: f32,
+}
+
+type RenderFn<T> = Arc<dyn Fn(&T, ReorderItemPhase) -> DynCompose + Send + Sync>;
+type PlaceholderFn = Arc<dyn Fn(f32) -> DynCompose + Send + Sync>;
+type ReorderFn = Arc<dyn Fn(usize, usize, &mut SetState) + Send + Sync>;
+
+/// A keyed list of items that can be reordered by dragging them to new positions, built on
+/// [`draggable`](crate::modify::ModifyFunctions::draggable) and
+/// [`drop_target`](crate::modify::ModifyFunctions::drop_target). Like a `Vec` of keyed composables, the items are
+/// identified by their [`Key`], so they keep their states when they are moved.
+///
+/// Every item is composed inside of its own `Node` with the `render` function, which receives the
+/// [`ReorderItemPhase`] of the item. While an item is dragged, a placeholder is shown where it would be dropped. The
+/// placeholder function receives the progress of its appear transition, from `0.0` to `1.0`. When an item is dropped,
+/// the `on_reorder` callback is called with the current and the new index of the item, and is expected to update the
+/// items accordingly.
+///
+/// # Example
+/// ```ignore
+/// let todos = cx.use_state(vec![]);
+/// let todos_id = todos.get_typed_id();
+///
+/// ReorderableList::new((*todos).clone(), |todo, phase| {
+///     TodoItem::new(todo.clone()).visible(phase == ReorderItemPhase::Idle)
+/// })
+/// .placeholder(|progress| Spawn::new(Node { height: Val::Px(32.0 * progress), ..default() }))
+/// .on_reorder(move |from, to, state| {
+///     state.modify(todos_id, move |todos| {
+///         let mut todos = todos.clone();
+///         let todo = todos.remove(from);
+///         todos.insert(to, todo);
+///         todos
+///     });
+/// })
+/// ```
+#[derive(Clone)]
+pub struct ReorderableList<T> {
+    items: Vec<T>,
+    render: RenderFn<T>,
+    placeholder: PlaceholderFn,
+    on_reorder: Option<ReorderFn>,
+    transition_duration: Duration,
+}
+
+impl<T: Key + Clone + Send + Sync + 'static> ReorderableList<T> {
+    /// Creates a new reorderable list from the given items and render function.
+    pub fn new<C: Compose + 'static>(
+        items: Vec<T>,
+        render: impl Fn(&T, ReorderItemPhase) -> C + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            items,
+            render: Arc::new(move |item, phase| DynCompose::new(render(item, phase))),
+            placeholder: Arc::new(|_| DynCompose::default()),
+            on_reorder: None,
+            transition_duration: Duration::from_millis(150),
+        }
+    }
+
+    /// Sets the placeholder that is shown where the dragged item would be dropped. By default, no placeholder is shown.
+    pub fn placeholder<C: Compose + 'static>(
+        mut self,
+        placeholder: impl Fn(f32) -> C + Send + Sync + 'static,
+    ) -> Self {
+        self.placeholder = Arc::new(move |progress| DynCompose::new(placeholder(progress)));
+        self
+    }
+
+    /// Sets the callback that is called with the current and the new index of an item when it is dropped.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(usize, usize, &mut SetState) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_reorder = Some(Arc::new(on_reorder));
+        self
+    }
+
+    /// Sets the duration of the appear transition of the placeholder.
+    pub fn transition_duration(mut self, transition_duration: Duration) -> Self {
+        self.transition_duration = transition_duration;
+        self
+    }
+}
+
+impl<T: Key + Clone + Send + Sync + 'static> Compose for ReorderableList<T> {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let list = cx.handle().id();
+        let dragged = cx
+            .use_drag_payload::<ReorderPayload<T>>()
+            .filter(|payload| payload.list == list);
+
+        let target = cx.use_state(None::<usize>);
+        let target_id = target.get_typed_id();
+        let transition = cx.use_state(PlaceholderTransition::default());
+        let transition_id = transition.get_typed_id();
+
+        // The target is left over from the last drag when no item of this list is dragged anymore.
+        if dragged.is_none() && target.is_some() {
+            cx.set_state_unchanged(&target, None);
+        }
+
+        let from = dragged.as_ref().map(|payload| payload.index);
+        let to = dragged
+            .as_ref()
+            .and((*target).filter(|to| Some(*to) != from));
+
+        let mut transition = *transition;
+
+        if transition.target != to {
+            transition = PlaceholderTransition {
+                target: to,
+                started: None,
+                progress: 0.0,
+            };
+            cx.set_state_unchanged(transition_id, transition);
+        }
+
+        if to.is_some() && transition.progress < 1.0 {
+            let duration = self.transition_duration;
+
+            cx.run_system(move |time: Option<Res<Time>>, mut state: SetState| {
+                let now = time.map(|time| time.elapsed());
+
+                state.modify(transition_id, move |transition| {
+                    let mut transition = *transition;
+                    transition.progress =
+                        advance_transition(&mut transition.started, now, duration);
+                    transition
+                });
+            });
+        }
+
+        let on_reorder = self.on_reorder.clone();
+
+        let drop_at = move |to: usize| {
+            let on_reorder = on_reorder.clone();
+
+            move |payload: &ReorderPayload<T>, state: &mut SetState| {
+                if payload.list != list || payload.index == to {
+                    return;
+                }
+
+                if let Some(on_reorder) = &on_reorder {
+                    on_reorder(payload.index, to, state);
+                }
+            }
+        };
+
+        let mut children = self
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let phase = match from == Some(index) {
+                    true => ReorderItemPhase::Dragging,
+                    false => ReorderItemPhase::Idle,
+                };
+
+                let payload = ReorderPayload {
+                    list,
+                    index,
+                    item: item.clone(),
+                };
+
+                let key = ReorderKey::Item(AnyKey::new(item));
+
+                let item = Node::default()
+                    .children((self.render)(item, phase))
+                    .draggable(payload)
+                    .drop_target(drop_at(index))
+                    .observe(
+                        move |_: Trigger<Pointer<DragEnter>>,
+                              context: Res<Store<DragContext>>,
+                              mut state: SetState| {
+                            let is_dragged_from_list = context
+                                .payload::<ReorderPayload<T>>()
+                                .is_some_and(|payload| payload.list == list);
+
+                            if is_dragged_from_list {
+                                state.set_neq(target_id, Some(index));
+                            }
+                        },
+                    );
+
+                Keyed::new(key, item)
+            })
+            .collect::<Vec<_>>();
+
+        if let (Some(from), Some(to)) = (from, to) {
+            // The placeholder takes the place that the item would move to, which is after the target when the item
+            // moves down, and before it when the item moves up.
+            let placeholder_index = if to > from { to + 1 } else { to };
+
+            let placeholder = Node::default()
+                .children((self.placeholder)(transition.progress))
+                .drop_target(drop_at(to));
+
+            children.insert(
+                placeholder_index.min(children.len()),
+                Keyed::new(ReorderKey::Placeholder, placeholder),
+            );
+        }
+
+        children
+    }
+
+    fn name(&self) -> String {
+        String::from("ReorderableList")
+    }
+}
@@ -1,20 +1,277 @@
 use crate::{
-    state::{Dependency, DynState, GetStateId, State, StateId, TypedStateId},
-    unique_id, AnyCompose, ChildIndex, StateChanged,
+    context::RootContext,
+    error_boundary::ErrorBoundaryId,
+    layout_direction::LayoutDirection,
+    state::{
+        DeriveSources, Dependency, DynState, GetStateId, SetState, State, StateError, StateId,
+        TypedStateId,
+    },
+    unique_id, AnyCompose, ChildIndex, Root, StateChanged,
 };
 use bevy_ecs::{
     entity::Entity,
-    system::{BoxedSystem, IntoSystem},
+    event::{Event, Events},
+    system::{BoxedSystem, Commands, In, IntoSystem, Query, ResMut, SystemParam},
 };
+use bevy_log::warn;
+use smallvec::SmallVec;
+#[cfg(feature = "debug_assert_hooks")]
+use std::{any::TypeId, panic::Location};
 use std::{
     any::Any,
     fmt::{Debug, Display},
+    ops::Add,
     sync::Arc,
+    time::Duration,
 };
 
 #[derive(Default, Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
 pub struct ScopeId(usize);
 
+/// A handle to a scope that can be captured by observers and systems. It is used to refer to the states of the scope by
+/// their index, so that simple composables don't need to create a [`TypedStateId`] for every state they want to modify
+/// from the outside.
+///
+/// # Example
+/// ```ignore
+/// let count = cx.use_state(0);
+/// let scope = cx.handle();
+///
+/// Button::default().observe(move |_: Trigger<Pointer<Click>>, mut state: SetState| {
+///     state.modify(scope.state::<i32>(0), |count| *count + 1);
+/// })
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ScopeHandle {
+    id: ScopeId,
+}
+
+impl ScopeHandle {
+    /// Returns the id of the scope.
+    pub fn id(&self) -> ScopeId {
+        self.id
+    }
+
+    /// Returns a typed state id that refers to the state at the given index. The index is the order in which the state
+    /// was created in the `compose` function.
+    pub fn state<T>(&self, index: usize) -> TypedStateId<T> {
+        TypedStateId::from_state_id(StateId::Indexed(self.id, index))
+    }
+}
+
+/// The reason why a composable is (re)composed. See [`recompose_reason`](Scope::recompose_reason).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum RecomposeReason {
+    /// The composable is composed for the first time.
+    Mount,
+    /// The parent of the composable recomposed, and none of the states of the composable changed.
+    ParentRecomposed,
+    /// States of the composable were changed by the composable itself, for example with
+    /// [`set_state`](Scope::set_state). Contains the ids of the changed states.
+    StateChanged(Vec<StateId>),
+    /// States of the composable were set from the outside with [`SetState`](crate::state::SetState). Contains the ids
+    /// of all changed states, including the ones changed by the composable itself.
+    SetState(Vec<StateId>),
+}
+
+impl RecomposeReason {
+    /// Returns the ids of the changed states, if the composable recomposes because its states changed.
+    pub fn changed_states(&self) -> &[StateId] {
+        match self {
+            RecomposeReason::StateChanged(ids) | RecomposeReason::SetState(ids) => ids,
+            RecomposeReason::Mount | RecomposeReason::ParentRecomposed => &[],
+        }
+    }
+}
+
+/// A read-only view of a composed scope. It lets tools, tests and debug overlays inspect the composed tree at runtime.
+/// Scope references are returned by [`Root::scope`] and the [`Scopes`] system param.
+///
+/// # Example
+/// ```ignore
+/// fn print_tree(scopes: Scopes) {
+///     for (_, scope) in scopes.iter() {
+///         for descendant in scope.descendants() {
+///             println!("{} at {}", descendant.name(), descendant.stable_path());
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct ScopeRef<'a> {
+    scope: &'a Scope<'static>,
+}
+
+impl<'a> ScopeRef<'a> {
+    pub(crate) fn new(scope: &'a Scope<'static>) -> Self {
+        Self { scope }
+    }
+
+    /// Returns the id of the scope.
+    pub fn id(&self) -> ScopeId {
+        self.scope.id
+    }
+
+    /// Returns the name of the composable of the scope. See [`Compose::name`](crate::Compose::name).
+    pub fn name(&self) -> String {
+        self.scope.composer.get_name()
+    }
+
+    /// Returns the entity spawned by the composable of the scope, if any.
+    pub fn entity(&self) -> Option<Entity> {
+        self.scope.entity
+    }
+
+    /// Returns the stable path of the scope. See [`StablePath`].
+    pub fn stable_path(&self) -> &'a StablePath {
+        &self.scope.path
+    }
+
+    /// Returns the stable key of the scope, if it has one. See [`ScopeKeyed`](crate::keyed::ScopeKeyed).
+    pub fn scope_key(&self) -> Option<&'a str> {
+        self.scope.scope_key.as_deref()
+    }
+
+    /// Returns the first scope with the given [stable key](crate::keyed::ScopeKeyed) among the scope and its
+    /// descendants, in depth-first order.
+    pub fn find(&self, key: &str) -> Option<ScopeRef<'a>> {
+        self.descendants()
+            .find(|scope| scope.scope_key() == Some(key))
+    }
+
+    /// Returns a handle to the scope, which can be used to refer to the states of the scope by their index.
+    pub fn handle(&self) -> ScopeHandle {
+        self.scope.handle()
+    }
+
+    /// Returns the child scopes of the scope.
+    pub fn children(&self) -> impl Iterator<Item = ScopeRef<'a>> + 'a {
+        self.scope.children.iter().map(ScopeRef::new)
+    }
+
+    /// Returns the scope and all of its descendants, in depth-first order.
+    pub fn descendants(&self) -> impl Iterator<Item = ScopeRef<'a>> + use<'a> {
+        let mut stack = vec![self.scope];
+
+        std::iter::from_fn(move || {
+            let scope = stack.pop()?;
+            stack.extend(scope.children.iter().rev());
+            Some(ScopeRef::new(scope))
+        })
+    }
+
+    /// Returns the number of states of the scope.
+    pub fn state_count(&self) -> usize {
+        self.scope.states.len()
+    }
+
+    /// Returns the state at the given index. The index is the order in which the state was created in the `compose`
+    /// function. Returns `None` if the scope has no state at the index, or if the state is not of type `T`.
+    pub fn state<T: Any + Send + Sync>(&self, index: usize) -> Option<State<T>> {
+        let dyn_state = self.scope.states.get(index)?;
+
+        Some(State {
+            id: dyn_state.id,
+            changed: dyn_state.changed,
+            value: dyn_state.value.clone().downcast::<T>().ok()?,
+            initial: dyn_state.initial.clone().downcast::<T>().ok()?,
+        })
+    }
+}
+
+impl Debug for ScopeRef<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self.scope, f)
+    }
+}
+
+/// A system param for inspecting the composed trees of all roots. See [`ScopeRef`].
+#[derive(SystemParam)]
+pub struct Scopes<'w, 's> {
+    roots: Query<'w, 's, (Entity, &'static Root)>,
+}
+
+impl Scopes<'_, '_> {
+    /// Returns the root scopes of all composed roots, together with the entities of the roots.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, ScopeRef<'_>)> {
+        self.roots
+            .iter()
+            .filter_map(|(entity, root)| Some((entity, root.scope()?)))
+    }
+
+    /// Returns the root scope of the root on the given entity, or `None` if the entity has no composed root.
+    pub fn get(&self, root_entity: Entity) -> Option<ScopeRef<'_>> {
+        self.roots.get(root_entity).ok()?.1.scope()
+    }
+
+    /// Returns the first scope with the given [stable key](crate::keyed::ScopeKeyed) in any of the roots, together with
+    /// the entity of its root.
+    ///
+    /// # Example
+    /// ```ignore
+    /// fn reset_inventory(scopes: Scopes, mut state: SetState) {
+    ///     if let Some((_, panel)) = scopes.find("inventory.panel") {
+    ///         state.reset(panel.handle().state::<usize>(0));
+    ///     }
+    /// }
+    /// ```
+    pub fn find(&self, key: &str) -> Option<(Entity, ScopeRef<'_>)> {
+        self.iter()
+            .find_map(|(entity, scope)| Some((entity, scope.find(key)?)))
+    }
+}
+
+/// A single segment of a [`StablePath`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub enum PathSegment {
+    /// The scope is addressed by its position relative to its parent scope.
+    Index(usize),
+    /// The scope is addressed by the hash of its key. See [`Key`](crate::Key).
+    Key(u64),
+}
+
+/// The path of a scope from its root scope. Unlike the [`ScopeId`], which is generated at runtime, the path only depends
+/// on the structure of the composables and the keys of keyed composables, which makes it stable across runs.
+// Every scope clones the path of its parent, so the segments of shallow paths are stored inline.
+#[derive(Default, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub struct StablePath(SmallVec<[PathSegment; 8]>);
+
+impl StablePath {
+    /// Returns the segments of the path, starting from the root scope.
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    pub(crate) fn push(&mut self, segment: PathSegment) {
+        self.0.push(segment);
+    }
+
+    pub(crate) fn set_last(&mut self, segment: PathSegment) {
+        if let Some(last) = self.0.last_mut() {
+            *last = segment;
+        }
+    }
+}
+
+impl Display for StablePath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "/")?;
+
+        for (i, segment) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "/")?;
+            }
+
+            match segment {
+                PathSegment::Index(index) => write!(f, "{}", index)?,
+                PathSegment::Key(key) => write!(f, "#{:016x}", key)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// A scope can be thought of as a "sum" of all modifications done by the [`compose`](crate::Compose::compose) function
 /// of the [`Compose`](crate::Compose) trait. It holds the state of the composable and its children scopes. It is the
 /// "actual" node in the tree-structure of the composables.
@@ -31,12 +288,20 @@ pub struct Scope<'a> {
     /// For composables that spawn an entity, this is the field that holds the rerefence to the entity.
     pub(crate) entity: Option<Entity>,
 
+    /// The path of the scope from the root scope. See [`StablePath`] for more information.
+    pub(crate) path: StablePath,
+
     /// The entity of the scopes most immediate parent with an entity.
     pub(crate) parent_entity: Entity,
 
     /// Indicates if the scope will decompose on before the next recomposition.
     pub(crate) will_decompose: bool,
 
+    /// Whether the scope is an inactive branch of a [cached](crate::dyn_compose::DynCompose::cached) `DynCompose`.
+    /// Inactive scopes keep their states and entities, but they are neither recomposed nor updated until they become
+    /// active again.
+    pub(crate) is_inactive: bool,
+
     /// A copy of the composer that created this scope. It is used to recompose this scope when one of the states was
     /// changed, but the parent scope was recomposed.
     pub(crate) composer: Arc<dyn AnyCompose + 'a>,
@@ -50,9 +315,120 @@ pub struct Scope<'a> {
     /// The children of the composable.
     pub(crate) children: Vec<Scope<'a>>,
 
+    /// The minimum duration between two recompositions triggered by the states of this scope. See
+    /// [`recompose_at_most`](Scope::recompose_at_most).
+    pub(crate) min_recompose_interval: Option<Duration>,
+
+    /// The (real) time at which the scope was last recomposed because of its own states.
+    pub(crate) last_recomposed: Option<Duration>,
+
+    /// The priority of the scope when recompositions are deferred because the root ran out of its budget. See
+    /// [`recompose_priority`](Scope::recompose_priority).
+    pub(crate) recompose_priority: i32,
+
+    /// The frame in which the scope was last recomposed, and the number of its recompositions in that frame. See
+    /// [`RecomposeSettings::max_recompositions_per_frame`](crate::settings::RecomposeSettings::max_recompositions_per_frame).
+    pub(crate) frame_recompositions: (u64, u32),
+
+    /// The number of consecutive frames in which the scope was recomposed because of states it set itself. See
+    /// [`LoopDetection`](crate::settings::LoopDetection).
+    pub(crate) loop_frames: u32,
+
+    /// Whether the scope recomposes itself in every frame on purpose. See
+    /// [`allow_recompose_loop`](Scope::allow_recompose_loop).
+    pub(crate) allows_recompose_loop: bool,
+
+    /// Whether the scope was detected as a loop, which throttles its recompositions.
+    pub(crate) is_loop_throttled: bool,
+
     /// The "collected" systems after the `compose`-function was executed. The systems are run and discarded after the
     /// recomposition.
     pub(crate) queued_systems: Vec<BoxedSystem<(), ()>>,
+
+    /// The systems that are run when the scope is decomposed. They are collected anew on every recomposition.
+    pub(crate) unmount_systems: Vec<BoxedSystem<(), ()>>,
+
+    /// The systems that are run when the scope is decomposed, which were collected when the scope was first composed.
+    /// See [`use_unmount_system_once`](Scope::use_unmount_system_once).
+    pub(crate) retained_unmount_systems: Vec<BoxedSystem<(), ()>>,
+
+    /// The guards that delay the decomposition of the scope. They are collected anew on every recomposition. See
+    /// [`use_despawn_guard`](Scope::use_despawn_guard).
+    pub(crate) despawn_guards: Vec<DespawnGuard>,
+
+    /// Whether the scope should be decomposed, but waits for its own despawn guards or those of its descendants.
+    pub(crate) is_leaving: bool,
+
+    /// The reason of the current (or last) composition. It is `None` until the scope is composed for the first time.
+    pub(crate) recompose_reason: Option<RecomposeReason>,
+
+    /// The stable key of the scope, assigned by the user. See [`ScopeKeyed`](crate::keyed::ScopeKeyed).
+    pub(crate) scope_key: Option<String>,
+
+    /// The states that were set with [`SetState`](crate::state::SetState) since the last composition.
+    pub(crate) externally_set_states: Vec<StateId>,
+
+    /// Whether a state error happened in the current composition. See
+    /// [`StateErrorMode::Poison`](crate::state::StateErrorMode::Poison).
+    pub(crate) is_poisoned: bool,
+
+    /// The error boundary that errors of this scope are reported to. It is inherited from the parent scope. See
+    /// [`report_error`](Scope::report_error).
+    pub(crate) error_boundary: Option<ErrorBoundaryId>,
+
+    /// The values that the root provides to its tree. It is inherited from the parent scope. See
+    /// [`use_context`](Scope::use_context).
+    pub(crate) context: Arc<RootContext>,
+
+    /// The values that the scope inherits from its parent. See [`Inherited`].
+    pub(crate) inherited: Inherited,
+
+    /// Whether the composable of the scope disables itself and its descendants. It is set by `Spawn` on every
+    /// composition. See [`disabled`](crate::modify::ModifyFunctions::disabled).
+    pub(crate) is_self_disabled: bool,
+
+    /// The state hooks that were called in the current composition, in order.
+    #[cfg(feature = "debug_assert_hooks")]
+    pub(crate) hook_calls: Vec<HookCall>,
+
+    /// The state hooks that were called in the last composition. It is `None` until the scope was composed once.
+    #[cfg(feature = "debug_assert_hooks")]
+    pub(crate) hook_signature: Option<Vec<HookCall>>,
+}
+
+/// The values that a scope inherits from its parent. They are updated whenever the parent recomposes the scope, and the
+/// scope is recomposed when they change, even if its composable is unchanged.
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub(crate) struct Inherited {
+    /// Whether an ancestor of the scope is disabled. See [`is_disabled`](Scope::is_disabled).
+    pub(crate) is_disabled: bool,
+    /// The layout direction of the root. See [`use_layout_direction`](Scope::use_layout_direction).
+    pub(crate) layout_direction: LayoutDirection,
+}
+
+/// A guard that delays the decomposition of a scope. See [`use_despawn_guard`](Scope::use_despawn_guard).
+pub(crate) struct DespawnGuard {
+    /// The state that tells the composable that it is leaving.
+    is_leaving: StateId,
+    /// The state that resolves the guard once it is `true`.
+    is_done: StateId,
+}
+
+/// A call to a state hook, which is recorded to check that the hooks of a composable are called in the same order on
+/// every composition.
+#[cfg(feature = "debug_assert_hooks")]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) struct HookCall {
+    location: &'static Location<'static>,
+    type_id: TypeId,
+    type_name: &'static str,
+}
+
+#[cfg(feature = "debug_assert_hooks")]
+impl Display for HookCall {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "state of type `{}` at {}", self.type_name, self.location)
+    }
 }
 
 impl Debug for Scope<'_> {
@@ -95,54 +471,171 @@ impl Scope<'_> {
         index: usize,
         parent_entity: Entity,
         mut parent_child_index: ChildIndex,
+        parent: &Scope,
     ) -> Self {
         parent_child_index.push(index);
 
+        let mut path = parent.path.clone();
+        path.push(PathSegment::Index(index));
+
         Self {
             id: ScopeId(unique_id()),
             index,
             child_index: parent_child_index,
+            path,
             entity: None,
             parent_entity,
             will_decompose: false,
+            is_inactive: false,
             composer: composer.clone(),
             state_index: 0,
             states: Vec::new(),
             children: Vec::new(),
+            min_recompose_interval: None,
+            last_recomposed: None,
+            recompose_priority: 0,
+            frame_recompositions: (0, 0),
+            loop_frames: 0,
+            allows_recompose_loop: false,
+            is_loop_throttled: false,
             queued_systems: Vec::new(),
+            unmount_systems: Vec::new(),
+            retained_unmount_systems: Vec::new(),
+            despawn_guards: Vec::new(),
+            is_leaving: false,
+            recompose_reason: None,
+            scope_key: None,
+            externally_set_states: Vec::new(),
+            is_poisoned: false,
+            error_boundary: parent.error_boundary,
+            context: parent.context.clone(),
+            inherited: parent.inherited_by_children(),
+            is_self_disabled: false,
+            #[cfg(feature = "debug_assert_hooks")]
+            hook_calls: Vec::new(),
+            #[cfg(feature = "debug_assert_hooks")]
+            hook_signature: None,
         }
     }
 
-    pub(crate) fn as_root_scope(entity: Entity, composer: Arc<dyn AnyCompose>) -> Self {
+    pub(crate) fn as_root_scope(
+        entity: Entity,
+        composer: Arc<dyn AnyCompose>,
+        context: Arc<RootContext>,
+    ) -> Self {
         Self {
             id: ScopeId(unique_id()),
             index: 0,
             child_index: ChildIndex::new(0),
+            path: StablePath::default(),
             entity: Some(entity),
             parent_entity: entity,
             will_decompose: false,
+            is_inactive: false,
             composer: composer.clone(),
             state_index: 0,
             states: Vec::new(),
             children: Vec::new(),
+            min_recompose_interval: None,
+            last_recomposed: None,
+            recompose_priority: 0,
+            frame_recompositions: (0, 0),
+            loop_frames: 0,
+            allows_recompose_loop: false,
+            is_loop_throttled: false,
             queued_systems: Vec::new(),
+            unmount_systems: Vec::new(),
+            retained_unmount_systems: Vec::new(),
+            despawn_guards: Vec::new(),
+            is_leaving: false,
+            recompose_reason: None,
+            scope_key: None,
+            externally_set_states: Vec::new(),
+            is_poisoned: false,
+            error_boundary: None,
+            context,
+            inherited: Inherited::default(),
+            is_self_disabled: false,
+            #[cfg(feature = "debug_assert_hooks")]
+            hook_calls: Vec::new(),
+            #[cfg(feature = "debug_assert_hooks")]
+            hook_signature: None,
         }
     }
 
     /// Creates a new state. States are persisted between each recomposition of the composable. Each time a state
     /// changes, the scope it belongs to is scheduled for recomposition.
+    #[cfg_attr(feature = "debug_assert_hooks", track_caller)]
     pub fn use_state<T: Any + Send + Sync>(&mut self, initial_value: T) -> State<T> {
+        self.use_state_lazy(|| initial_value)
+    }
+
+    /// Creates a state that is saved by the [`UiSession`](crate::session::UiSession) when the `session` feature is
+    /// enabled. The built-in composables use it for states that are worth restoring, like scroll offsets.
+    #[cfg(feature = "session")]
+    pub(crate) fn use_widget_state<T>(&mut self, initial_value: T) -> State<T>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned + Any + Send + Sync,
+    {
+        self.use_saved_state(initial_value)
+    }
+
+    /// Creates a state that is saved by the `UiSession` when the `session` feature is enabled. Without the feature, it
+    /// is a plain state.
+    #[cfg(not(feature = "session"))]
+    pub(crate) fn use_widget_state<T: Any + Send + Sync>(&mut self, initial_value: T) -> State<T> {
+        self.use_state(initial_value)
+    }
+
+    /// Creates a new state, like [`use_state`](Self::use_state), but the initial value is only created when the state
+    /// is first used. This is useful for initial values that are expensive to create, since they would otherwise be
+    /// created (and dropped) on every recomposition.
+    #[cfg_attr(feature = "debug_assert_hooks", track_caller)]
+    pub fn use_state_lazy<T: Any + Send + Sync>(
+        &mut self,
+        initial_value: impl FnOnce() -> T,
+    ) -> State<T> {
+        #[cfg(feature = "debug_assert_hooks")]
+        self.record_hook::<T>(Location::caller());
+
         if let Some(existing_state) = self.states.get(self.state_index) {
+            let index = self.state_index;
             self.state_index += 1;
-            return existing_state.to_state::<T>();
+
+            match existing_state.try_to_state::<T>() {
+                Ok(state) => return state,
+                Err(error) if !self.context.settings.poison_on_state_error() => panic!("{error}"),
+                Err(error) => {
+                    self.poison(format_args!("{error} (state index {index})"));
+
+                    let value = Arc::new(initial_value());
+
+                    let dyn_state = DynState {
+                        id: StateId::Generated(unique_id()),
+                        changed: StateChanged::Changed,
+                        value: value.clone(),
+                        initial: value,
+                        #[cfg(feature = "session")]
+                        codec: None,
+                    };
+
+                    let state = dyn_state.to_state();
+                    self.states[index] = dyn_state;
+
+                    return state;
+                }
+            }
         }
 
-        let value = Arc::new(initial_value);
+        let value = Arc::new(initial_value());
 
         let dyn_state = DynState {
             id: StateId::Generated(unique_id()),
             changed: StateChanged::Changed,
             value: value.clone(),
+            initial: value,
+            #[cfg(feature = "session")]
+            codec: None,
         };
 
         let state = dyn_state.to_state();
@@ -153,24 +646,116 @@ impl Scope<'_> {
         state
     }
 
+    /// Creates a new state, like [`use_state`](Self::use_state), but returns an error instead of panicking when the
+    /// existing state has a different type. This lets composables that call hooks conditionally handle the mistake
+    /// themselves, regardless of the [`StateErrorMode`](crate::state::StateErrorMode).
+    #[cfg_attr(feature = "debug_assert_hooks", track_caller)]
+    pub fn try_use_state<T: Any + Send + Sync>(
+        &mut self,
+        initial_value: T,
+    ) -> Result<State<T>, StateError> {
+        if self.state_index < self.states.len() {
+            #[cfg(feature = "debug_assert_hooks")]
+            self.record_hook::<T>(Location::caller());
+
+            let existing_state = &self.states[self.state_index];
+            self.state_index += 1;
+            return existing_state.try_to_state::<T>();
+        }
+
+        Ok(self.use_state(initial_value))
+    }
+
+    /// Creates a new state, like [`use_state`](Self::use_state), with the default value of the type as the initial
+    /// value.
+    #[cfg_attr(feature = "debug_assert_hooks", track_caller)]
+    pub fn use_state_default<T: Default + Any + Send + Sync>(&mut self) -> State<T> {
+        self.use_state_lazy(T::default)
+    }
+
+    /// Creates a new state that is initialized from the given prop, and reset to the prop whenever the prop changes.
+    /// In between, the state can be changed like any other state, for example to hold the edited value of an input
+    /// that is seeded by its parent. The returned state is marked as changed when it was reset to a new prop.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let volume = cx.use_state_from(&self.initial_volume);
+    /// let volume_id = volume.get_typed_id();
+    ///
+    /// Slider::new(*volume, 0.0..=1.0).on_change(move |value, state| state.set(volume_id, value))
+    /// ```
+    #[cfg_attr(feature = "debug_assert_hooks", track_caller)]
+    pub fn use_state_from<T: PartialEq + Clone + Any + Send + Sync>(
+        &mut self,
+        prop: &T,
+    ) -> State<T> {
+        let state = self.use_state_lazy(|| prop.clone());
+        let last_prop = self.use_state_lazy(|| prop.clone());
+
+        if *last_prop == *prop {
+            return state;
+        }
+
+        self.set_state_unchanged(&last_prop, prop.clone());
+
+        if *state == *prop {
+            return state;
+        }
+
+        self.set_state_unchanged(&state, prop.clone());
+
+        State {
+            changed: StateChanged::Changed,
+            ..self.pending_value(&state).unwrap_or(state)
+        }
+    }
+
     /// Creates a new state with a given id. It is useful for cases where you want to reference a state in an external
     /// system or a different composable.
+    ///
+    /// Manual ids have to be unique, since states with the same id overwrite each other. In debug builds, a warning is
+    /// logged when an id is used by more than one composable. Ids created with
+    /// [`typed_state_id!`](crate::typed_state_id) are unique by construction.
+    #[cfg_attr(feature = "debug_assert_hooks", track_caller)]
     pub fn use_state_with_id<T: Any + Send + Sync>(
         &mut self,
         state_id: TypedStateId<T>,
         initial_value: T,
     ) -> State<T> {
-        if let Some(existing_state) = self.states.iter().find(|s| s.id == state_id.get_id()) {
-            self.state_index += 1;
-            return existing_state.to_state::<T>();
+        #[cfg(feature = "debug_assert_hooks")]
+        self.record_hook::<T>(Location::caller());
+
+        let existing_index = self.states.iter().position(|s| s.id == state_id.get_id());
+
+        #[cfg(debug_assertions)]
+        self.track_manual_state_id::<T>(state_id.get_id(), existing_index.is_none());
+
+        if let Some(index) = existing_index {
+            match self.states[index].try_to_state::<T>() {
+                Ok(state) => {
+                    self.state_index += 1;
+                    return state;
+                }
+                Err(error) if !self.context.settings.poison_on_state_error() => panic!("{error}"),
+                Err(error) => {
+                    self.poison(format_args!("{error} (state {:?})", state_id.get_id()));
+                    self.states.remove(index);
+                }
+            }
         }
 
-        let value = Arc::new(initial_value);
+        let value = self
+            .context
+            .preset_state::<T>(state_id.get_id())
+            .unwrap_or_else(|| Arc::new(initial_value));
 
         let dyn_state = DynState {
             id: state_id.get_id(),
             changed: StateChanged::Changed,
             value: value.clone(),
+            initial: value,
+            #[cfg(feature = "session")]
+            codec: None,
         };
 
         let state = dyn_state.to_state();
@@ -181,46 +766,289 @@ impl Scope<'_> {
         state
     }
 
+    /// Registers the scope as a user of the manual state id, which warns when the id is already used by another scope.
+    #[cfg(debug_assertions)]
+    fn track_manual_state_id<T: Any>(&mut self, state_id: StateId, is_new: bool) {
+        let StateId::Manual(id) = state_id else {
+            return;
+        };
+
+        let scope = self.id;
+
+        if is_new {
+            let composable = self.composer.get_name();
+            let type_name = std::any::type_name::<T>();
+
+            self.run_system(
+                move |mut manual_ids: ResMut<crate::state::ManualStateIds>| {
+                    manual_ids.register(id, scope, composable.clone(), type_name);
+                },
+            );
+        }
+
+        self.use_unmount_system_once(
+            move |mut manual_ids: ResMut<crate::state::ManualStateIds>| {
+                manual_ids.unregister(id, scope);
+            },
+        );
+    }
+
     /// Sets the value of the given state. The change happens immediately.
     pub fn set_state<T: Send + Sync + 'static>(&mut self, state: impl GetStateId<T>, value: T) {
-        let state = self
-            .states
-            .iter_mut()
-            .find(|s| s.id == state.get_id())
-            .unwrap_or_else(|| panic!("State not found."));
+        let state_id = state.get_id();
+        let Some(state) = self.find_state_mut(state_id) else {
+            self.state_error(state_id, StateError::NotFound);
+            return;
+        };
 
         if !state.value.is::<T>() {
-            panic!("State value type mismatch.");
+            self.state_error(state_id, StateError::TypeMismatch);
+            return;
         }
 
         state.value = Arc::new(value);
         state.changed = StateChanged::Queued;
     }
 
+    /// Resets the given state to its initial value, the value it was created with. Like
+    /// [`set_state`](Self::set_state), the change happens immediately. This is useful for resetting forms and toggles
+    /// without keeping a copy of their initial values around.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let name = cx.use_state(String::from("Untitled"));
+    ///
+    /// if *submitted {
+    ///     cx.reset_state(&name);
+    /// }
+    /// ```
+    pub fn reset_state<T: Send + Sync + 'static>(&mut self, state: impl GetStateId<T>) {
+        let state_id = state.get_id();
+        let Some(state) = self.find_state_mut(state_id) else {
+            self.state_error(state_id, StateError::NotFound);
+            return;
+        };
+
+        if !state.initial.is::<T>() {
+            self.state_error(state_id, StateError::TypeMismatch);
+            return;
+        }
+
+        state.value = state.initial.clone();
+        state.changed = StateChanged::Queued;
+    }
+
+    /// Flips the value of a boolean state. Like [`set_state`](Self::set_state), the change happens immediately.
+    pub fn toggle_state(&mut self, state: impl GetStateId<bool>) {
+        self.modify_state(state, |value| !value);
+    }
+
+    /// Adds the given delta to the value of a numeric state. Like [`set_state`](Self::set_state), the change happens
+    /// immediately.
+    pub fn increment_state<T: Add<Output = T> + Copy + Send + Sync + 'static>(
+        &mut self,
+        state: impl GetStateId<T>,
+        delta: T,
+    ) {
+        self.modify_state(state, |value| *value + delta);
+    }
+
+    /// Appends an item to the end of a `Vec` state. Like [`set_state`](Self::set_state), the change happens
+    /// immediately.
+    pub fn push_state<T: Clone + Send + Sync + 'static>(
+        &mut self,
+        state: impl GetStateId<Vec<T>>,
+        item: T,
+    ) {
+        self.modify_state(state, |items| {
+            let mut items = items.clone();
+            items.push(item);
+            items
+        });
+    }
+
+    /// Removes all items of a `Vec` state that match the given predicate. Like [`set_state`](Self::set_state), the
+    /// change happens immediately.
+    pub fn remove_state_where<T: Clone + Send + Sync + 'static>(
+        &mut self,
+        state: impl GetStateId<Vec<T>>,
+        predicate: impl Fn(&T) -> bool,
+    ) {
+        self.modify_state(state, |items| {
+            let mut items = items.clone();
+            items.retain(|item| !predicate(item));
+            items
+        });
+    }
+
+    /// Sets the state to a value computed from its latest value.
+    fn modify_state<T: Send + Sync + 'static>(
+        &mut self,
+        state: impl GetStateId<T>,
+        modify: impl FnOnce(&T) -> T,
+    ) {
+        let state_id = state.get_id();
+        let Some(state) = self.find_state(state_id) else {
+            self.state_error(state_id, StateError::NotFound);
+            return;
+        };
+
+        let Some(value) = state.value.downcast_ref::<T>() else {
+            self.state_error(state_id, StateError::TypeMismatch);
+            return;
+        };
+
+        let value = modify(value);
+        self.set_state(TypedStateId::from_state_id(state_id), value);
+    }
+
+    /// Returns the latest value of the given state. Since [`set_state`](Self::set_state) changes the value immediately,
+    /// the `State` returned by `use_state` may be outdated within the same `compose` call. This returns the value as it
+    /// currently is, which is the value the next recomposition will see.
+    ///
+    /// Returns `None` if the state doesn't exist or has a different type, which is handled according to the
+    /// [`StateErrorMode`](crate::state::StateErrorMode) like the errors of the other state functions.
+    pub fn pending_value<T: Any + Send + Sync>(
+        &mut self,
+        state: impl GetStateId<T>,
+    ) -> Option<State<T>> {
+        let state_id = state.get_id();
+
+        let result = match self.find_state(state_id) {
+            Some(state) => state.try_to_state(),
+            None => Err(StateError::NotFound),
+        };
+
+        result
+            .map_err(|error| self.state_error(state_id, error))
+            .ok()
+    }
+
     /// Sets the value of the given state without triggering a recomposition. The change happens immediately.
     pub fn set_state_unchanged<T: Send + Sync + 'static>(
         &mut self,
         state: impl GetStateId<T>,
         value: T,
     ) {
-        let state = self
-            .states
-            .iter_mut()
-            .find(|s| s.id == state.get_id())
-            .unwrap_or_else(|| panic!("State not found."));
+        let state_id = state.get_id();
+        let Some(state) = self.find_state_mut(state_id) else {
+            self.state_error(state_id, StateError::NotFound);
+            return;
+        };
 
         if !state.value.is::<T>() {
-            panic!("State value type mismatch.");
+            self.state_error(state_id, StateError::TypeMismatch);
+            return;
         }
 
         state.value = Arc::new(value);
     }
 
+    /// Handles a state that was set with the wrong type or that doesn't exist, according to the [`StateErrorMode`].
+    fn state_error(&mut self, state_id: StateId, error: StateError) {
+        if !self.context.settings.poison_on_state_error() {
+            panic!("{error}");
+        }
+
+        self.poison(format_args!("{error} (state {state_id:?})"));
+    }
+
+    /// Poisons the scope, which makes it compose nothing for the current composition. See [`StateErrorMode::Poison`].
+    fn poison(&mut self, message: std::fmt::Arguments) {
+        warn!("{}: {message}", self.composer.get_name());
+        self.is_poisoned = true;
+    }
+
+    /// Records a call to a state hook, and reports it when it differs from the hook at the same position in the last
+    /// composition, according to the [`StateErrorMode`].
+    #[cfg(feature = "debug_assert_hooks")]
+    fn record_hook<T: Any>(&mut self, location: &'static Location<'static>) {
+        let call = HookCall {
+            location,
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+        };
+
+        let position = self.hook_calls.len();
+        self.hook_calls.push(call);
+
+        let Some(expected) = self
+            .hook_signature
+            .as_ref()
+            .map(|signature| signature.get(position).copied())
+        else {
+            return;
+        };
+
+        match expected {
+            Some(expected) if expected == call => {}
+            Some(expected) => self.hook_order_mismatch(format_args!(
+                "hook {position} changed between compositions: expected {expected}, found {call}"
+            )),
+            None => self.hook_order_mismatch(format_args!(
+                "hook {position} was not called in the last composition: found {call}"
+            )),
+        }
+    }
+
+    /// Checks that all hooks of the last composition were called again, and keeps the hooks of the current composition
+    /// to compare the next composition against.
+    #[cfg(feature = "debug_assert_hooks")]
+    pub(crate) fn finish_hook_signature(&mut self) {
+        let calls = std::mem::take(&mut self.hook_calls);
+
+        if let Some(expected) = self
+            .hook_signature
+            .as_ref()
+            .and_then(|signature| signature.get(calls.len()).copied())
+        {
+            self.hook_order_mismatch(format_args!(
+                "hook {} was not called in this composition: expected {expected}",
+                calls.len()
+            ));
+        }
+
+        self.hook_signature = Some(calls);
+    }
+
+    /// Reports the first hook of a composition that was called out of order. Hooks must be called in the same order on
+    /// every composition, since their states are looked up by their position.
+    #[cfg(feature = "debug_assert_hooks")]
+    fn hook_order_mismatch(&mut self, message: std::fmt::Arguments) {
+        if self.is_poisoned {
+            return;
+        }
+
+        if !self.context.settings.poison_on_state_error() {
+            panic!("{}: {message}", self.composer.get_name());
+        }
+
+        self.poison(message);
+    }
+
+    /// Finds the state with the given id. Indexed state ids are only resolved if they refer to this scope.
+    pub(crate) fn find_state(&self, id: StateId) -> Option<&DynState> {
+        match id {
+            StateId::Indexed(scope_id, index) if scope_id == self.id => self.states.get(index),
+            StateId::Indexed(..) => None,
+            _ => self.states.iter().find(|s| s.id == id),
+        }
+    }
+
+    /// Finds the state with the given id. Indexed state ids are only resolved if they refer to this scope.
+    pub(crate) fn find_state_mut(&mut self, id: StateId) -> Option<&mut DynState> {
+        match id {
+            StateId::Indexed(scope_id, index) if scope_id == self.id => self.states.get_mut(index),
+            StateId::Indexed(..) => None,
+            _ => self.states.iter_mut().find(|s| s.id == id),
+        }
+    }
+
     pub(crate) fn get_state_by_index<T: Any + Send + Sync>(&self, index: usize) -> State<T> {
         let dyn_state = self
             .states
             .get(index)
-            .unwrap_or_else(|| panic!("State not found."));
+            .unwrap_or_else(|| panic!("{}", StateError::NotFound));
 
         dyn_state.to_state()
     }
@@ -242,11 +1070,214 @@ impl Scope<'_> {
 
     /// Runs a system. The system is not cached and is "rebuilt" every time the composable recomposes. It is therefore
     /// not the most efficient way to to interact with the ECS world.
+    ///
+    /// The queued systems of all scopes are run together, before the states are set, in the order of the scopes. See
+    /// [`batch_queued_systems`](crate::settings::RecomposeSettings::batch_queued_systems) for applying their `Commands`
+    /// together.
     pub fn run_system<M>(&mut self, system: impl IntoSystem<(), (), M>) {
         let sys: BoxedSystem<(), ()> = Box::from(IntoSystem::into_system(system));
         self.queued_systems.push(sys);
     }
 
+    /// Runs a system only if the dependencies have changed. This is the same as wrapping [`run_system`](Self::run_system)
+    /// in an [`effect`](Self::effect), but lets the system be passed directly.
+    pub fn use_effect_system<M>(
+        &mut self,
+        system: impl IntoSystem<(), (), M>,
+        dependecies: impl Dependency,
+    ) {
+        if !dependecies.has_changed() {
+            return;
+        }
+
+        self.run_system(system);
+    }
+
+    /// Runs a system that computes a value from the world, and caches the value in a state. The system runs when the
+    /// composable is first composed and whenever the dependencies change, which makes this the way to derive expensive
+    /// values from the world inside `compose`, like the preview of a path or the totals of an inventory.
+    ///
+    /// Systems run between compositions, so the state is `None` until the system has run for the first time, and holds
+    /// the previous value until the system has run again after the dependencies change.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let path = cx.use_memo_system(
+    ///     move |waypoints: Query<&Waypoint>| compute_path(&waypoints, start, goal),
+    ///     (&start, &goal),
+    /// );
+    /// ```
+    pub fn use_memo_system<O: Send + Sync + 'static, M>(
+        &mut self,
+        system: impl IntoSystem<(), O, M>,
+        dependecies: impl Dependency,
+    ) -> State<Option<O>> {
+        let once = self.use_state(());
+        let memo = self.use_state(None);
+
+        if !matches!(once.changed, StateChanged::Changed) && !dependecies.has_changed() {
+            return memo;
+        }
+
+        let memo_id: TypedStateId<Option<O>> = memo.get_typed_id();
+
+        self.run_system(system.pipe(move |In(value): In<O>, mut state: SetState| {
+            state.set(memo_id, Some(value));
+        }));
+
+        memo
+    }
+
+    /// Derives a value from the given source states, and caches it in a state. The value is only recomputed when one of
+    /// the sources has changed, and the returned state is only marked as changed when the recomputed value differs from
+    /// the cached one. Passing the derived state to [`effect`](Self::effect) or to a child that skips unchanged props
+    /// therefore only reruns them when the derived value actually changes.
+    ///
+    /// The sources can be states of this scope or states that were passed down from ancestor scopes.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let items = cx.use_state(vec![]);
+    /// let filter = cx.use_state(String::new());
+    ///
+    /// let visible_count = cx.derive((&items, &filter), |items, filter| {
+    ///     items.iter().filter(|item| item.name.contains(filter.as_str())).count()
+    /// });
+    /// ```
+    pub fn derive<O: PartialEq + Send + Sync + 'static, F>(
+        &mut self,
+        sources: impl DeriveSources<F, O>,
+        derive: F,
+    ) -> State<O> {
+        let derived = self.use_state_lazy(|| sources.derive(&derive));
+
+        // A changed derived state was just created from the current sources, so there is nothing to recompute.
+        if matches!(derived.changed, StateChanged::Changed) || !sources.has_changed() {
+            return derived;
+        }
+
+        let value = sources.derive(&derive);
+
+        if value == *derived {
+            return derived;
+        }
+
+        self.set_state_unchanged(&derived, value);
+
+        State {
+            changed: StateChanged::Changed,
+            ..self.pending_value(&derived).unwrap_or(derived)
+        }
+    }
+
+    /// Runs a system when the composable is decomposed. This is useful for cleaning up things that were set up by the
+    /// composable, like entities or subscriptions.
+    pub fn use_unmount_system<M>(&mut self, system: impl IntoSystem<(), (), M>) {
+        let sys: BoxedSystem<(), ()> = Box::from(IntoSystem::into_system(system));
+        self.unmount_systems.push(sys);
+    }
+
+    /// Like [`use_unmount_system`](Self::use_unmount_system), but the system is only created when the composable is
+    /// first composed, rather than on every composition. Values that change between compositions have to be read by the
+    /// system instead of being captured.
+    pub(crate) fn use_unmount_system_once<M>(&mut self, system: impl IntoSystem<(), (), M>) {
+        if self.recompose_reason != Some(RecomposeReason::Mount) {
+            return;
+        }
+
+        let sys: BoxedSystem<(), ()> = Box::from(IntoSystem::into_system(system));
+        self.retained_unmount_systems.push(sys);
+    }
+
+    /// Delays the decomposition of the composable until the given state is `true`, which enables exit animations and
+    /// cleanups that take a while, like saving edits. Returns a state that tells whether the composable is leaving: once
+    /// it should be decomposed, the state is set to `true`, and the composable stays in place with its entities until
+    /// the given state is set to `true`.
+    /// Ancestors that are decomposed together with the composable wait for it as well, while their other descendants are
+    /// decomposed right away.
+    ///
+    /// The state has to belong to this composable. Guards only delay the decomposition while the [`Root`] exists.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let is_saved = cx.use_state(false);
+    /// let is_leaving = cx.use_despawn_guard(&is_saved);
+    /// let is_saved_id = is_saved.get_typed_id();
+    ///
+    /// if *is_leaving {
+    ///     cx.use_effect_system(
+    ///         move |mut state: SetState| {
+    ///             save_document();
+    ///             state.set(is_saved_id, true);
+    ///         },
+    ///         &is_leaving,
+    ///     );
+    /// }
+    /// ```
+    pub fn use_despawn_guard(&mut self, is_done: impl GetStateId<bool>) -> State<bool> {
+        let is_leaving = self.use_state(false);
+
+        self.despawn_guards.push(DespawnGuard {
+            is_leaving: is_leaving.get_id(),
+            is_done: is_done.get_id(),
+        });
+
+        is_leaving
+    }
+
+    /// Whether one of the despawn guards of the scope hasn't resolved yet. Guards whose state doesn't exist anymore are
+    /// resolved.
+    pub(crate) fn has_pending_despawn_guard(&self) -> bool {
+        self.despawn_guards.iter().any(|guard| {
+            self.find_state(guard.is_done)
+                .and_then(|state| state.value.downcast_ref::<bool>())
+                .is_some_and(|is_done| !is_done)
+        })
+    }
+
+    /// Whether the scope or one of its descendants has a despawn guard that hasn't resolved yet.
+    pub(crate) fn has_pending_despawn_guard_in_tree(&self) -> bool {
+        let mut scopes = vec![self];
+
+        while let Some(scope) = scopes.pop() {
+            if scope.has_pending_despawn_guard() {
+                return true;
+            }
+
+            scopes.extend(scope.children.iter());
+        }
+
+        false
+    }
+
+    /// Keeps the scope in place until its despawn guards resolve, and recomposes it to let it know that it is leaving.
+    pub(crate) fn leave(&mut self) {
+        if self.is_leaving {
+            return;
+        }
+
+        self.is_leaving = true;
+
+        for guard in self.despawn_guards.iter() {
+            let Some(state) = self
+                .states
+                .iter_mut()
+                .find(|state| state.id == guard.is_leaving)
+            else {
+                continue;
+            };
+
+            state.value = Arc::new(true);
+            state.changed = StateChanged::Queued;
+        }
+    }
+
+    /// Whether the scope can be recomposed. Scopes that should be decomposed are only recomposed while they wait for
+    /// their own despawn guards.
+    pub(crate) fn can_recompose(&self) -> bool {
+        !self.will_decompose || (self.is_leaving && !self.despawn_guards.is_empty())
+    }
+
     /// Runs a system when the composable is first composed.
     pub fn use_system_once<M>(&mut self, system: impl IntoSystem<(), (), M>) {
         let once = self.use_state(());
@@ -256,7 +1287,238 @@ impl Scope<'_> {
         }
     }
 
+    /// Triggers an event whenever the given state changes, which lets game logic react to the UI without polling it.
+    /// The event is created from the new value of the state, and is triggered for global observers, like those added
+    /// with `app.add_observer`. When the event was also added to the app with `add_event`, it is sent to `EventReader`s
+    /// as well. No event is emitted when the composable is first composed.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let volume = cx.use_state(0.8);
+    ///
+    /// cx.emit_on_change(&volume, |volume| VolumeChanged(*volume));
+    /// ```
+    pub fn emit_on_change<T, E: Event + Clone>(
+        &mut self,
+        state: &State<T>,
+        event: impl FnOnce(&T) -> E,
+    ) {
+        let once = self.use_state(());
+
+        if matches!(once.changed, StateChanged::Changed) || !state.has_changed() {
+            return;
+        }
+
+        let event = event(state);
+
+        self.run_system(
+            move |mut commands: Commands, events: Option<ResMut<Events<E>>>| {
+                if let Some(mut events) = events {
+                    events.send(event.clone());
+                }
+
+                commands.trigger(event.clone());
+            },
+        );
+    }
+
+    /// Limits how often the scope can be recomposed because of changes to its own states. State changes that happen in
+    /// between are coalesced and applied on the next allowed recomposition. This is useful for composables driven by
+    /// noisy state sources, such as the cursor position. Recompositions caused by the parent scope are not limited.
+    ///
+    /// Rates that aren't positive, or aren't a number, don't limit the recompositions.
+    pub fn recompose_at_most(&mut self, hz: f32) {
+        self.min_recompose_interval = Duration::try_from_secs_f32(1.0 / hz)
+            .ok()
+            .filter(|_| hz > 0.0);
+    }
+
+    /// Sets the priority of the scope for roots with a [recompose budget](crate::Root::recompose_budget). When the root
+    /// can't recompose all changed scopes within its budget, scopes with a higher priority are recomposed first, and the
+    /// rest is deferred to the next frames. Scopes with the same priority are recomposed in the order of the tree. The
+    /// priority defaults to `0`.
+    pub fn recompose_priority(&mut self, priority: i32) {
+        self.recompose_priority = priority;
+    }
+
+    /// Marks the composable as one that recomposes itself in every frame on purpose, like a composable that advances an
+    /// animation by setting its own state while composing. Such composables are not reported or throttled by the
+    /// [`LoopDetection`](crate::settings::LoopDetection). The hook has to be called on every composition.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let angle = cx.use_state(0.0_f32);
+    ///
+    /// cx.allow_recompose_loop();
+    /// cx.set_state(&angle, (*angle + 0.01) % TAU);
+    /// ```
+    pub fn allow_recompose_loop(&mut self) {
+        self.allows_recompose_loop = true;
+    }
+
+    /// Whether the recomposition of the scope should be postponed, given the current time.
+    pub(crate) fn is_throttled(&self, now: Option<Duration>) -> bool {
+        let interval = match self.is_loop_throttled {
+            true => self
+                .min_recompose_interval
+                .max(Some(self.context.settings.loop_throttle_interval())),
+            false => self.min_recompose_interval,
+        };
+
+        let (Some(interval), Some(last), Some(now)) = (interval, self.last_recomposed, now) else {
+            return false;
+        };
+
+        now.saturating_sub(last) < interval
+    }
+
+    /// Whether the scope was recomposed as often as allowed in the current frame. See
+    /// [`RecomposeSettings::max_recompositions_per_frame`](crate::settings::RecomposeSettings::max_recompositions_per_frame).
+    pub(crate) fn has_reached_recompose_limit(&self) -> bool {
+        let (frame, recompositions) = self.frame_recompositions;
+        let settings = &self.context.settings;
+        frame == settings.frame() && recompositions >= settings.max_recompositions_per_frame()
+    }
+
+    /// Counts the recomposition towards the limit of the current frame, and towards the consecutive frames in which the
+    /// scope recomposed itself. Must be called after the recompose reason was updated.
+    pub(crate) fn track_recomposition(&mut self) {
+        let frame = self.context.settings.frame();
+        let (last_frame, recompositions) = self.frame_recompositions;
+
+        self.frame_recompositions = match last_frame == frame {
+            true => (frame, recompositions + 1),
+            false => (frame, 1),
+        };
+
+        let is_recomposing_itself = matches!(
+            self.recompose_reason,
+            Some(RecomposeReason::StateChanged(_))
+        );
+
+        self.loop_frames = match is_recomposing_itself {
+            false => 0,
+            true if last_frame == frame => self.loop_frames,
+            true if last_frame + 1 == frame => self.loop_frames + 1,
+            true => 1,
+        };
+    }
+
+    /// Reports and throttles the scope once it recomposed itself in enough consecutive frames. See
+    /// [`LoopDetection`](crate::settings::LoopDetection).
+    pub(crate) fn detect_recompose_loop(&mut self) {
+        if self.allows_recompose_loop {
+            self.is_loop_throttled = false;
+            return;
+        }
+
+        let Some(loop_frames) = self.context.settings.loop_frames() else {
+            self.is_loop_throttled = false;
+            return;
+        };
+
+        if self.is_loop_throttled || self.loop_frames < loop_frames {
+            return;
+        }
+
+        self.is_loop_throttled = true;
+
+        warn!(
+            "`{}` recomposed itself in {} consecutive frames, because it sets its own states while composing. It is \
+            throttled from now on. Call `allow_recompose_loop` if this is intended.",
+            self.composer.get_name(),
+            self.loop_frames
+        );
+    }
+
+    /// Returns a handle to this scope, which can be captured by observers and systems to refer to the states of this
+    /// scope by their index.
+    pub fn handle(&self) -> ScopeHandle {
+        ScopeHandle { id: self.id }
+    }
+
+    /// Returns the reason why the composable is being composed. This is useful for skipping expensive work that only
+    /// depends on the own states of the composable, and for debugging unexpected recompositions.
+    ///
+    /// # Example
+    /// ```ignore
+    /// fn chart(cx: &mut Scope) -> impl Compose {
+    ///     let points = cx.use_state(Vec::new());
+    ///
+    ///     if *cx.recompose_reason() == RecomposeReason::ParentRecomposed {
+    ///         info!("Chart recomposed because of its parent.");
+    ///     }
+    ///
+    ///     Chart::new(points)
+    /// }
+    /// ```
+    pub fn recompose_reason(&self) -> &RecomposeReason {
+        self.recompose_reason
+            .as_ref()
+            .unwrap_or(&RecomposeReason::Mount)
+    }
+
+    /// Determines the reason of the upcoming composition from the queued states. Called before the queued states are
+    /// marked as changed.
+    pub(crate) fn update_recompose_reason(&mut self) {
+        let changed_states = self
+            .states
+            .iter()
+            .filter(|state| matches!(state.changed, StateChanged::Queued))
+            .map(|state| state.id)
+            .collect::<Vec<_>>();
+
+        let is_set_externally = changed_states
+            .iter()
+            .any(|id| self.externally_set_states.contains(id));
+
+        self.recompose_reason = Some(match self.recompose_reason {
+            None => RecomposeReason::Mount,
+            Some(_) if changed_states.is_empty() => RecomposeReason::ParentRecomposed,
+            Some(_) if is_set_externally => RecomposeReason::SetState(changed_states),
+            Some(_) => RecomposeReason::StateChanged(changed_states),
+        });
+
+        self.externally_set_states.clear();
+    }
+
+    /// Returns the stable path of this scope. The path is made up of the indices of the scope and its ancestors, and the
+    /// hashed keys of keyed composables, which makes it suitable for identifying scopes across runs.
+    pub fn stable_path(&self) -> &StablePath {
+        &self.path
+    }
+
+    /// Records the hashed key of the composable in the scope, so that it is addressed by its key rather than its index.
+    pub(crate) fn set_key(&mut self, key: u64) {
+        self.path.set_last(PathSegment::Key(key));
+    }
+
+    /// Returns the stable key of this scope, if it has one. See [`ScopeKeyed`](crate::keyed::ScopeKeyed).
+    pub fn scope_key(&self) -> Option<&str> {
+        self.scope_key.as_deref()
+    }
+
+    pub(crate) fn set_scope_key(&mut self, key: Option<&str>) {
+        if self.scope_key.as_deref() != key {
+            self.scope_key = key.map(String::from);
+        }
+    }
+
     pub(crate) fn set_entity(&mut self, entity: Entity) {
         self.entity = Some(entity);
     }
+
+    /// Whether the composable is disabled, because it or one of its ancestors was disabled with
+    /// [`disabled`](crate::modify::ModifyFunctions::disabled). The composable is recomposed when this changes.
+    pub fn is_disabled(&self) -> bool {
+        self.inherited.is_disabled || self.is_self_disabled
+    }
+
+    /// Returns the values that the children of the scope inherit.
+    pub(crate) fn inherited_by_children(&self) -> Inherited {
+        Inherited {
+            is_disabled: self.is_disabled(),
+            ..self.inherited
+        }
+    }
 }
@@ -0,0 +1,480 @@
+use crate::{
+    dyn_compose::DynCompose,
+    layout_direction::LogicalRect,
+    modify::{Modifier, Modify, ModifyFunctions},
+    state::{ReadState, SetState, TypedStateId},
+    Compose, Scope,
+};
+use bevy_color::{Color, Srgba};
+use bevy_ecs::{
+    component::Component,
+    event::{Event, EventCursor, Events},
+    observer::Trigger,
+    query::Changed,
+    system::{Commands, Local, Query, Res},
+};
+use bevy_hierarchy::{Children, Parent};
+use bevy_input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy_math::Vec2;
+use bevy_picking::{
+    events::{Drag, Pointer},
+    focus::HoverMap,
+    pointer::PointerId,
+};
+use bevy_ui::{
+    AlignItems, BackgroundColor, BorderRadius, ComputedNode, FlexDirection, Node, Overflow,
+    PositionType, ScrollPosition, Val,
+};
+
+/// The number of logical pixels that are scrolled per line when the mouse wheel reports its movement in lines.
+const LINE_HEIGHT: f32 = 20.0;
+
+/// The axes along which a [`ScrollView`] can be scrolled.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ScrollAxis {
+    #[default]
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+impl ScrollAxis {
+    fn scrolls_x(self) -> bool {
+        matches!(self, ScrollAxis::Horizontal | ScrollAxis::Both)
+    }
+
+    fn scrolls_y(self) -> bool {
+        matches!(self, ScrollAxis::Vertical | ScrollAxis::Both)
+    }
+}
+
+/// A mouse wheel movement over an entity. It is triggered on the hovered entity and bubbles up the hierarchy until it
+/// reaches a [`ScrollView`] that can be scrolled in the direction of the movement.
+#[derive(Component, Clone, Debug)]
+pub struct ScrollWheel {
+    /// The distance to scroll, in logical pixels. Positive values scroll the content to the right and down.
+    pub delta: Vec2,
+}
+
+impl Event for ScrollWheel {
+    type Traversal = &'static Parent;
+
+    const AUTO_PROPAGATE: bool = true;
+}
+
+/// The size of the viewport and the content of a [`ScrollView`], in logical pixels.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub(crate) struct ScrollMetrics {
+    viewport: Vec2,
+    content: Vec2,
+}
+
+impl ScrollMetrics {
+    fn max_offset(&self) -> Vec2 {
+        (self.content - self.viewport).max(Vec2::ZERO)
+    }
+}
+
+/// Marks the viewport of a [`ScrollView`]. The metrics are kept up to date by [`update_scroll_metrics`], so that the
+/// retained observers of the viewport can clamp the scroll offset without being recreated.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct ScrollViewport {
+    offset: TypedStateId<[f32; 2]>,
+    metrics_id: TypedStateId<ScrollMetrics>,
+    metrics: ScrollMetrics,
+    axis: ScrollAxis,
+}
+
+impl ScrollViewport {
+    /// Scrolls the viewport by the given distance. Returns whether the viewport can be scrolled in the direction of the
+    /// given distance.
+    fn scroll_by(&self, state: &mut SetState, delta: Vec2) -> bool {
+        let delta = match self.axis {
+            // Vertical mouse wheels are the most common, so they also scroll views that only scroll horizontally.
+            ScrollAxis::Horizontal if delta.x == 0.0 => Vec2::new(delta.y, 0.0),
+            ScrollAxis::Horizontal => Vec2::new(delta.x, 0.0),
+            ScrollAxis::Vertical => Vec2::new(0.0, delta.y),
+            ScrollAxis::Both => delta,
+        };
+
+        let max_offset = self.metrics.max_offset();
+        let can_scroll =
+            (delta.x != 0.0 && max_offset.x > 0.0) || (delta.y != 0.0 && max_offset.y > 0.0);
+
+        if !can_scroll {
+            return false;
+        }
+
+        // Several wheel or drag events can arrive in the same frame, so each delta builds on the ones before it.
+        state.modify_after_queued(self.offset, move |offset| {
+            (Vec2::from_array(*offset) + delta)
+                .clamp(Vec2::ZERO, max_offset)
+                .to_array()
+        });
+
+        true
+    }
+}
+
+/// A composable that clips its content and lets the user scroll it with the mouse wheel or by dragging. The scroll
+/// offset is kept in a state, which is saved by the `UiSession` of the `session` feature. Scrollbars are shown
+/// along the scrollable axes whenever the content is larger than the viewport.
+///
+/// The scroll view fills its parent, so the size of the viewport is determined by the parent node.
+///
+/// # Example
+/// ```ignore
+/// Node {
+///     height: Val::Px(300.0),
+///     ..Default::default()
+/// }
+/// .children(ScrollView::new(items))
+/// ```
+#[derive(Clone)]
+pub struct ScrollView {
+    content: DynCompose,
+    axis: ScrollAxis,
+    show_scrollbars: bool,
+    scrollbar_size: f32,
+    scrollbar_color: Color,
+    modifier: Modifier,
+}
+
+impl ScrollView {
+    /// Creates a new vertical scroll view with the given content.
+    pub fn new(content: impl Compose + 'static) -> Self {
+        Self {
+            content: DynCompose::new(content),
+            axis: ScrollAxis::Vertical,
+            show_scrollbars: true,
+            scrollbar_size: 6.0,
+            scrollbar_color: Srgba::rgba_u8(255, 255, 255, 80).into(),
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Sets the axes along which the content can be scrolled.
+    pub fn axis(mut self, axis: ScrollAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Sets whether scrollbars are shown.
+    pub fn show_scrollbars(mut self, show_scrollbars: bool) -> Self {
+        self.show_scrollbars = show_scrollbars;
+        self
+    }
+
+    /// Sets the thickness of the scrollbars, in logical pixels.
+    pub fn scrollbar_size(mut self, size: f32) -> Self {
+        self.scrollbar_size = size;
+        self
+    }
+
+    /// Sets the color of the scrollbar thumbs.
+    pub fn scrollbar_color(mut self, color: impl Into<Color>) -> Self {
+        self.scrollbar_color = color.into();
+        self
+    }
+}
+
+impl Modify for ScrollView {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for ScrollView {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let offset = cx.use_widget_state([0.0_f32; 2]);
+        let metrics = cx.use_state(ScrollMetrics::default());
+        let layout_direction = cx.use_layout_direction();
+
+        let axis = self.axis;
+        let max_offset = metrics.max_offset();
+        let clamped_offset = Vec2::from_array(*offset).clamp(Vec2::ZERO, max_offset);
+
+        let viewport = ScrollViewport {
+            offset: offset.get_typed_id(),
+            metrics_id: metrics.get_typed_id(),
+            metrics: *metrics,
+            axis,
+        };
+
+        let content = Node {
+            flex_direction: FlexDirection::Column,
+            flex_shrink: 0.0,
+            min_width: Val::Percent(100.0),
+            min_height: Val::Percent(100.0),
+            ..Default::default()
+        }
+        .children(self.content.clone());
+
+        let viewport_node = (
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: if axis == ScrollAxis::Horizontal {
+                    FlexDirection::Row
+                } else {
+                    FlexDirection::Column
+                },
+                align_items: if axis == ScrollAxis::Both {
+                    AlignItems::FlexStart
+                } else {
+                    AlignItems::Stretch
+                },
+                overflow: match axis {
+                    ScrollAxis::Vertical => Overflow::scroll_y(),
+                    ScrollAxis::Horizontal => Overflow::scroll_x(),
+                    ScrollAxis::Both => Overflow::scroll(),
+                },
+                ..Default::default()
+            },
+            ScrollPosition {
+                offset_x: clamped_offset.x,
+                offset_y: clamped_offset.y,
+            },
+            viewport,
+        )
+            .children(content)
+            .observe_retained(
+                |mut trigger: Trigger<ScrollWheel>,
+                 viewports: Query<&ScrollViewport>,
+                 mut state: SetState| {
+                    let Ok(viewport) = viewports.get(trigger.entity()) else {
+                        return;
+                    };
+
+                    if viewport.scroll_by(&mut state, trigger.delta) {
+                        trigger.propagate(false);
+                    }
+                },
+            )
+            .observe_retained(
+                |trigger: Trigger<Pointer<Drag>>,
+                 viewports: Query<&ScrollViewport>,
+                 mut state: SetState| {
+                    let Ok(viewport) = viewports.get(trigger.entity()) else {
+                        return;
+                    };
+
+                    // The content follows the pointer, so it moves in the opposite direction of the scroll offset.
+                    viewport.scroll_by(&mut state, -trigger.delta);
+                },
+            );
+
+        let show_scrollbars = self.show_scrollbars;
+        let scrollbar = |vertical: bool| {
+            let (viewport_length, content_length, offset) = if vertical {
+                (metrics.viewport.y, metrics.content.y, clamped_offset.y)
+            } else {
+                (metrics.viewport.x, metrics.content.x, clamped_offset.x)
+            };
+
+            let is_scrollable = if vertical {
+                axis.scrolls_y()
+            } else {
+                axis.scrolls_x()
+            };
+
+            let is_visible = show_scrollbars && is_scrollable && content_length > viewport_length;
+
+            let thumb_length = viewport_length / content_length.max(1.0) * 100.0;
+            let thumb_offset = offset / content_length.max(1.0) * 100.0;
+            let size = Val::Px(self.scrollbar_size);
+
+            let thumb = (
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: if vertical {
+                        Val::Px(0.0)
+                    } else {
+                        Val::Percent(thumb_offset)
+                    },
+                    top: if vertical {
+                        Val::Percent(thumb_offset)
+                    } else {
+                        Val::Px(0.0)
+                    },
+                    width: if vertical {
+                        size
+                    } else {
+                        Val::Percent(thumb_length)
+                    },
+                    height: if vertical {
+                        Val::Percent(thumb_length)
+                    } else {
+                        size
+                    },
+                    ..Default::default()
+                },
+                BackgroundColor(self.scrollbar_color),
+                BorderRadius::all(Val::Px(self.scrollbar_size / 2.0)),
+            )
+                .observe(
+                    move |trigger: Trigger<Pointer<Drag>>, mut state: SetState| {
+                        // The thumb represents the viewport, so moving it by a pixel scrolls the content by the ratio of the
+                        // content to the viewport.
+                        let scale = content_length / viewport_length.max(1.0);
+                        let delta = if vertical {
+                            Vec2::new(0.0, trigger.delta.y * scale)
+                        } else {
+                            Vec2::new(trigger.delta.x * scale, 0.0)
+                        };
+
+                        viewport.scroll_by(&mut state, delta);
+                    },
+                );
+
+            // Leaves room for the other scrollbar in the corner, when both are visible.
+            let corner = if axis == ScrollAxis::Both {
+                size
+            } else {
+                Val::Px(0.0)
+            };
+
+            // The vertical scrollbar is placed at the end of the line, which is on the left in right-to-left layouts.
+            let edges = if vertical {
+                LogicalRect {
+                    start: Val::Auto,
+                    end: Val::Px(0.0),
+                    top: Val::Px(0.0),
+                    bottom: corner,
+                }
+            } else {
+                LogicalRect {
+                    start: Val::Px(0.0),
+                    end: corner,
+                    top: Val::Auto,
+                    bottom: Val::Px(0.0),
+                }
+            }
+            .resolve(layout_direction);
+
+            Node {
+                position_type: PositionType::Absolute,
+                left: edges.left,
+                right: edges.right,
+                top: edges.top,
+                bottom: edges.bottom,
+                width: if vertical { size } else { Val::Auto },
+                height: if vertical { Val::Auto } else { size },
+                ..Default::default()
+            }
+            .children(thumb)
+            .some_if(is_visible)
+        };
+
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            overflow: Overflow::clip(),
+            ..Default::default()
+        }
+        .children((viewport_node, scrollbar(true), scrollbar(false)))
+        .use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("ScrollView")
+    }
+}
+
+/// Triggers [`ScrollWheel`] events on the entities hovered by the mouse.
+pub(crate) fn trigger_scroll_wheel(
+    mut commands: Commands,
+    mut cursor: Local<EventCursor<MouseWheel>>,
+    wheel_events: Option<Res<Events<MouseWheel>>>,
+    hover_map: Option<Res<HoverMap>>,
+) {
+    let Some(wheel_events) = wheel_events else {
+        return;
+    };
+
+    let delta = cursor
+        .read(&wheel_events)
+        .map(|event| {
+            let delta = Vec2::new(event.x, event.y);
+
+            match event.unit {
+                MouseScrollUnit::Line => delta * LINE_HEIGHT,
+                MouseScrollUnit::Pixel => delta,
+            }
+        })
+        .sum::<Vec2>();
+
+    if delta == Vec2::ZERO {
+        return;
+    }
+
+    let Some(hovered) = hover_map
+        .as_ref()
+        .and_then(|map| map.get(&PointerId::Mouse))
+    else {
+        return;
+    };
+
+    for entity in hovered.keys() {
+        // Scrolling the wheel "up" reveals the content above, which decreases the scroll offset.
+        commands.trigger_targets(ScrollWheel { delta: -delta }, *entity);
+    }
+}
+
+/// Binds the scroll offset of an entity to a state. See [`bind_scroll`](crate::modify::ModifyFunctions::bind_scroll).
+#[derive(Component, Clone, Copy)]
+pub(crate) struct ScrollBinding(pub(crate) TypedStateId<Vec2>);
+
+/// Applies the bound states to the scroll positions. The bindings are inserted again whenever the composable that binds
+/// them recomposes, which is also when the bound state changes.
+pub(crate) fn apply_scroll_bindings(
+    mut bindings: Query<(&ScrollBinding, &mut ScrollPosition), Changed<ScrollBinding>>,
+    states: ReadState,
+) {
+    for (binding, mut position) in bindings.iter_mut() {
+        let Some(offset) = states.get(binding.0) else {
+            continue;
+        };
+
+        if position.offset_x != offset.x || position.offset_y != offset.y {
+            position.offset_x = offset.x;
+            position.offset_y = offset.y;
+        }
+    }
+}
+
+/// Writes the scroll positions that were clamped by the layout back to the bound states, so that the states never
+/// scroll past the content.
+pub(crate) fn sync_scroll_bindings(
+    bindings: Query<(&ScrollBinding, &ScrollPosition), Changed<ScrollPosition>>,
+    mut state: SetState,
+) {
+    for (binding, position) in bindings.iter() {
+        state.set_neq(binding.0, Vec2::new(position.offset_x, position.offset_y));
+    }
+}
+
+/// Measures the viewports and the content of the scroll views after the layout was computed.
+pub(crate) fn update_scroll_metrics(
+    mut viewports: Query<(&mut ScrollViewport, &ComputedNode, &Children)>,
+    nodes: Query<&ComputedNode>,
+    mut state: SetState,
+) {
+    for (mut viewport, node, children) in viewports.iter_mut() {
+        let Some(content) = children.first().and_then(|child| nodes.get(*child).ok()) else {
+            continue;
+        };
+
+        let metrics = ScrollMetrics {
+            viewport: node.size() * node.inverse_scale_factor(),
+            content: content.size() * content.inverse_scale_factor(),
+        };
+
+        if viewport.metrics == metrics {
+            continue;
+        }
+
+        viewport.metrics = metrics;
+        state.set_neq(viewport.metrics_id, metrics);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    pointer_events::{PointerEvent, PointerEventKind},
+    state::{GetStateId, SetState, State, TypedStateId},
+    Scope,
+};
+use bevy_ecs::{
+    component::Component,
+    observer::Trigger,
+    system::{Query, Res},
+};
+use bevy_hierarchy::{Children, Parent};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_picking::pointer::PointerButton;
+
+/// Whether a [`Selection`] holds a single key or several keys.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum SelectionMode {
+    /// At most one key is selected. Clicking a key selects it instead of the previous one, and ctrl-clicking the
+    /// selected key deselects it.
+    #[default]
+    Single,
+    /// Any number of keys is selected. Clicking a key selects only that key, ctrl-clicking toggles it, and
+    /// shift-clicking selects the range from the last clicked key, which ctrl-shift-clicking adds to the selection.
+    Multi,
+}
+
+/// The selected keys of a list, created with [`use_selection`](Scope::use_selection). Entities are made selectable with
+/// [`selectable`](crate::modify::ModifyFunctions::selectable).
+#[derive(Clone, PartialEq, Debug)]
+pub struct Selection<K> {
+    mode: SelectionMode,
+    selected: Vec<K>,
+    anchor: Option<K>,
+}
+
+impl<K: Clone + PartialEq> Selection<K> {
+    /// Creates an empty selection.
+    pub fn new(mode: SelectionMode) -> Self {
+        Self {
+            mode,
+            selected: Vec::new(),
+            anchor: None,
+        }
+    }
+
+    /// Returns the mode of the selection.
+    pub fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    /// Whether the given key is selected.
+    pub fn is_selected(&self, key: &K) -> bool {
+        self.selected.contains(key)
+    }
+
+    /// Returns the selected keys, in the order they were selected.
+    pub fn selected(&self) -> &[K] {
+        &self.selected
+    }
+
+    /// Whether no key is selected.
+    pub fn is_empty(&self) -> bool {
+        self.selected.is_empty()
+    }
+
+    /// Selects the given key. In [`SelectionMode::Single`], the previously selected key is deselected.
+    pub fn select(&mut self, key: K) {
+        if self.mode == SelectionMode::Single {
+            self.selected.clear();
+        }
+
+        if !self.is_selected(&key) {
+            self.selected.push(key.clone());
+        }
+
+        self.anchor = Some(key);
+    }
+
+    /// Deselects the given key.
+    pub fn deselect(&mut self, key: &K) {
+        self.selected.retain(|selected| selected != key);
+    }
+
+    /// Selects the given key if it isn't selected, and deselects it otherwise.
+    pub fn toggle(&mut self, key: K) {
+        match self.is_selected(&key) {
+            true => {
+                self.deselect(&key);
+                self.anchor = Some(key);
+            }
+            false => self.select(key),
+        }
+    }
+
+    /// Deselects all keys.
+    pub fn clear(&mut self) {
+        self.selected.clear();
+        self.anchor = None;
+    }
+
+    /// Changes the mode of the selection. Only the most recently selected key is kept when switching to
+    /// [`SelectionMode::Single`].
+    pub fn set_mode(&mut self, mode: SelectionMode) {
+        self.mode = mode;
+
+        if mode == SelectionMode::Single && self.selected.len() > 1 {
+            self.selected.drain(..self.selected.len() - 1);
+        }
+    }
+
+    /// Applies a click on the given key, where `keys` are the selectable keys in the order they are shown.
+    fn click(&mut self, key: K, toggle: bool, extend: bool, keys: &[K]) {
+        let anchor = self.anchor.clone().unwrap_or_else(|| key.clone());
+
+        let range = match (self.mode, extend) {
+            (SelectionMode::Multi, true) => {
+                let start = keys.iter().position(|k| *k == anchor);
+                let end = keys.iter().position(|k| *k == key);
+
+                match (start, end) {
+                    (Some(start), Some(end)) => keys[start.min(end)..=start.max(end)].to_vec(),
+                    _ => vec![key.clone()],
+                }
+            }
+            _ => Vec::new(),
+        };
+
+        match (self.mode, toggle, extend) {
+            // The anchor stays in place, so that further shift-clicks change the range instead of starting a new one.
+            (SelectionMode::Multi, false, true) => self.selected = range,
+            (SelectionMode::Multi, true, true) => {
+                for key in range {
+                    if !self.is_selected(&key) {
+                        self.selected.push(key);
+                    }
+                }
+            }
+            (_, true, _) => self.toggle(key),
+            (_, false, _) => {
+                self.selected.clear();
+                self.select(key);
+            }
+        }
+    }
+}
+
+/// The key of a selectable entity. See [`selectable`](crate::modify::ModifyFunctions::selectable).
+#[derive(Component, Clone)]
+pub(crate) struct Selectable<K: Send + Sync + 'static> {
+    pub(crate) key: K,
+    pub(crate) selection: TypedStateId<Selection<K>>,
+}
+
+pub(crate) fn select_on_click<K: Clone + PartialEq + Send + Sync + 'static>(
+    trigger: Trigger<PointerEvent>,
+    input: Option<Res<ButtonInput<KeyCode>>>,
+    selectables: Query<&Selectable<K>>,
+    parents: Query<&Parent>,
+    children: Query<&Children>,
+    mut state: SetState,
+) {
+    if trigger.kind != PointerEventKind::Click || trigger.button != Some(PointerButton::Primary) {
+        return;
+    }
+
+    let entity = trigger.entity();
+
+    let Ok(selectable) = selectables.get(entity) else {
+        return;
+    };
+
+    let is_pressed =
+        |keys: [KeyCode; 2]| input.as_ref().is_some_and(|input| input.any_pressed(keys));
+
+    let toggle = is_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+        || is_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]);
+    let extend = is_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    // The range of a shift-click spans the selectable siblings of the entity, in the order of the children of their
+    // parent, which is the order they are shown in.
+    let keys = match extend {
+        true => parents
+            .get(entity)
+            .ok()
+            .and_then(|parent| children.get(parent.get()).ok())
+            .map(|siblings| {
+                siblings
+                    .iter()
+                    .filter_map(|sibling| selectables.get(*sibling).ok())
+                    .filter(|sibling| sibling.selection.get_id() == selectable.selection.get_id())
+                    .map(|sibling| sibling.key.clone())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default(),
+        false => Vec::new(),
+    };
+
+    let key = selectable.key.clone();
+
+    state.modify(selectable.selection, move |selection| {
+        let mut selection = selection.clone();
+        selection.click(key.clone(), toggle, extend, &keys);
+        selection
+    });
+}
+
+impl Scope<'_> {
+    /// Creates a selection of keys, for lists whose items can be selected, like the files of a file browser. Items are
+    /// made selectable with [`selectable`](crate::modify::ModifyFunctions::selectable), which handles clicks,
+    /// ctrl-clicks and shift-clicks as described by the [`SelectionMode`]. The selection can also be changed like any
+    /// other state, for example to clear it.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let selection = cx.use_selection::<FileId>(SelectionMode::Multi);
+    ///
+    /// let rows = files
+    ///     .iter()
+    ///     .map(|file| {
+    ///         let row = FileRow::new(file, selection.is_selected(&file.id)).selectable(file.id, &selection);
+    ///         Keyed::new(file.id, row)
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// ```
+    pub fn use_selection<K: Clone + PartialEq + Send + Sync + 'static>(
+        &mut self,
+        mode: SelectionMode,
+    ) -> State<Selection<K>> {
+        let selection = self.use_state(Selection::new(mode));
+
+        if selection.mode() == mode {
+            return selection;
+        }
+
+        let mut updated_selection = (*selection).clone();
+        updated_selection.set_mode(mode);
+        self.set_state_unchanged(&selection, updated_selection);
+
+        self.pending_value(&selection).unwrap_or(selection)
+    }
+}
@@ -0,0 +1,389 @@
+use crate::{
+    scope::{Scope, StablePath},
+    state::{State, StateChanged},
+    Root,
+};
+use bevy_ecs::{
+    entity::Entity,
+    query::Added,
+    system::{Query, ResMut, Resource},
+    world::{DeferredWorld, World},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+/// Converts the value of a saved state to and from its serialized form. The functions are instantiated for the type of
+/// the state when it is created, so that the type-erased value can be serialized later on.
+#[derive(Clone, Copy)]
+pub(crate) struct StateCodec {
+    serialize: fn(&(dyn Any + Send + Sync)) -> Option<String>,
+    deserialize: fn(&str) -> Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl StateCodec {
+    fn new<T: Serialize + DeserializeOwned + Any + Send + Sync>() -> Self {
+        Self {
+            serialize: |value| ron::to_string(value.downcast_ref::<T>()?).ok(),
+            deserialize: |serialized| {
+                let value = ron::from_str::<T>(serialized).ok()?;
+                Some(Arc::new(value))
+            },
+        }
+    }
+}
+
+/// Saves and restores the values of marked states, so that applications like editors can restore the workspace of the
+/// user on launch: open panels, scroll offsets, selections and the contents of forms. States are marked with
+/// [`use_saved_state`](Scope::use_saved_state).
+///
+/// States are identified by the [persistent id](Root::persistent) of their root, the [`StablePath`] of their scope, the
+/// name of their composable and their index in the scope. The identity therefore only depends on the structure of the
+/// composables, which makes it stable across app restarts, while states whose composable has been replaced by a
+/// different one aren't restored. Only the states of roots with a persistent id are saved, since there is no other way
+/// to tell roots apart across restarts.
+///
+/// # Example
+/// ```ignore
+/// // On exit.
+/// let bytes = world.resource::<UiSession>().save(world);
+/// std::fs::write("session.ron", bytes)?;
+///
+/// // On launch.
+/// let bytes = std::fs::read("session.ron")?;
+/// world.resource_mut::<UiSession>().restore(&bytes)?;
+/// ```
+#[derive(Resource, Default)]
+pub struct UiSession {
+    /// Restored values of states that haven't been composed yet.
+    pending: BTreeMap<String, String>,
+}
+
+impl UiSession {
+    /// Serializes the current values of all saved states in the world. Restored values of states that haven't been
+    /// composed yet are included as well, so that they aren't lost when the session is saved again.
+    pub fn save(&self, world: &World) -> Vec<u8> {
+        let mut saved = self.pending.clone();
+
+        for root in world
+            .iter_entities()
+            .filter_map(|entity| entity.get::<Root>())
+        {
+            let (Some(scope), Some(root_id)) = (&root.scope, &root.persistent_id) else {
+                continue;
+            };
+
+            let mut scopes = vec![scope];
+
+            while let Some(scope) = scopes.pop() {
+                let name = scope.composer.get_name();
+
+                for (index, state) in scope.states.iter().enumerate() {
+                    let Some(codec) = state.codec else {
+                        continue;
+                    };
+
+                    let Some(serialized) = (codec.serialize)(state.value.as_ref()) else {
+                        continue;
+                    };
+
+                    saved.insert(session_key(root_id, &scope.path, &name, index), serialized);
+                }
+
+                scopes.extend(scope.children.iter());
+            }
+        }
+
+        ron::to_string(&saved)
+            .expect("Failed to serialize session.")
+            .into_bytes()
+    }
+
+    /// Restores the states saved with [`save`](Self::save). States are restored as soon as they are composed, which is
+    /// either on the next update or when their composable is added to the tree later on. Values that can't be
+    /// deserialized into the type of their state are ignored.
+    pub fn restore(&mut self, bytes: &[u8]) -> Result<(), ron::error::SpannedError> {
+        self.pending = ron::de::from_bytes(bytes)?;
+        Ok(())
+    }
+
+    /// Whether there are restored values of states that haven't been composed yet.
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+}
+
+fn session_key(root_id: &str, path: &StablePath, name: &str, index: usize) -> String {
+    format!("{}{}:{}:{}", root_id, path, name, index)
+}
+
+/// The saved states of a single [`Root`], taken with [`Root::snapshot_states`] and restored with
+/// [`Root::restore_states`]. Unlike the [`UiSession`], which covers all roots at once, a snapshot belongs to one root,
+/// which makes it a good fit for save games and for restoring a UI after hot-reloading it.
+///
+/// States are marked for snapshots with [`use_saved_state`](Scope::use_saved_state), and are identified by the
+/// [`StablePath`] of their scope, the name of their composable and their index in the scope. States whose composable
+/// has been replaced by a different one are therefore left alone when the snapshot is restored.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct StateSnapshot {
+    states: BTreeMap<String, String>,
+}
+
+impl StateSnapshot {
+    /// Serializes the snapshot, so that it can be written to a file.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        ron::to_string(&self.states)
+            .expect("Failed to serialize state snapshot.")
+            .into_bytes()
+    }
+
+    /// Deserializes a snapshot that was serialized with [`to_bytes`](Self::to_bytes).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ron::error::SpannedError> {
+        Ok(Self {
+            states: ron::de::from_bytes(bytes)?,
+        })
+    }
+
+    /// The number of states in the snapshot.
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Whether the snapshot has no states.
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+}
+
+fn snapshot_key(scope: &Scope, index: usize) -> String {
+    format!("{}:{}:{}", scope.path, scope.composer.get_name(), index)
+}
+
+impl Root {
+    /// Takes a snapshot of the saved states of this root. Restored values of states that haven't been composed yet are
+    /// included as well.
+    pub fn snapshot_states(&self) -> StateSnapshot {
+        let mut snapshot = StateSnapshot {
+            states: self.pending_states.clone(),
+        };
+
+        let Some(scope) = &self.scope else {
+            return snapshot;
+        };
+
+        let mut scopes = vec![scope];
+
+        while let Some(scope) = scopes.pop() {
+            for (index, state) in scope.states.iter().enumerate() {
+                let Some(codec) = state.codec else {
+                    continue;
+                };
+
+                let Some(serialized) = (codec.serialize)(state.value.as_ref()) else {
+                    continue;
+                };
+
+                snapshot
+                    .states
+                    .insert(snapshot_key(scope, index), serialized);
+            }
+
+            scopes.extend(scope.children.iter());
+        }
+
+        snapshot
+    }
+
+    /// Restores the states of a snapshot taken with [`snapshot_states`](Self::snapshot_states). States that are
+    /// currently composed are restored right away and recomposed on the next update. The other states are restored as
+    /// soon as they are composed, for example when the panel they belong to is opened by a restored state. Values that
+    /// can't be deserialized into the type of their state are ignored.
+    pub fn restore_states(&mut self, snapshot: StateSnapshot) {
+        self.pending_states = snapshot.states;
+        restore_root_states(self);
+    }
+}
+
+/// The saved states of [persistent](Root::persistent) roots that have been despawned, by the persistent ids of the
+/// roots. The states are stashed when a persistent root is despawned, and restored when a root with the same id is
+/// spawned again.
+#[derive(Resource, Default)]
+pub struct PersistentRoots {
+    snapshots: HashMap<String, StateSnapshot>,
+}
+
+impl PersistentRoots {
+    /// Returns the stashed states of the root with the given id, if it has been despawned.
+    pub fn get(&self, id: &str) -> Option<&StateSnapshot> {
+        self.snapshots.get(id)
+    }
+
+    /// Discards the stashed states of the root with the given id, so that it starts with fresh states the next time it
+    /// is spawned.
+    pub fn forget(&mut self, id: &str) -> Option<StateSnapshot> {
+        self.snapshots.remove(id)
+    }
+}
+
+impl Root {
+    /// Gives the root a persistent id, so that its saved states survive when the root is despawned and spawned again,
+    /// and are saved by the [`UiSession`]. The id has to be unique among the roots. When the root is despawned, a
+    /// [snapshot](Root::snapshot_states) of its states is stashed in the [`PersistentRoots`] resource, and it is
+    /// restored when a root with the same id is spawned. This lets menus remember their last tab or scroll offset
+    /// without moving their states into a store.
+    ///
+    /// Like snapshots, only the states that are marked with [`use_saved_state`](Scope::use_saved_state) are kept.
+    ///
+    /// # Example
+    /// ```ignore
+    /// commands.spawn((Root::new(MainMenu).persistent("main_menu"), Node::default()));
+    /// ```
+    pub fn persistent(mut self, id: impl Into<String>) -> Self {
+        self.persistent_id = Some(id.into());
+        self
+    }
+}
+
+/// Stashes the saved states of the root on the given entity, if the root is persistent.
+pub(crate) fn stash_persistent_root(world: &mut DeferredWorld, entity: Entity) {
+    let Some(root) = world.get::<Root>(entity) else {
+        return;
+    };
+
+    let Some(id) = root.persistent_id.clone() else {
+        return;
+    };
+
+    let snapshot = root.snapshot_states();
+
+    if let Some(mut persistent_roots) = world.get_resource_mut::<PersistentRoots>() {
+        persistent_roots.snapshots.insert(id, snapshot);
+    }
+}
+
+/// Hands the stashed states of newly spawned persistent roots back to the roots, which restore them once they are
+/// composed.
+pub(crate) fn unstash_persistent_roots(
+    persistent_roots: Option<ResMut<PersistentRoots>>,
+    mut roots: Query<&mut Root, Added<Root>>,
+) {
+    let Some(mut persistent_roots) = persistent_roots else {
+        return;
+    };
+
+    for mut root in roots.iter_mut() {
+        let Some(id) = &root.persistent_id else {
+            continue;
+        };
+
+        if let Some(snapshot) = persistent_roots.snapshots.remove(id) {
+            root.pending_states = snapshot.states;
+        }
+    }
+}
+
+/// Restores the pending states of the root that have been composed since the snapshot was restored.
+fn restore_root_states(root: &mut Root) {
+    let Some(scope) = &mut root.scope else {
+        return;
+    };
+
+    let mut scopes = vec![scope];
+
+    while let Some(scope) = scopes.pop() {
+        for index in 0..scope.states.len() {
+            let Some(codec) = scope.states[index].codec else {
+                continue;
+            };
+
+            let Some(serialized) = root.pending_states.remove(&snapshot_key(scope, index)) else {
+                continue;
+            };
+
+            let Some(value) = (codec.deserialize)(&serialized) else {
+                continue;
+            };
+
+            let state = &mut scope.states[index];
+            state.value = value;
+            state.changed = StateChanged::Queued;
+        }
+
+        scopes.extend(scope.children.iter_mut());
+    }
+}
+
+impl Scope<'_> {
+    /// Creates a new state, like [`use_state`](Scope::use_state), that is saved and restored by the [`UiSession`]. The
+    /// value is saved whenever the session is saved, and replaced by the restored value when the session is restored.
+    pub fn use_saved_state<T: Serialize + DeserializeOwned + Any + Send + Sync>(
+        &mut self,
+        initial_value: T,
+    ) -> State<T> {
+        let state = self.use_state(initial_value);
+        let dyn_state = &mut self.states[self.state_index - 1];
+
+        if dyn_state.codec.is_none() {
+            dyn_state.codec = Some(StateCodec::new::<T>());
+        }
+
+        state
+    }
+}
+
+pub(crate) fn restore_session_states(
+    session: Option<ResMut<UiSession>>,
+    mut roots: Query<&mut Root>,
+) {
+    for mut root in roots.iter_mut() {
+        if !root.pending_states.is_empty() {
+            restore_root_states(&mut root);
+        }
+    }
+
+    let Some(mut session) = session else {
+        return;
+    };
+
+    if !session.has_pending() {
+        return;
+    }
+
+    for mut root in roots.iter_mut() {
+        let root = &mut *root;
+
+        let (Some(scope), Some(root_id)) = (&mut root.scope, &root.persistent_id) else {
+            continue;
+        };
+
+        let mut scopes = vec![scope];
+
+        while let Some(scope) = scopes.pop() {
+            let name = scope.composer.get_name();
+
+            for (index, state) in scope.states.iter_mut().enumerate() {
+                let Some(codec) = state.codec else {
+                    continue;
+                };
+
+                let key = session_key(root_id, &scope.path, &name, index);
+
+                let Some(serialized) = session.pending.remove(&key) else {
+                    continue;
+                };
+
+                let Some(value) = (codec.deserialize)(&serialized) else {
+                    continue;
+                };
+
+                state.value = value;
+                state.changed = StateChanged::Queued;
+            }
+
+            scopes.extend(scope.children.iter_mut());
+        }
+    }
+}
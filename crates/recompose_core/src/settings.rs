@@ -0,0 +1,208 @@
+use crate::{state::StateErrorMode, Root};
+use bevy_ecs::system::{Local, Query, Res, Resource};
+use bevy_time::{Real, Time};
+use std::{
+    sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering},
+    time::Duration,
+};
+
+/// Guards against composables that recompose without an end, like a composable that composes itself, or one that sets
+/// its own states every time it composes. Insert the resource to change the settings. It is read at the start of every
+/// frame, so it can be changed at runtime.
+///
+/// # Example
+/// ```ignore
+/// App::new()
+///     .add_plugins((DefaultPlugins, RecomposePlugin))
+///     .insert_resource(RecomposeSettings {
+///         max_depth: 128,
+///         ..Default::default()
+///     })
+/// ```
+#[derive(Resource, Clone, Debug)]
+pub struct RecomposeSettings {
+    /// The maximum depth of the scope tree. The children of composables that are nested deeper are not composed, and an
+    /// error is logged. This stops composables that compose themselves before they overflow the stack.
+    pub max_depth: usize,
+    /// The maximum number of times a scope is recomposed in a single frame. Further recompositions because of its
+    /// states are deferred to the next frame.
+    pub max_recompositions_per_frame: u32,
+    /// Detects composables that recompose themselves in every frame. `None`, the default, disables the detection.
+    pub loop_detection: Option<LoopDetection>,
+    /// Applies the `Commands` of the systems queued with [`run_system`](crate::Scope::run_system) together, once all
+    /// of them have run, instead of after every system. This is faster when many scopes queue systems, but a queued
+    /// system then doesn't see the commands of the systems that were queued before it in the same frame, unless it is
+    /// an exclusive system. Disabled by default.
+    pub batch_queued_systems: bool,
+}
+
+impl Default for RecomposeSettings {
+    fn default() -> Self {
+        Self {
+            max_depth: 256,
+            max_recompositions_per_frame: 4,
+            loop_detection: None,
+            batch_queued_systems: false,
+        }
+    }
+}
+
+/// Detects composables that set their own states while composing, and are therefore recomposed in every frame. Once a
+/// loop is detected, a warning with the name of the composable is logged, and the composable is throttled. Composables
+/// that animate on purpose can opt out with [`allow_recompose_loop`](crate::Scope::allow_recompose_loop).
+///
+/// # Example
+/// ```ignore
+/// app.insert_resource(RecomposeSettings {
+///     loop_detection: Some(LoopDetection::default()),
+///     ..Default::default()
+/// })
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct LoopDetection {
+    /// The number of consecutive frames in which a composable has to recompose itself to be considered a loop.
+    pub frames: u32,
+    /// The maximum rate at which a detected loop is recomposed, in recompositions per second. Loops are only reported,
+    /// but not throttled, if the rate isn't positive.
+    pub throttle_hz: f32,
+}
+
+impl Default for LoopDetection {
+    fn default() -> Self {
+        Self {
+            frames: 120,
+            throttle_hz: 10.0,
+        }
+    }
+}
+
+/// The settings of the current frame, which are stamped into every root at the start of the frame. Scopes are composed
+/// without access to the world, so they read the settings of their root from here. Keeping them per root, rather than
+/// in statics, keeps the frames and settings of separate apps in the same process apart.
+#[derive(Debug)]
+pub(crate) struct FrameSettings {
+    frame: AtomicU64,
+    max_depth: AtomicUsize,
+    max_recompositions_per_frame: AtomicU32,
+    loop_frames: AtomicU32,
+    loop_throttle_nanos: AtomicU64,
+    poison_on_state_error: AtomicBool,
+    /// The elapsed real time at the start of the frame, in nanoseconds, or `u64::MAX` without a `Time` resource.
+    now_nanos: AtomicU64,
+}
+
+impl Default for FrameSettings {
+    fn default() -> Self {
+        let settings = Self {
+            frame: AtomicU64::new(0),
+            max_depth: AtomicUsize::new(0),
+            max_recompositions_per_frame: AtomicU32::new(0),
+            loop_frames: AtomicU32::new(0),
+            loop_throttle_nanos: AtomicU64::new(0),
+            poison_on_state_error: AtomicBool::new(false),
+            now_nanos: AtomicU64::new(u64::MAX),
+        };
+
+        settings.store(
+            0,
+            &RecomposeSettings::default(),
+            StateErrorMode::default(),
+            None,
+        );
+        settings
+    }
+}
+
+impl FrameSettings {
+    fn store(
+        &self,
+        frame: u64,
+        settings: &RecomposeSettings,
+        state_error_mode: StateErrorMode,
+        now: Option<Duration>,
+    ) {
+        let (loop_frames, loop_throttle) = match settings.loop_detection {
+            Some(detection) => (
+                detection.frames.max(1),
+                Duration::try_from_secs_f32(1.0 / detection.throttle_hz)
+                    .ok()
+                    .filter(|_| detection.throttle_hz > 0.0)
+                    .unwrap_or(Duration::ZERO),
+            ),
+            None => (0, Duration::ZERO),
+        };
+
+        self.frame.store(frame, Ordering::Relaxed);
+        self.max_depth.store(settings.max_depth, Ordering::Relaxed);
+        self.max_recompositions_per_frame.store(
+            settings.max_recompositions_per_frame.max(1),
+            Ordering::Relaxed,
+        );
+        self.loop_frames.store(loop_frames, Ordering::Relaxed);
+        self.loop_throttle_nanos
+            .store(loop_throttle.as_nanos() as u64, Ordering::Relaxed);
+        self.poison_on_state_error.store(
+            state_error_mode == StateErrorMode::Poison,
+            Ordering::Relaxed,
+        );
+        self.now_nanos.store(
+            now.map_or(u64::MAX, |now| now.as_nanos() as u64),
+            Ordering::Relaxed,
+        );
+    }
+
+    /// The number of the current frame of the app, which is used to count the recompositions of scopes per frame.
+    pub(crate) fn frame(&self) -> u64 {
+        self.frame.load(Ordering::Relaxed)
+    }
+
+    /// See [`RecomposeSettings::max_depth`].
+    pub(crate) fn max_depth(&self) -> usize {
+        self.max_depth.load(Ordering::Relaxed)
+    }
+
+    /// See [`RecomposeSettings::max_recompositions_per_frame`].
+    pub(crate) fn max_recompositions_per_frame(&self) -> u32 {
+        self.max_recompositions_per_frame.load(Ordering::Relaxed)
+    }
+
+    /// The number of frames after which a loop is detected, or `None` if the detection is disabled.
+    pub(crate) fn loop_frames(&self) -> Option<u32> {
+        Some(self.loop_frames.load(Ordering::Relaxed)).filter(|frames| *frames > 0)
+    }
+
+    /// The minimum duration between two recompositions of a detected loop.
+    pub(crate) fn loop_throttle_interval(&self) -> Duration {
+        Duration::from_nanos(self.loop_throttle_nanos.load(Ordering::Relaxed))
+    }
+
+    /// Whether state errors poison the scope instead of panicking. See [`StateErrorMode`].
+    pub(crate) fn poison_on_state_error(&self) -> bool {
+        self.poison_on_state_error.load(Ordering::Relaxed)
+    }
+
+    /// The elapsed real time at the start of the frame, which scopes are throttled by. See
+    /// [`recompose_at_most`](crate::scope::Scope::recompose_at_most).
+    pub(crate) fn now(&self) -> Option<Duration> {
+        Some(self.now_nanos.load(Ordering::Relaxed))
+            .filter(|nanos| *nanos != u64::MAX)
+            .map(Duration::from_nanos)
+    }
+}
+
+pub(crate) fn begin_recompose_frame(
+    settings: Res<RecomposeSettings>,
+    state_error_mode: Res<StateErrorMode>,
+    time: Option<Res<Time<Real>>>,
+    roots: Query<&Root>,
+    mut frame: Local<u64>,
+) {
+    *frame += 1;
+    let now = time.map(|time| time.elapsed());
+
+    for root in roots.iter() {
+        root.context
+            .settings
+            .store(*frame, &settings, *state_error_mode, now);
+    }
+}
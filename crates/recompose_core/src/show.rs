@@ -0,0 +1,68 @@
+use crate::{dyn_compose::DynCompose, Compose, Scope};
+
+/// A composable that only composes its content while a condition is met, and decomposes it otherwise. The content is
+/// built by a closure that is only called while the condition is met, so unlike
+/// [`some_if`](crate::modify::ModifyFunctions::some_if), hidden content is never constructed. This avoids building
+/// heavy props for branches that aren't shown.
+///
+/// An alternative can be composed with [`else_compose`](Show::else_compose) while the condition isn't met. The content
+/// and the alternative are composed in separate scopes, so switching between them always decomposes the other branch,
+/// even if both are of the same type.
+///
+/// # Example
+/// ```ignore
+/// Show::when(*is_inventory_open, || Inventory::new(&items))
+///     .else_compose(|| Text::new("Press I to open the inventory"))
+/// ```
+#[derive(Clone)]
+pub struct Show<F, G = fn()> {
+    condition: bool,
+    content: F,
+    fallback: G,
+}
+
+impl<C: Compose + 'static, F: Fn() -> C + Send + Sync> Show<F> {
+    /// Creates a new `Show` that composes the content built by the given closure while the condition is `true`.
+    pub fn when(condition: bool, content: F) -> Self {
+        Self {
+            condition,
+            content,
+            fallback: || {},
+        }
+    }
+}
+
+impl<F, G> Show<F, G> {
+    /// Composes the alternative built by the given closure while the condition is `false`. The closure is only called
+    /// while the condition is `false`.
+    pub fn else_compose<E: Compose + 'static, H: Fn() -> E + Send + Sync>(
+        self,
+        fallback: H,
+    ) -> Show<F, H> {
+        Show {
+            condition: self.condition,
+            content: self.content,
+            fallback,
+        }
+    }
+}
+
+impl<C, E, F, G> Compose for Show<F, G>
+where
+    C: Compose + 'static,
+    E: Compose + 'static,
+    F: Fn() -> C + Send + Sync,
+    G: Fn() -> E + Send + Sync,
+{
+    fn compose<'a>(&self, _: &mut Scope) -> impl Compose + 'a {
+        // Each branch has its own slot, so that the branch that isn't shown is decomposed.
+        match self.condition {
+            true => (DynCompose::new((self.content)()), DynCompose::default()),
+            false => (DynCompose::default(), DynCompose::new((self.fallback)())),
+        }
+    }
+
+    fn name(&self) -> String {
+        String::from("Show")
+    }
+}
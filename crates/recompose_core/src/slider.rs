@@ -0,0 +1,257 @@
+use crate::{
+    binding::{BindValue, Binding, ChangeHandler},
+    bundle_extension::BundleExtension,
+    layout_direction::LogicalRect,
+    modify::{Modifier, Modify, ModifyFunctions},
+    state::SetState,
+    Compose, Scope,
+};
+use bevy_color::{Color, Srgba};
+use bevy_ecs::{
+    component::Component,
+    observer::Trigger,
+    system::{Commands, Query},
+};
+use bevy_picking::events::{Drag, DragStart, Pointer};
+use bevy_ui::{BackgroundColor, BorderRadius, Node, PositionType, Val};
+use std::{ops::RangeInclusive, sync::Arc};
+
+const TRACK_HEIGHT: f32 = 20.0;
+const RAIL_HEIGHT: f32 = 4.0;
+const THUMB_SIZE: f32 = 16.0;
+
+/// The value of the slider when the current drag started.
+#[derive(Component)]
+struct SliderDragStart(f32);
+
+/// A composable that lets the user pick a value from a range by dragging a thumb along a track.
+///
+/// The value is controlled by the parent: it is passed in with [`new`](Slider::new), and edits are reported through
+/// [`on_change`](Slider::on_change). Alternatively, the value can be bound to a state with
+/// [`bind_value`](BindValue::bind_value).
+///
+/// # Example
+/// ```ignore
+/// let volume = cx.use_state(0.5);
+///
+/// Slider::new(*volume, 0.0..=1.0).step(0.1).bind_value(&volume)
+/// ```
+#[derive(Clone)]
+pub struct Slider {
+    value: f32,
+    range: RangeInclusive<f32>,
+    step: Option<f32>,
+    width: f32,
+    on_change: Option<ChangeHandler<f32>>,
+    binding: Option<Binding<f32>>,
+    modifier: Modifier,
+}
+
+impl Slider {
+    /// Creates a new slider with the given value and range.
+    pub fn new(value: f32, range: RangeInclusive<f32>) -> Self {
+        Self {
+            value,
+            range,
+            step: None,
+            width: 160.0,
+            on_change: None,
+            binding: None,
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Snaps the value to multiples of the given step, counted from the start of the range.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Sets the width of the slider track, in logical pixels.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the function that is called with the new value when the slider is dragged.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(f32, &mut SetState) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+}
+
+impl BindValue<f32> for Slider {
+    fn binding(&mut self) -> &mut Option<Binding<f32>> {
+        &mut self.binding
+    }
+}
+
+impl Modify for Slider {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for Slider {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let value = cx.use_binding(self.binding.as_ref(), self.value);
+        let is_hovered = cx.use_state(false);
+        let is_pressed = cx.use_state(false);
+        let direction = cx.use_layout_direction();
+
+        let setter = value.setter();
+        let current_value = *value;
+        let range = self.range.clone();
+        let step = self.step;
+        let width = self.width;
+        let on_change = self.on_change.clone();
+        let sign = direction.sign();
+
+        let (start, end) = (*range.start(), *range.end());
+        let span = end - start;
+        let ratio = if span > 0.0 {
+            ((current_value - start) / span).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let thumb_color = match (*is_pressed, *is_hovered) {
+            (true, _) => Srgba::rgb(0.75, 0.75, 0.75),
+            (false, true) => Srgba::rgb(0.9, 0.9, 0.9),
+            (false, false) => Srgba::WHITE,
+        };
+
+        let rail = (
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                right: Val::Px(0.0),
+                top: Val::Px((TRACK_HEIGHT - RAIL_HEIGHT) / 2.0),
+                height: Val::Px(RAIL_HEIGHT),
+                ..Default::default()
+            },
+            BackgroundColor(Color::srgb(0.3, 0.3, 0.3)),
+            BorderRadius::all(Val::Px(RAIL_HEIGHT / 2.0)),
+        );
+
+        let fill = (
+            Node {
+                position_type: PositionType::Absolute,
+                left: if direction.is_rtl() {
+                    Val::Auto
+                } else {
+                    Val::Px(0.0)
+                },
+                right: if direction.is_rtl() {
+                    Val::Px(0.0)
+                } else {
+                    Val::Auto
+                },
+                top: Val::Px((TRACK_HEIGHT - RAIL_HEIGHT) / 2.0),
+                width: Val::Percent(ratio * 100.0),
+                height: Val::Px(RAIL_HEIGHT),
+                ..Default::default()
+            },
+            BackgroundColor(Srgba::rgb(0.25, 0.5, 0.95).into()),
+            BorderRadius::all(Val::Px(RAIL_HEIGHT / 2.0)),
+        );
+
+        let thumb = (
+            Node {
+                position_type: PositionType::Absolute,
+                left: if direction.is_rtl() {
+                    Val::Auto
+                } else {
+                    Val::Percent(ratio * 100.0)
+                },
+                right: if direction.is_rtl() {
+                    Val::Percent(ratio * 100.0)
+                } else {
+                    Val::Auto
+                },
+                top: Val::Px((TRACK_HEIGHT - THUMB_SIZE) / 2.0),
+                margin: LogicalRect::start(Val::Px(-THUMB_SIZE / 2.0)).resolve(direction),
+                width: Val::Px(THUMB_SIZE),
+                height: Val::Px(THUMB_SIZE),
+                ..Default::default()
+            },
+            BackgroundColor(thumb_color.into()),
+            BorderRadius::all(Val::Px(THUMB_SIZE / 2.0)),
+        );
+
+        Node {
+            width: Val::Px(width),
+            height: Val::Px(TRACK_HEIGHT),
+            ..Default::default()
+        }
+        .children((rail.to_compose(), fill.to_compose(), thumb.to_compose()))
+        .bind_hover(&is_hovered)
+        .bind_pressed(&is_pressed)
+        .observe(
+            move |trigger: Trigger<Pointer<DragStart>>, mut commands: Commands| {
+                commands
+                    .entity(trigger.entity())
+                    .insert(SliderDragStart(current_value));
+            },
+        )
+        .observe(
+            move |mut trigger: Trigger<Pointer<Drag>>,
+                  drag_starts: Query<&SliderDragStart>,
+                  mut state: SetState| {
+                // Dragging the slider shouldn't also drag its ancestors, like a scroll view.
+                trigger.propagate(false);
+
+                let Ok(SliderDragStart(start_value)) = drag_starts.get(trigger.entity()) else {
+                    return;
+                };
+
+                // The value is derived from the total distance of the drag, so that no movement is lost when multiple
+                // drag events happen before the slider is recomposed.
+                let new_value = snap(
+                    start_value + trigger.distance.x * sign / width * span,
+                    &range,
+                    step,
+                );
+
+                if new_value == current_value {
+                    return;
+                }
+
+                setter.set(&mut state, new_value);
+
+                if let Some(on_change) = &on_change {
+                    on_change(new_value, &mut state);
+                }
+            },
+        )
+        .use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("Slider")
+    }
+}
+
+fn snap(value: f32, range: &RangeInclusive<f32>, step: Option<f32>) -> f32 {
+    // `f32::clamp` panics for reversed and NaN bounds, so the range is normalized first. `min` and `max` skip a NaN
+    // bound, and a range whose bounds are both NaN leaves the value as it is.
+    let start = range.start().min(*range.end());
+    let end = range.start().max(*range.end());
+
+    if start.is_nan() {
+        return value;
+    }
+
+    let value = value.clamp(start, end);
+
+    match step {
+        Some(step) if step > 0.0 => {
+            let steps = ((value - start) / step).round();
+            (start + steps * step).clamp(start, end)
+        }
+        _ => value,
+    }
+}
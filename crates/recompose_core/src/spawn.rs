@@ -1,16 +1,26 @@
 use crate::{
     ChildIndex, ChildOrder, Compose, Root, Scope, SetState,
-    modify::{Modifier, Modify},
+    dyn_compose::DynCompose,
+    interaction::{disable_entity, enable_entity},
+    modify::{Modifier, Modify, ObserverSignature},
     scope::ScopeId,
+    state::{ReadState, State, TypedStateId},
+    tooltip::TooltipHost,
 };
 use bevy_ecs::{
     bundle::Bundle,
-    component::Component,
+    component::{Component, ComponentId},
     entity::Entity,
-    system::{Commands, Query},
+    system::{Commands, EntityCommands, Query, Res},
+    world::{EntityWorldMut, World},
 };
-use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt};
-use std::{collections::BTreeMap, sync::Arc};
+use bevy_hierarchy::{BuildChildren, Children, DespawnRecursiveExt, Parent};
+use bevy_log::warn;
+use bevy_picking::PickingBehavior;
+use bevy_render::view::Visibility;
+use bevy_time::{Time, Timer, TimerMode};
+use bevy_transform::components::Transform;
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
 
 /// A composable that takes in a bundle and spawns an entity with the bundle. When the composable is recomposed, the
 /// bundle, children and observers are updated. When the composable is "decomposed", the entity is despawned from the
@@ -19,6 +29,9 @@ use std::{collections::BTreeMap, sync::Arc};
 pub struct Spawn<B: Bundle + Clone> {
     pub(crate) bundle_generator: Arc<dyn (Fn() -> B) + Send + Sync>,
     pub(crate) modifier: Modifier,
+    /// The existing entity that the bundle is inserted into, instead of spawning a new one. See
+    /// [`attach`](Spawn::attach).
+    pub(crate) attach_to: Option<Entity>,
 }
 
 impl<B: Bundle + Clone> Spawn<B> {
@@ -27,8 +40,47 @@ impl<B: Bundle + Clone> Spawn<B> {
         Self {
             bundle_generator: Arc::new(move || bundle.clone()),
             modifier: Modifier::default(),
+            attach_to: None,
         }
     }
+
+    /// Creates a new spawn that inserts the bundle into an existing entity that is owned elsewhere, like an entity that
+    /// was spawned by a scene, instead of spawning a new entity. The bundle, children and observers are updated like
+    /// for any other spawn, but the entity keeps its place in the hierarchy.
+    ///
+    /// When the composable is decomposed, the entity isn't despawned. Instead, only the components that were added to
+    /// it since it was attached are removed, and no despawn transition is played.
+    ///
+    /// # Example
+    /// ```ignore
+    /// Spawn::attach(scene_panel, BackgroundColor(Color::BLACK)).children(TextLabel::new("Loaded from a scene"))
+    /// ```
+    pub fn attach(entity: Entity, bundle: B) -> Self {
+        Self {
+            attach_to: Some(entity),
+            ..Self::new(bundle)
+        }
+    }
+}
+
+impl<B: Bundle + Clone> Spawn<B> {
+    /// Creates a new spawn for entities that live in world-space rather than in the UI tree, such as sprites and meshes.
+    /// The spawned entity is given a `Transform` and `Visibility` if the bundle doesn't already contain them, so that
+    /// the transform hierarchy is propagated through the composed children.
+    ///
+    /// Note that the `Root` should also be spawned with a `Transform` and `Visibility` for the hierarchy to propagate.
+    pub fn spatial(bundle: B) -> Self {
+        let mut spawn = Self::new(bundle);
+
+        spawn
+            .modifier
+            .bundle_modifiers
+            .push(Arc::new(|entity: &mut EntityCommands| {
+                entity.try_insert_if_new((Transform::default(), Visibility::default()));
+            }));
+
+        spawn
+    }
 }
 
 impl<B: Bundle + Clone> Modify for Spawn<B> {
@@ -46,22 +98,42 @@ impl<B: Bundle + Clone> Compose for Spawn<B> {
         >>(Box::new(
             |_: Entity, _: ChildIndex, _: &mut Commands, _: &mut SetState| {},
         ));
-        let temporary_observers = cx.use_state(Vec::new());
+        let temporary_observers = cx.use_state(Vec::<(ObserverSignature, Entity)>::new());
+        let retained_observers = cx.use_state(Vec::new());
+        let was_disabled = cx.use_state(false);
 
-        if let Some(entity) = *entity {
+        let spawned_entity = *entity;
+
+        if let Some(entity) = spawned_entity {
             cx.set_entity(entity);
         };
 
         let retained_observer_generators = self.modifier.retained_observers.clone();
         let scope_id = cx.id;
 
+        let attach_to = self.attach_to;
+
         cx.use_system_once(move |mut state: SetState, mut commands: Commands| {
-            let mut ec = commands.spawn(SpawnComposable(scope_id));
+            let mut ec = match attach_to {
+                Some(target) => {
+                    let Some(mut ec) = commands.get_entity(target) else {
+                        warn!("Cannot attach to entity {target}, since it doesn't exist.");
+                        return;
+                    };
 
-            retained_observer_generators.iter().for_each(|generator| {
-                generator.generate(&mut ec);
-            });
+                    ec.queue(record_attached_components)
+                        .insert(SpawnComposable(scope_id));
+                    ec
+                }
+                None => commands.spawn(SpawnComposable(scope_id)),
+            };
+
+            let observer_entities = retained_observer_generators
+                .iter()
+                .map(|generator| generator.generate(&mut ec))
+                .collect::<Vec<_>>();
 
+            state.set_unchanged(&retained_observers, observer_entities);
             state.set(&entity, Some(ec.id()));
         });
 
@@ -70,6 +142,19 @@ impl<B: Bundle + Clone> Compose for Spawn<B> {
         let temporary_observer_entities = temporary_observers.clone();
         let conditional_bundles = self.modifier.bundle_modifiers.clone();
         let parent_entity = cx.parent_entity;
+        let order = self.modifier.order.unwrap_or(0);
+        let detached = self.modifier.detached;
+        let pickable = self.modifier.pickable;
+        let block_lower = self.modifier.block_lower;
+        let is_attached = self.attach_to.is_some();
+
+        // The flag is set before the children are composed, so that they are disabled as well.
+        cx.is_self_disabled = self.modifier.disabled.unwrap_or(false);
+        let is_disabled = cx.is_disabled();
+        let is_styled = cx.is_self_disabled;
+        let should_enable = *was_disabled;
+        cx.set_state_unchanged(&was_disabled, is_disabled);
+
         // In order to make the Spawn-composable more efficient, we're doing some trickery to avoid using `run_system`,
         // which proved itself to be very slow.
         //
@@ -85,46 +170,162 @@ impl<B: Bundle + Clone> Compose for Spawn<B> {
                       child_index: ChildIndex,
                       commands: &mut Commands,
                       state: &mut SetState| {
-                    for observer_entity in temporary_observer_entities.iter() {
-                        let Some(observer_ec) = commands.get_entity(*observer_entity) else {
-                            continue;
-                        };
-
-                        observer_ec.try_despawn_recursive();
-                    }
-
                     let bundle = generator();
                     let mut ec = commands.entity(entity);
 
+                    if should_enable {
+                        enable_entity(&mut ec, is_disabled, is_styled, pickable);
+                    }
+
                     for conditional_bundle in conditional_bundles.iter() {
                         conditional_bundle(&mut ec);
                     }
 
-                    ec.try_insert((bundle, ChildOrder(child_index)))
-                        .set_parent(parent_entity);
+                    if pickable.is_some() || block_lower.is_some() {
+                        override_picking_behavior(&mut ec, pickable, block_lower);
+                    }
+
+                    ec.try_insert(bundle);
+
+                    if is_disabled {
+                        disable_entity(&mut ec, is_styled);
+                    }
+
+                    // Attached entities are owned elsewhere, so they keep their place in the hierarchy.
+                    if !is_attached {
+                        let child_order = ChildOrder::new(order, child_index);
 
+                        // The order and the parent are only written when they differ, since a new order makes the
+                        // siblings be sorted again, and setting the parent moves the entity to the end of the children.
+                        ec.queue(move |mut entity: EntityWorldMut| {
+                            if entity.get::<ChildOrder>() != Some(&child_order) {
+                                entity.insert(child_order);
+                            }
+
+                            match detached {
+                                true => {
+                                    entity.remove_parent();
+                                }
+                                false
+                                    if entity.get::<Parent>().map(Parent::get)
+                                        != Some(parent_entity) =>
+                                {
+                                    entity.set_parent(parent_entity);
+                                }
+                                false => {}
+                            }
+                        });
+                    }
+
+                    // Observers are regenerated on every update, since they may capture new values. Observers that
+                    // take the place of an observer with the same signature reuse its entity, so they don't have to be
+                    // registered again.
                     let observer_entities = temporary_observer_generators
                         .iter()
-                        .map(|generator| generator.generate(&mut ec))
+                        .enumerate()
+                        .map(|(index, generator)| {
+                            let signature = generator.signature();
+
+                            match temporary_observer_entities.get(index) {
+                                Some((previous_signature, observer_entity))
+                                    if *previous_signature == signature =>
+                                {
+                                    generator.regenerate(&mut ec, *observer_entity);
+                                    (signature, *observer_entity)
+                                }
+                                _ => (signature, generator.generate(&mut ec)),
+                            }
+                        })
                         .collect::<Vec<_>>();
 
+                    for (index, (signature, observer_entity)) in
+                        temporary_observer_entities.iter().enumerate()
+                    {
+                        let is_reused = observer_entities
+                            .get(index)
+                            .is_some_and(|(new_signature, _)| new_signature == signature);
+
+                        if is_reused {
+                            continue;
+                        }
+
+                        let Some(observer_ec) = commands.get_entity(*observer_entity) else {
+                            continue;
+                        };
+
+                        observer_ec.try_despawn_recursive();
+                    }
+
                     state.set_unchanged(&temporary_observers, observer_entities);
                 },
             ),
         );
 
-        self.modifier.children.clone()
+        let Some(tooltip) = &self.modifier.tooltip else {
+            return self.modifier.children.clone();
+        };
+
+        // The tooltip is composed next to the children, and only once the entity has been spawned.
+        let tooltip_host = spawned_entity.map(|target| TooltipHost {
+            tooltip: tooltip.clone(),
+            target,
+        });
+
+        DynCompose::new((self.modifier.children.clone(), tooltip_host))
     }
 
     fn decompose(&self, cx: &mut Scope) {
         let entity = cx.get_state_by_index::<Option<Entity>>(0);
+        let temporary_observers = cx.get_state_by_index::<Vec<(ObserverSignature, Entity)>>(3);
+        let retained_observers = cx.get_state_by_index::<Vec<Entity>>(4);
+
+        // Observers of the spawned entity are despawned together with it, but observers of other entities and global
+        // observers have to be despawned separately.
+        let observer_entities = temporary_observers
+            .iter()
+            .map(|(_, observer_entity)| observer_entity)
+            .chain(retained_observers.iter())
+            .copied()
+            .collect::<Vec<_>>();
 
         if let Some(entity) = *entity {
+            let despawn_transition = self.modifier.despawn_transition.clone();
+            let despawn_policy = self.modifier.despawn_policy.clone().unwrap_or_default();
+            let is_attached = self.attach_to.is_some();
+
             cx.use_system_once(move |mut commands: Commands| {
-                let Some(ec) = commands.get_entity(entity) else {
+                for observer_entity in observer_entities.iter() {
+                    let Some(observer_ec) = commands.get_entity(*observer_entity) else {
+                        continue;
+                    };
+
+                    observer_ec.try_despawn_recursive();
+                }
+
+                let Some(mut ec) = commands.get_entity(entity) else {
                     return;
                 };
-                ec.try_despawn_recursive();
+
+                if is_attached {
+                    ec.queue(remove_attached_components);
+                    return;
+                }
+
+                let Some(despawn_transition) = &despawn_transition else {
+                    despawn_policy.apply(&mut ec);
+                    return;
+                };
+
+                (despawn_transition.on_start)(&mut ec);
+
+                ec.remove::<SpawnComposable>().try_insert((
+                    Despawning {
+                        timer: Timer::new(despawn_transition.duration, TimerMode::Once),
+                        ticked_at: None,
+                        policy: despawn_policy.clone(),
+                    },
+                    PickingBehavior::IGNORE,
+                ));
             });
         }
     }
@@ -134,9 +335,204 @@ impl<B: Bundle + Clone> Compose for Spawn<B> {
     }
 }
 
+impl Scope<'_> {
+    /// Spawns an auxiliary entity that is owned by the composable, like a sound emitter or a tween controller. Unlike
+    /// with [`Spawn`], the entity isn't part of the hierarchy of the composable, and the bundle is only inserted when
+    /// the composable is first composed. The entity is despawned, with its children, when the composable is decomposed.
+    ///
+    /// The returned state is `None` until the entity has been spawned, which happens before the next recomposition.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let emitter = cx.use_entity((AudioPlayer::new(music), PlaybackSettings::LOOP));
+    ///
+    /// if let Some(emitter) = *emitter {
+    ///     // Control the emitter from an observer or a system.
+    /// }
+    /// ```
+    pub fn use_entity<B: Bundle>(&mut self, bundle: B) -> State<Option<Entity>> {
+        let entity = self.use_state(None);
+        let entity_id: TypedStateId<Option<Entity>> = entity.get_typed_id();
+        let mut bundle = Some(bundle);
+
+        self.use_system_once(move |mut commands: Commands, mut state: SetState| {
+            let Some(bundle) = bundle.take() else {
+                return;
+            };
+
+            state.set(entity_id, Some(commands.spawn(bundle).id()));
+        });
+
+        // The entity is read when the composable is decomposed rather than captured, since it may have been spawned
+        // after the last recomposition.
+        self.use_unmount_system_once(move |state: ReadState, mut commands: Commands| {
+            let Some(entity) = state.get(entity_id).and_then(|entity| *entity) else {
+                return;
+            };
+
+            if let Some(ec) = commands.get_entity(entity) {
+                ec.try_despawn_recursive();
+            }
+        });
+
+        entity
+    }
+}
+
 #[derive(Component, Debug)]
 pub struct SpawnComposable(ScopeId);
 
+/// The components that an entity had before it was attached to a [`Spawn`]. See [`Spawn::attach`].
+#[derive(Component)]
+pub(crate) struct AttachedComponents(Vec<ComponentId>);
+
+/// Sets the fields of the `PickingBehavior` of the entity that were set with
+/// [`pickable`](crate::modify::ModifyFunctions::pickable) and
+/// [`block_lower`](crate::modify::ModifyFunctions::block_lower), and leaves the other fields as they are.
+fn override_picking_behavior(
+    entity: &mut EntityCommands,
+    pickable: Option<bool>,
+    block_lower: Option<bool>,
+) {
+    entity.queue(move |entity: Entity, world: &mut World| {
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            return;
+        };
+
+        let mut behavior = entity.get::<PickingBehavior>().cloned().unwrap_or_default();
+
+        if let Some(pickable) = pickable {
+            behavior.is_hoverable = pickable;
+        }
+
+        if let Some(block_lower) = block_lower {
+            behavior.should_block_lower = block_lower;
+        }
+
+        if entity.get::<PickingBehavior>() != Some(&behavior) {
+            entity.insert(behavior);
+        }
+    });
+}
+
+fn record_attached_components(mut entity: EntityWorldMut) {
+    let components = entity.archetype().components().collect();
+    entity.insert(AttachedComponents(components));
+}
+
+fn remove_attached_components(mut entity: EntityWorldMut) {
+    let Some(AttachedComponents(components)) = entity.take::<AttachedComponents>() else {
+        return;
+    };
+
+    // The hierarchy is managed by the owner of the entity and by the despawned children, so it is left alone.
+    let hierarchy = [
+        entity.world().component_id::<Parent>(),
+        entity.world().component_id::<Children>(),
+    ];
+
+    let added_components = entity
+        .archetype()
+        .components()
+        .filter(|component| !components.contains(component))
+        .filter(|component| !hierarchy.contains(&Some(*component)))
+        .collect::<Vec<_>>();
+
+    for component in added_components {
+        entity.remove_by_id(component);
+    }
+}
+
+/// Determines how the spawned entity is despawned when its composable is decomposed. See
+/// [`despawn_policy`](crate::modify::ModifyFunctions::despawn_policy).
+#[derive(Clone, Default)]
+pub enum DespawnPolicy {
+    /// The entity is despawned together with all of its descendants.
+    #[default]
+    Recursive,
+    /// The children of the entity are detached from it before it is despawned, so that children that were added
+    /// outside of the composition, like entities that were reparented to it by other systems, survive as root entities.
+    /// Composed children are still despawned when their own composables are decomposed.
+    KeepChildren,
+    /// The given function is called instead of despawning the entity, and is responsible for cleaning it up.
+    Custom(Arc<dyn Fn(&mut EntityCommands) + Send + Sync>),
+}
+
+impl DespawnPolicy {
+    /// Creates a policy that calls the given function instead of despawning the entity.
+    pub fn custom(cleanup: impl Fn(&mut EntityCommands) + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(cleanup))
+    }
+
+    fn apply(&self, ec: &mut EntityCommands) {
+        match self {
+            DespawnPolicy::Recursive => ec.reborrow().try_despawn_recursive(),
+            DespawnPolicy::KeepChildren => ec.clear_children().reborrow().try_despawn_recursive(),
+            DespawnPolicy::Custom(cleanup) => cleanup(ec),
+        }
+    }
+}
+
+/// A component that is added to entities that are about to be despawned after their despawn transition. See
+/// [`on_despawn_transition`](crate::modify::ModifyFunctions::on_despawn_transition).
+#[derive(Component)]
+pub struct Despawning {
+    timer: Timer,
+    /// The elapsed time of the app when the timer was last ticked. The timer is ticked in `PreUpdate` and in every
+    /// [`RecomposeFlush`](crate::RecomposeFlush), so it's ticked by the time since then rather than by the frame time.
+    ticked_at: Option<Duration>,
+    policy: DespawnPolicy,
+}
+
+impl Despawning {
+    /// Returns the progress of the despawn transition, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f32 {
+        self.timer.fraction()
+    }
+
+    /// Returns the time elapsed since the despawn transition started.
+    pub fn elapsed(&self) -> Duration {
+        self.timer.elapsed()
+    }
+
+    /// Returns the duration of the despawn transition.
+    pub fn duration(&self) -> Duration {
+        self.timer.duration()
+    }
+}
+
+pub(crate) fn despawn_after_transition(
+    mut commands: Commands,
+    time: Option<Res<Time>>,
+    mut despawning: Query<(Entity, &mut Despawning)>,
+) {
+    for (entity, mut despawning) in despawning.iter_mut() {
+        if let Some(time) = &time {
+            let delta = match despawning.ticked_at {
+                Some(ticked_at) => time.elapsed().saturating_sub(ticked_at),
+                None => time.delta(),
+            };
+
+            despawning.ticked_at = Some(time.elapsed());
+            despawning.timer.tick(delta);
+        }
+
+        // Without a time source there is nothing to wait for, so we despawn the entity right away.
+        if time.is_some() && !despawning.timer.finished() {
+            continue;
+        }
+
+        let Some(mut ec) = commands.get_entity(entity) else {
+            continue;
+        };
+
+        // The marker is removed first, since entities that survive a custom policy would otherwise be cleaned up again
+        // every frame.
+        ec.remove::<Despawning>();
+        despawning.policy.apply(&mut ec);
+    }
+}
+
 pub fn update_spawn_composables(
     mut commands: Commands,
     mut state: SetState,
@@ -0,0 +1,213 @@
+use crate::{
+    dyn_compose::DynCompose,
+    modify::{Modifier, Modify, ModifyFunctions},
+    state::SetState,
+    Compose, Scope,
+};
+use bevy_color::Color;
+use bevy_ecs::{observer::Trigger, system::Query};
+use bevy_hierarchy::Parent;
+use bevy_picking::events::{Drag, Pointer};
+use bevy_ui::{BackgroundColor, ComputedNode, FlexDirection, Node, Overflow, Val};
+
+/// The direction in which the panes of a [`SplitPane`] are laid out.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SplitDirection {
+    /// The panes are laid out next to each other, with a vertical divider between them.
+    Horizontal,
+    /// The panes are laid out on top of each other, with a horizontal divider between them.
+    Vertical,
+}
+
+/// A composable that lays out two children next to each other (or on top of each other), separated by a divider that
+/// can be dragged to resize them. The ratio between the panes is kept in a state, so it persists when the split pane is
+/// recomposed.
+///
+/// # Example
+/// ```ignore
+/// SplitPane::horizontal(hierarchy, inspector)
+///     .ratio(0.25)
+///     .min_sizes(100.0, 200.0)
+/// ```
+#[derive(Clone)]
+pub struct SplitPane {
+    direction: SplitDirection,
+    first: DynCompose,
+    second: DynCompose,
+    ratio: f32,
+    min_first: f32,
+    min_second: f32,
+    divider_size: f32,
+    divider_color: Color,
+    modifier: Modifier,
+}
+
+impl SplitPane {
+    /// Creates a new split pane with the given direction and children.
+    pub fn new(
+        direction: SplitDirection,
+        first: impl Compose + 'static,
+        second: impl Compose + 'static,
+    ) -> Self {
+        Self {
+            direction,
+            first: DynCompose::new(first),
+            second: DynCompose::new(second),
+            ratio: 0.5,
+            min_first: 0.0,
+            min_second: 0.0,
+            divider_size: 4.0,
+            divider_color: Color::srgb(0.3, 0.3, 0.3),
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Creates a new split pane with the children laid out next to each other.
+    pub fn horizontal(first: impl Compose + 'static, second: impl Compose + 'static) -> Self {
+        Self::new(SplitDirection::Horizontal, first, second)
+    }
+
+    /// Creates a new split pane with the children laid out on top of each other.
+    pub fn vertical(first: impl Compose + 'static, second: impl Compose + 'static) -> Self {
+        Self::new(SplitDirection::Vertical, first, second)
+    }
+
+    /// Sets the initial ratio of the size of the first pane to the total size, between `0.0` and `1.0`. Changing this
+    /// value after the split pane is composed has no effect, since the ratio is then controlled by the divider.
+    pub fn ratio(mut self, ratio: f32) -> Self {
+        self.ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the minimum sizes of the first and second pane, in logical pixels.
+    pub fn min_sizes(mut self, min_first: f32, min_second: f32) -> Self {
+        self.min_first = min_first;
+        self.min_second = min_second;
+        self
+    }
+
+    /// Sets the thickness of the divider, in logical pixels.
+    pub fn divider_size(mut self, divider_size: f32) -> Self {
+        self.divider_size = divider_size;
+        self
+    }
+
+    /// Sets the color of the divider.
+    pub fn divider_color(mut self, color: impl Into<Color>) -> Self {
+        self.divider_color = color.into();
+        self
+    }
+}
+
+impl Modify for SplitPane {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for SplitPane {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let ratio = cx.use_widget_state(self.ratio);
+        let ratio_id = ratio.get_typed_id();
+        let layout_direction = cx.use_layout_direction();
+        let sign = layout_direction.sign();
+
+        let direction = self.direction;
+        let divider_size = self.divider_size;
+        let min_first = self.min_first;
+        let min_second = self.min_second;
+
+        let is_horizontal = direction == SplitDirection::Horizontal;
+
+        let pane = |grow: f32, min_size: f32| Node {
+            flex_grow: grow,
+            flex_shrink: 1.0,
+            flex_basis: Val::Px(0.0),
+            min_width: if is_horizontal {
+                Val::Px(min_size)
+            } else {
+                Val::Auto
+            },
+            min_height: if is_horizontal {
+                Val::Auto
+            } else {
+                Val::Px(min_size)
+            },
+            overflow: Overflow::clip(),
+            ..Default::default()
+        };
+
+        let divider = (
+            Node {
+                width: if is_horizontal {
+                    Val::Px(divider_size)
+                } else {
+                    Val::Percent(100.0)
+                },
+                height: if is_horizontal {
+                    Val::Percent(100.0)
+                } else {
+                    Val::Px(divider_size)
+                },
+                flex_shrink: 0.0,
+                ..Default::default()
+            },
+            BackgroundColor(self.divider_color),
+        )
+            .observe(
+                move |trigger: Trigger<Pointer<Drag>>,
+                      parents: Query<&Parent>,
+                      nodes: Query<&ComputedNode>,
+                      mut state: SetState| {
+                    let Ok(parent) = parents.get(trigger.entity()) else {
+                        return;
+                    };
+
+                    let Ok(node) = nodes.get(parent.get()) else {
+                        return;
+                    };
+
+                    let size = node.size() * node.inverse_scale_factor();
+
+                    let (length, delta) = match direction {
+                        SplitDirection::Horizontal => (size.x, trigger.delta.x * sign),
+                        SplitDirection::Vertical => (size.y, trigger.delta.y),
+                    };
+
+                    let available = length - divider_size;
+
+                    if available <= 0.0 {
+                        return;
+                    }
+
+                    let min_ratio = (min_first / available).min(1.0);
+                    let max_ratio = (1.0 - min_second / available).max(min_ratio);
+
+                    state.modify(ratio_id, move |ratio| {
+                        (ratio + delta / available).clamp(min_ratio, max_ratio)
+                    });
+                },
+            );
+
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: if is_horizontal {
+                layout_direction.row()
+            } else {
+                FlexDirection::Column
+            },
+            ..Default::default()
+        }
+        .children((
+            pane(*ratio, min_first).children(self.first.clone()),
+            divider,
+            pane(1.0 - *ratio, min_second).children(self.second.clone()),
+        ))
+        .use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("SplitPane")
+    }
+}
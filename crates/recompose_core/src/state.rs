@@ -1,12 +1,57 @@
-use bevy_ecs::system::{ResMut, Resource, SystemParam};
+#[cfg(feature = "session")]
+use crate::session::StateCodec;
+use crate::{scope::ScopeId, Root};
+use bevy_ecs::system::{Query, ResMut, Resource, SystemParam};
 use paste::paste;
-use std::{any::Any, collections::HashMap, marker::PhantomData, ops::Deref, sync::Arc};
+use std::{
+    any::Any,
+    collections::HashMap,
+    error::Error,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Add, Deref},
+    sync::Arc,
+};
 
 type ArcAny = Arc<dyn Any + Send + Sync>;
 
 pub(crate) enum StateSetterAction {
     Set(ArcAny, bool),
     Modify(Box<dyn (Fn(ArcAny) -> (ArcAny, bool)) + Send + Sync>),
+    /// Sets the state back to its initial value.
+    Reset,
+}
+
+impl StateSetterAction {
+    fn set<T: Send + Sync + 'static>(value: T, should_change: bool) -> Self {
+        Self::Set(Arc::new(value), should_change)
+    }
+
+    pub(crate) fn set_neq<T: PartialEq + Send + Sync + 'static>(value: T) -> Self {
+        let value = Arc::new(value);
+
+        Self::Modify(Box::new(move |input| {
+            let has_changed = *input.downcast_ref::<T>().unwrap() != *value;
+
+            // When the value hasn't changed, we keep the existing value to avoid an unnecessary allocation.
+            match has_changed {
+                true => (value.clone(), true),
+                false => (input, false),
+            }
+        }))
+    }
+
+    fn modify<T: Send + Sync + 'static>(
+        value_fn: impl (Fn(&T) -> T) + Send + Sync + 'static,
+        should_change: bool,
+    ) -> Self {
+        Self::Modify(Box::new(move |input| {
+            let input = input.downcast_ref::<T>().unwrap();
+
+            (Arc::new((value_fn)(input)), should_change)
+        }))
+    }
 }
 
 #[derive(Resource, Default)]
@@ -22,40 +67,27 @@ pub struct SetState<'w> {
 impl SetState<'_> {
     /// Sets the state value.
     pub fn set<T: Send + Sync + 'static>(&mut self, state: impl GetStateId<T>, value: T) {
-        self.setter.queued.insert(
-            state.get_id(),
-            StateSetterAction::Set(Arc::new(value), true),
-        );
+        self.setter
+            .queued
+            .insert(state.get_id(), StateSetterAction::set(value, true));
     }
 
     /// Sets the state value only if it differs from the previous value.
-    pub fn set_neq<T: PartialEq + Clone + Send + Sync + 'static>(
+    pub fn set_neq<T: PartialEq + Send + Sync + 'static>(
         &mut self,
         state: impl GetStateId<T>,
         value: T,
     ) {
-        self.setter.queued.insert(
-            state.get_id(),
-            StateSetterAction::Modify(Box::new(move |input| {
-                let input = input.downcast_ref::<T>().unwrap();
-                let has_changed = *input != value;
-
-                let new_value = match has_changed {
-                    true => value.clone(),
-                    false => input.clone(),
-                };
-
-                (Arc::new(new_value), has_changed)
-            })),
-        );
+        self.setter
+            .queued
+            .insert(state.get_id(), StateSetterAction::set_neq(value));
     }
 
     /// Sets the state value, but does not trigger a recompose.
     pub fn set_unchanged<T: Send + Sync + 'static>(&mut self, state: impl GetStateId<T>, value: T) {
-        self.setter.queued.insert(
-            state.get_id(),
-            StateSetterAction::Set(Arc::new(value), false),
-        );
+        self.setter
+            .queued
+            .insert(state.get_id(), StateSetterAction::set(value, false));
     }
 
     /// Modifies the state value based on the existing value.
@@ -64,14 +96,9 @@ impl SetState<'_> {
         state: impl GetStateId<T>,
         value_fn: impl (Fn(&T) -> T) + Send + Sync + 'static,
     ) {
-        self.setter.queued.insert(
-            state.get_id(),
-            StateSetterAction::Modify(Box::new(move |input| {
-                let input = input.downcast_ref::<T>().unwrap();
-
-                (Arc::new((value_fn)(input)), true)
-            })),
-        );
+        self.setter
+            .queued
+            .insert(state.get_id(), StateSetterAction::modify(value_fn, true));
     }
 
     /// Modifies the state value, but does not trigger a recompose.
@@ -80,14 +107,225 @@ impl SetState<'_> {
         state: impl GetStateId<T>,
         value_fn: impl (Fn(&T) -> T) + Send + Sync + 'static,
     ) {
-        self.setter.queued.insert(
-            state.get_id(),
-            StateSetterAction::Modify(Box::new(move |input| {
-                let input = input.downcast_ref::<T>().unwrap();
+        self.setter
+            .queued
+            .insert(state.get_id(), StateSetterAction::modify(value_fn, false));
+    }
 
-                (Arc::new((value_fn)(input)), false)
-            })),
-        );
+    /// Modifies the state value based on the value that the action already queued for the state produces, instead of
+    /// replacing that action. This keeps all modifications that are queued for a state within the same frame. A queued
+    /// reset is replaced, since the initial value isn't known until the action is applied.
+    pub(crate) fn modify_after_queued<T: Send + Sync + 'static>(
+        &mut self,
+        state: impl GetStateId<T>,
+        value_fn: impl (Fn(&T) -> T) + Send + Sync + 'static,
+    ) {
+        let state_id = state.get_id();
+
+        let action = match self.setter.queued.remove(&state_id) {
+            Some(StateSetterAction::Set(value, _)) => {
+                StateSetterAction::Modify(Box::new(move |_| {
+                    let value = value.downcast_ref::<T>().unwrap();
+                    (Arc::new((value_fn)(value)), true)
+                }))
+            }
+            Some(StateSetterAction::Modify(queued_fn)) => {
+                StateSetterAction::Modify(Box::new(move |input| {
+                    let (value, _) = (queued_fn)(input);
+                    let value = value.downcast_ref::<T>().unwrap();
+                    (Arc::new((value_fn)(value)), true)
+                }))
+            }
+            Some(StateSetterAction::Reset) | None => StateSetterAction::modify(value_fn, true),
+        };
+
+        self.setter.queued.insert(state_id, action);
+    }
+
+    /// Resets the state to its initial value, the value it was created with.
+    pub fn reset<T: Send + Sync + 'static>(&mut self, state: impl GetStateId<T>) {
+        self.setter
+            .queued
+            .insert(state.get_id(), StateSetterAction::Reset);
+    }
+
+    /// Flips the value of a boolean state. Like the other helpers below, it builds on the action already queued for the
+    /// state, so several calls within the same frame stack instead of replacing each other.
+    pub fn toggle(&mut self, state: impl GetStateId<bool>) {
+        self.modify_after_queued(state, |value| !value);
+    }
+
+    /// Adds the given delta to the value of a numeric state. Deltas added within the same frame stack.
+    pub fn increment<T: Add<Output = T> + Copy + Send + Sync + 'static>(
+        &mut self,
+        state: impl GetStateId<T>,
+        delta: T,
+    ) {
+        self.modify_after_queued(state, move |value| *value + delta);
+    }
+
+    /// Appends an item to the end of a `Vec` state. Items pushed within the same frame are all appended, in order.
+    pub fn push<T: Clone + Send + Sync + 'static>(
+        &mut self,
+        state: impl GetStateId<Vec<T>>,
+        item: T,
+    ) {
+        self.modify_after_queued(state, move |items| {
+            let mut items = items.clone();
+            items.push(item.clone());
+            items
+        });
+    }
+
+    /// Removes all items of a `Vec` state that match the given predicate, including items that were queued to be added
+    /// within the same frame.
+    pub fn remove_where<T: Clone + Send + Sync + 'static>(
+        &mut self,
+        state: impl GetStateId<Vec<T>>,
+        predicate: impl Fn(&T) -> bool + Send + Sync + 'static,
+    ) {
+        self.modify_after_queued(state, move |items| {
+            let mut items = items.clone();
+            items.retain(|item| !predicate(item));
+            items
+        });
+    }
+
+    /// Returns the value that the state will have after the queued action for the state is applied. If no action is
+    /// queued, the value of the given state is returned. This lets observers and systems make decisions based on what
+    /// the value is about to become, rather than the (possibly outdated) value they captured.
+    ///
+    /// Note that the given state is used as the "current" value the queued action is applied to, so a state captured
+    /// in an earlier recomposition may lead to a different result than the one that is eventually applied.
+    pub fn peek<T: Send + Sync + 'static>(&self, state: &State<T>) -> Arc<T> {
+        let Some(action) = self.setter.queued.get(&state.id) else {
+            return state.value.clone();
+        };
+
+        let value = match action {
+            StateSetterAction::Set(value, _) => value.clone(),
+            StateSetterAction::Modify(f) => f(state.value.clone()).0,
+            StateSetterAction::Reset => return state.initial.clone(),
+        };
+
+        value
+            .downcast::<T>()
+            .unwrap_or_else(|_| panic!("State value type mismatch."))
+    }
+
+    /// Sets multiple states at once. This is more efficient than setting the states one by one when many states are
+    /// set every frame, for example when mirroring resources into states from an external system. Setting the same
+    /// state multiple times within a batch only keeps the last action.
+    ///
+    /// # Example
+    /// ```ignore
+    /// fn mirror_player(player: Res<Player>, mut state: SetState) {
+    ///     state.batch(|batch| {
+    ///         batch.set_neq(HEALTH_ID, player.health);
+    ///         batch.set_neq(MANA_ID, player.mana);
+    ///     });
+    /// }
+    /// ```
+    pub fn batch(&mut self, f: impl FnOnce(&mut StateBatch)) {
+        let mut batch = StateBatch::default();
+        f(&mut batch);
+
+        self.setter.queued.reserve(batch.actions.len());
+        self.setter.queued.extend(batch.actions);
+    }
+}
+
+/// A system param for reading the current value of states from systems. The value reflects the state as of the last
+/// recomposition, which means that actions queued with [`SetState`] are not visible until they are applied.
+///
+/// # Example
+/// ```ignore
+/// fn log_count(count: ReadState) {
+///     if let Some(count) = count.get(COUNT_ID) {
+///         println!("Count: {}", *count);
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct ReadState<'w, 's> {
+    roots: Query<'w, 's, &'static Root>,
+}
+
+impl ReadState<'_, '_> {
+    /// Returns the state with the given id, or `None` if no composable uses the state.
+    pub fn get<T: Any + Send + Sync>(&self, state: impl GetStateId<T>) -> Option<State<T>> {
+        let id = state.get_id();
+
+        for root in self.roots.iter() {
+            let Some(scope) = &root.scope else {
+                continue;
+            };
+
+            let mut scopes = vec![scope];
+
+            while let Some(scope) = scopes.pop() {
+                if let Some(dyn_state) = scope.find_state(id) {
+                    return Some(dyn_state.to_state());
+                }
+
+                scopes.extend(scope.children.iter());
+            }
+        }
+
+        None
+    }
+}
+
+/// A collection of state actions that are queued at once. See [`SetState::batch`].
+#[derive(Default)]
+pub struct StateBatch {
+    actions: HashMap<StateId, StateSetterAction>,
+}
+
+impl StateBatch {
+    /// Sets the state value.
+    pub fn set<T: Send + Sync + 'static>(
+        &mut self,
+        state: impl GetStateId<T>,
+        value: T,
+    ) -> &mut Self {
+        self.actions
+            .insert(state.get_id(), StateSetterAction::set(value, true));
+        self
+    }
+
+    /// Sets the state value only if it differs from the previous value. This is the preferred way of mirroring values
+    /// that rarely change, since it avoids recomposing the scopes that use them.
+    pub fn set_neq<T: PartialEq + Send + Sync + 'static>(
+        &mut self,
+        state: impl GetStateId<T>,
+        value: T,
+    ) -> &mut Self {
+        self.actions
+            .insert(state.get_id(), StateSetterAction::set_neq(value));
+        self
+    }
+
+    /// Sets the state value, but does not trigger a recompose.
+    pub fn set_unchanged<T: Send + Sync + 'static>(
+        &mut self,
+        state: impl GetStateId<T>,
+        value: T,
+    ) -> &mut Self {
+        self.actions
+            .insert(state.get_id(), StateSetterAction::set(value, false));
+        self
+    }
+
+    /// Modifies the state value based on the existing value.
+    pub fn modify<T: Send + Sync + 'static>(
+        &mut self,
+        state: impl GetStateId<T>,
+        value_fn: impl (Fn(&T) -> T) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.actions
+            .insert(state.get_id(), StateSetterAction::modify(value_fn, true));
+        self
     }
 }
 
@@ -95,6 +333,8 @@ impl SetState<'_> {
 pub enum StateId {
     Generated(usize),
     Manual(usize),
+    /// Refers to the state at the given index of the given scope. See [`ScopeHandle`](crate::scope::ScopeHandle).
+    Indexed(ScopeId, usize),
 }
 
 #[derive(Clone, Copy)]
@@ -108,20 +348,77 @@ pub(crate) struct DynState {
     pub(crate) id: StateId,
     pub(crate) changed: StateChanged,
     pub(crate) value: Arc<dyn Any + Send + Sync>,
+    /// The value the state was created with. See [`State::initial`].
+    pub(crate) initial: Arc<dyn Any + Send + Sync>,
+    /// How the value is saved to and restored from a [`UiSession`](crate::session::UiSession). Only states created
+    /// with [`use_saved_state`](crate::scope::Scope::use_saved_state) have a codec.
+    #[cfg(feature = "session")]
+    pub(crate) codec: Option<StateCodec>,
 }
 
 impl DynState {
     pub(crate) fn to_state<T: Any + Send + Sync>(&self) -> State<T> {
-        self.value
-            .clone()
-            .downcast::<T>()
-            .map(|value| State {
-                id: self.id,
-                changed: self.changed,
-                value,
-            })
+        self.try_to_state()
             .unwrap_or_else(|_| panic!("State value type mismatch."))
     }
+
+    pub(crate) fn try_to_state<T: Any + Send + Sync>(&self) -> Result<State<T>, StateError> {
+        let (Ok(value), Ok(initial)) = (
+            self.value.clone().downcast::<T>(),
+            self.initial.clone().downcast::<T>(),
+        ) else {
+            return Err(StateError::TypeMismatch);
+        };
+
+        Ok(State {
+            id: self.id,
+            changed: self.changed,
+            value,
+            initial,
+        })
+    }
+}
+
+/// An error that occurs when a state can't be accessed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StateError {
+    /// The state doesn't exist.
+    NotFound,
+    /// The state has a value of a different type. This usually means that hooks were called conditionally, so the
+    /// states of the scope ended up in a different order than in the previous composition.
+    TypeMismatch,
+}
+
+impl Display for StateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateError::NotFound => write!(f, "State not found."),
+            StateError::TypeMismatch => write!(f, "State value type mismatch."),
+        }
+    }
+}
+
+impl Error for StateError {}
+
+/// What happens when a state is accessed with the wrong type, which usually means that hooks were called conditionally.
+/// Insert the resource to change the mode. It is read at the start of every frame, so it can be changed at runtime.
+/// Accessing a state that doesn't exist, for example with an id of a decomposed scope, is handled the same way.
+///
+/// # Example
+/// ```ignore
+/// App::new()
+///     .add_plugins((DefaultPlugins, RecomposePlugin))
+///     .insert_resource(StateErrorMode::Poison)
+/// ```
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum StateErrorMode {
+    /// Panics, which makes the mistake hard to miss during development.
+    #[default]
+    Panic,
+    /// Logs a warning with the name of the composable and the index of the state, and poisons the scope instead of
+    /// panicking. The mismatched states of a poisoned scope are created anew from their initial values, and the scope
+    /// composes nothing until it composes without errors again. Setting a state with the wrong type is ignored.
+    Poison,
 }
 
 #[derive(Clone)]
@@ -129,6 +426,7 @@ pub struct State<T> {
     pub(crate) id: StateId,
     pub(crate) changed: StateChanged,
     pub(crate) value: Arc<T>,
+    pub(crate) initial: Arc<T>,
 }
 
 impl<T> Deref for State<T> {
@@ -152,6 +450,78 @@ impl<T> State<T> {
     pub fn get_typed_id(&self) -> TypedStateId<T> {
         TypedStateId::from_state_id(self.id)
     }
+
+    /// Returns the value the state was created with. See [`reset_state`](crate::Scope::reset_state).
+    pub fn initial(&self) -> &T {
+        &self.initial
+    }
+}
+
+impl<T: Any + Send + Sync> State<T> {
+    /// Creates a [`StateView`] that points to a part of the state value. This lets us pass (parts of) a state to child
+    /// composables without cloning the underlying value.
+    pub fn view<U: ?Sized + 'static>(&self, project: fn(&T) -> &U) -> StateView<U> {
+        let owner: ArcAny = self.value.clone();
+
+        StateView {
+            owner,
+            project: Arc::new(project_fn(move |value| {
+                project(value.downcast_ref::<T>().unwrap())
+            })),
+        }
+    }
+}
+
+type ProjectFn<U> = dyn (for<'a> Fn(&'a (dyn Any + Send + Sync)) -> &'a U) + Send + Sync;
+
+/// Forces the closure to have a higher-ranked signature, which closures are unable to infer by themselves.
+fn project_fn<U: ?Sized, F>(f: F) -> F
+where
+    F: for<'a> Fn(&'a (dyn Any + Send + Sync)) -> &'a U,
+{
+    f
+}
+
+/// A cheaply clonable, read-only view into (a part of) a state value.
+///
+/// Composables are stored in their scopes so that they can be recomposed later, which means that they can't borrow
+/// from their parent's states. Instead, a `StateView` shares the ownership of the state value, which lets props like
+/// labels and lists be passed to children without cloning `String`s or `Vec`s on every recomposition.
+///
+/// # Example
+/// ```ignore
+/// let todos = cx.use_state(vec![String::from("Buy milk")]);
+///
+/// TodoList {
+///     todos: todos.view(Vec::as_slice),
+/// }
+/// ```
+pub struct StateView<U: ?Sized + 'static> {
+    owner: ArcAny,
+    project: Arc<ProjectFn<U>>,
+}
+
+impl<U: ?Sized + 'static> Clone for StateView<U> {
+    fn clone(&self) -> Self {
+        Self {
+            owner: self.owner.clone(),
+            project: self.project.clone(),
+        }
+    }
+}
+
+impl<U: ?Sized + 'static> Deref for StateView<U> {
+    type Target = U;
+
+    fn deref(&self) -> &Self::Target {
+        (self.project)(self.owner.as_ref())
+    }
+}
+
+impl<T: Any + Send + Sync> From<State<T>> for StateView<T> {
+    fn from(state: State<T>) -> Self {
+        state.view(|value| value)
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -196,6 +566,129 @@ impl<T> TypedStateId<T> {
             _marker: PhantomData,
         }
     }
+
+    /// Creates an id from the hash of the given source location. Used by [`typed_state_id`](crate::typed_state_id).
+    #[doc(hidden)]
+    pub const fn from_location(location: &str) -> Self {
+        // FNV-1a, since the id has to be computed in a const context.
+        Self::new(fnv1a(FNV_OFFSET_BASIS, location.as_bytes()) as usize)
+    }
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+const fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    let mut index = 0;
+
+    while index < bytes.len() {
+        hash ^= bytes[index] as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+        index += 1;
+    }
+
+    hash
+}
+
+/// Hashes the value with FNV-1a. Unlike the algorithm of `DefaultHasher`, which may change between Rust releases, the
+/// algorithm is fixed, so the hash can be part of keys that are saved, like the
+/// [stable paths](crate::scope::StablePath) of scopes.
+pub(crate) fn stable_hash(value: &impl Hash) -> u64 {
+    let mut hasher = StableHasher(FNV_OFFSET_BASIS);
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+struct StableHasher(u64);
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 = fnv1a(self.0, bytes);
+    }
+}
+
+/// Creates a [`TypedStateId`] from the module path, line and column of the macro call. Unlike numbered ids, like
+/// `TypedStateId::new(0)`, the ids don't collide with the ids of other files.
+///
+/// # Example
+/// ```ignore
+/// const SCORE: TypedStateId<u32> = typed_state_id!();
+///
+/// let score = cx.use_state_with_id(SCORE, 0);
+/// ```
+#[macro_export]
+macro_rules! typed_state_id {
+    () => {
+        $crate::state::TypedStateId::from_location(concat!(
+            module_path!(),
+            ":",
+            line!(),
+            ":",
+            column!()
+        ))
+    };
+}
+
+#[cfg(debug_assertions)]
+struct ManualStateUse {
+    scope: ScopeId,
+    composable: String,
+    type_name: &'static str,
+}
+
+/// The scopes that use each manual state id, which is tracked in debug builds to warn about ids that are used by more
+/// than one scope. See [`use_state_with_id`](crate::scope::Scope::use_state_with_id).
+#[cfg(debug_assertions)]
+#[derive(Resource, Default)]
+pub(crate) struct ManualStateIds {
+    uses: HashMap<usize, Vec<ManualStateUse>>,
+}
+
+#[cfg(debug_assertions)]
+impl ManualStateIds {
+    pub(crate) fn register(
+        &mut self,
+        id: usize,
+        scope: ScopeId,
+        composable: String,
+        type_name: &'static str,
+    ) {
+        let uses = self.uses.entry(id).or_default();
+
+        if uses.iter().any(|state_use| state_use.scope == scope) {
+            return;
+        }
+
+        for other in uses.iter() {
+            bevy_log::warn!(
+                "State id {id} is used by both `{}` (with type `{}`) and `{composable}` (with type `{type_name}`), so \
+                their states overwrite each other. Manual state ids should be unique, see `typed_state_id!`.",
+                other.composable,
+                other.type_name,
+            );
+        }
+
+        uses.push(ManualStateUse {
+            scope,
+            composable,
+            type_name,
+        });
+    }
+
+    pub(crate) fn unregister(&mut self, id: usize, scope: ScopeId) {
+        let Some(uses) = self.uses.get_mut(&id) else {
+            return;
+        };
+
+        uses.retain(|state_use| state_use.scope != scope);
+
+        if uses.is_empty() {
+            self.uses.remove(&id);
+        }
+    }
 }
 
 pub trait GetStateId<T> {
@@ -277,3 +770,65 @@ impl_dependency!(0, 1, 2, 3, 4, 5, 6);
 impl_dependency!(0, 1, 2, 3, 4, 5, 6, 7);
 impl_dependency!(0, 1, 2, 3, 4, 5, 6, 7, 8);
 impl_dependency!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
+
+/// A trait for getting the value of a source state of a derived state.
+trait GetStateValue {
+    type Value;
+
+    fn get_state_value(&self) -> &Self::Value;
+}
+
+impl<T> GetStateValue for &State<T> {
+    type Value = T;
+
+    fn get_state_value(&self) -> &Self::Value {
+        &self.value
+    }
+}
+
+impl<T> GetStateValue for State<T> {
+    type Value = T;
+
+    fn get_state_value(&self) -> &Self::Value {
+        &self.value
+    }
+}
+
+/// The source states of a [derived state](crate::Scope::derive). This is implemented for single states and for tuples
+/// of up to ten states, with functions that take the values of the states as separate arguments.
+pub trait DeriveSources<F, O>: Dependency {
+    fn derive(&self, derive: &F) -> O;
+}
+
+impl<F: Fn(&D::Value) -> O, O, D: GetStateChanged + GetStateValue> DeriveSources<F, O> for D {
+    fn derive(&self, derive: &F) -> O {
+        derive(self.get_state_value())
+    }
+}
+
+macro_rules! impl_derive_sources {
+    ($($d:expr),*) => {
+        paste! {
+            impl<F, O, $([<D$d>]: GetStateChanged + GetStateValue),*> DeriveSources<F, O> for ($([<D$d>]),*)
+            where
+                F: Fn($(&[<D$d>]::Value),*) -> O,
+            {
+                fn derive(&self, derive: &F) -> O {
+                    let ($([<d$d>]),*) = self;
+
+                    derive($([<d$d>].get_state_value()),*)
+                }
+            }
+        }
+    };
+}
+
+impl_derive_sources!(0, 1);
+impl_derive_sources!(0, 1, 2);
+impl_derive_sources!(0, 1, 2, 3);
+impl_derive_sources!(0, 1, 2, 3, 4);
+impl_derive_sources!(0, 1, 2, 3, 4, 5);
+impl_derive_sources!(0, 1, 2, 3, 4, 5, 6);
+impl_derive_sources!(0, 1, 2, 3, 4, 5, 6, 7);
+impl_derive_sources!(0, 1, 2, 3, 4, 5, 6, 7, 8);
+impl_derive_sources!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9);
@@ -0,0 +1,157 @@
+use crate::{
+    state::{GetStateId, State, StateSetter, StateSetterAction, TypedStateId},
+    Scope, StateChanged,
+};
+use bevy_ecs::{
+    change_detection::{DetectChanges, DetectChangesMut},
+    system::{Commands, ResMut, Resource},
+    world::World,
+};
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+};
+
+/// A resource that mirrors a state that was created with [`use_state_resource`](Scope::use_state_resource). Classic
+/// Bevy systems can read the state with `Res<StateMirror<T>>`, and change it with `ResMut<StateMirror<T>>`, which
+/// sets the state and recomposes its composable before the next recomposition.
+///
+/// There is one mirror per type, so only one composable should mirror a state of the same type at a time. The mirror
+/// stays in the world when the composable is decomposed, but it is no longer linked to a state.
+#[derive(Resource)]
+pub struct StateMirror<T> {
+    value: T,
+    state: Option<TypedStateId<T>>,
+}
+
+impl<T> StateMirror<T> {
+    /// Whether the mirror is linked to the state of a composed composable.
+    pub fn is_linked(&self) -> bool {
+        self.state.is_some()
+    }
+}
+
+impl<T> Deref for StateMirror<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for StateMirror<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+/// The functions that sync the mirrors of each type back into their states, keyed by the type of the mirror.
+#[derive(Resource, Default)]
+pub(crate) struct StateMirrors {
+    syncs: HashMap<TypeId, fn(&mut World)>,
+}
+
+pub(crate) fn sync_state_mirrors(world: &mut World) {
+    let syncs = world
+        .resource::<StateMirrors>()
+        .syncs
+        .values()
+        .copied()
+        .collect::<Vec<_>>();
+
+    for sync in syncs {
+        sync(world);
+    }
+}
+
+fn sync_state_mirror<T: Clone + PartialEq + Send + Sync + 'static>(world: &mut World) {
+    let Some(mirror) = world.get_resource_ref::<StateMirror<T>>() else {
+        return;
+    };
+
+    let Some(state) = mirror.state.filter(|_| mirror.is_changed()) else {
+        return;
+    };
+
+    let action = StateSetterAction::set_neq(mirror.value.clone());
+
+    world
+        .resource_mut::<StateSetter>()
+        .queued
+        .insert(state.get_id(), action);
+}
+
+impl Scope<'_> {
+    /// Creates a state that is mirrored into a [`StateMirror`] resource, so that it can be shared with classic Bevy
+    /// systems. Changes of the state are written to the resource after the recomposition, and changes of the resource
+    /// are set to the state before the next recomposition. When both change in the same frame, the state wins.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let volume = cx.use_state_resource(0.8_f32);
+    ///
+    /// Slider::new(*volume, 0.0..=1.0).bind_value(&volume)
+    ///
+    /// // In a regular system:
+    /// fn apply_volume(volume: Res<StateMirror<f32>>, mut global_volume: ResMut<GlobalVolume>) {
+    ///     if volume.is_changed() {
+    ///         global_volume.volume = Volume::new(**volume);
+    ///     }
+    /// }
+    /// ```
+    pub fn use_state_resource<T: Clone + PartialEq + Send + Sync + 'static>(
+        &mut self,
+        initial_value: T,
+    ) -> State<T> {
+        let state = self.use_state(initial_value);
+        let state_id = state.get_typed_id();
+
+        // The resource is only written when the state itself has changed, so that a recomposition for other reasons
+        // doesn't overwrite a value that was just set by a system.
+        if matches!(state.changed, StateChanged::Changed) {
+            let value = (*state).clone();
+
+            self.run_system(
+                move |mirror: Option<ResMut<StateMirror<T>>>,
+                      mut mirrors: ResMut<StateMirrors>,
+                      mut commands: Commands| {
+                    mirrors
+                        .syncs
+                        .entry(TypeId::of::<T>())
+                        .or_insert(sync_state_mirror::<T>);
+
+                    let Some(mut mirror) = mirror else {
+                        commands.insert_resource(StateMirror {
+                            value: value.clone(),
+                            state: Some(state_id),
+                        });
+                        return;
+                    };
+
+                    // Linking the mirror isn't a change of its value, so systems aren't notified about it.
+                    mirror.bypass_change_detection().state = Some(state_id);
+
+                    if mirror.value != value {
+                        mirror.value = value.clone();
+                    }
+                },
+            );
+        }
+
+        self.use_unmount_system_once(move |mirror: Option<ResMut<StateMirror<T>>>| {
+            let Some(mut mirror) = mirror else {
+                return;
+            };
+
+            if mirror
+                .state
+                .is_some_and(|state| state.get_id() == state_id.get_id())
+            {
+                mirror.bypass_change_detection().state = None;
+            }
+        });
+
+        state
+    }
+}
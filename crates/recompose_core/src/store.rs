@@ -0,0 +1,150 @@
+use crate::{
+    run_queued_systems, set_states,
+    state::{GetStateId, SetState, State, StateId, TypedStateId},
+    Scope,
+};
+use bevy_app::{App, PreUpdate};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    schedule::IntoSystemConfigs,
+    system::{Res, ResMut, Resource},
+};
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+
+/// A resource that holds app-global state that can be shared between composables of all roots. Composables subscribe to
+/// a part of the store with [`use_store_selector`](Scope::use_store_selector), and are only recomposed when the
+/// selected part changes.
+///
+/// The store is added to the app with [`insert_store`](StoreAppExtension::insert_store) and modified like any other
+/// resource, with `ResMut<Store<T>>`.
+#[derive(Resource, Default)]
+pub struct Store<T>(pub T);
+
+impl<T> Deref for Store<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Store<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+type SelectFn<T> = Box<dyn Fn(&T, &mut SetState) + Send + Sync>;
+
+struct StoreSubscription<T> {
+    select: SelectFn<T>,
+    initialized: bool,
+}
+
+#[derive(Resource)]
+struct StoreSubscriptions<T> {
+    subscriptions: HashMap<StateId, StoreSubscription<T>>,
+}
+
+impl<T> Default for StoreSubscriptions<T> {
+    fn default() -> Self {
+        Self {
+            subscriptions: HashMap::new(),
+        }
+    }
+}
+
+/// Extension trait for adding a [`Store`] to the app.
+pub trait StoreAppExtension {
+    /// Inserts a [`Store`] with the given value, replacing the value of a store of the same type that was added before.
+    fn insert_store<T: Send + Sync + 'static>(&mut self, value: T) -> &mut Self;
+
+    /// Inserts a [`Store`] with the default value, unless a store of the same type was added before.
+    fn init_store<T: Default + Send + Sync + 'static>(&mut self) -> &mut Self;
+}
+
+impl StoreAppExtension for App {
+    fn insert_store<T: Send + Sync + 'static>(&mut self, value: T) -> &mut Self {
+        self.insert_resource(Store(value));
+        add_store_subscriptions::<T>(self)
+    }
+
+    fn init_store<T: Default + Send + Sync + 'static>(&mut self) -> &mut Self {
+        self.init_resource::<Store<T>>();
+        add_store_subscriptions::<T>(self)
+    }
+}
+
+/// Adds the system that updates the selectors of the store, once per store type, so that adding a store again doesn't
+/// run its selectors twice.
+fn add_store_subscriptions<T: Send + Sync + 'static>(app: &mut App) -> &mut App {
+    if app.world().contains_resource::<StoreSubscriptions<T>>() {
+        return app;
+    }
+
+    app.init_resource::<StoreSubscriptions<T>>().add_systems(
+        PreUpdate,
+        update_store_subscriptions::<T>
+            .after(run_queued_systems)
+            .before(set_states),
+    )
+}
+
+fn update_store_subscriptions<T: Send + Sync + 'static>(
+    store: Res<Store<T>>,
+    mut subscriptions: ResMut<StoreSubscriptions<T>>,
+    mut state: SetState,
+) {
+    let has_changed = store.is_changed();
+
+    for subscription in subscriptions.subscriptions.values_mut() {
+        if !has_changed && subscription.initialized {
+            continue;
+        }
+
+        (subscription.select)(&store, &mut state);
+        subscription.initialized = true;
+    }
+}
+
+impl Scope<'_> {
+    /// Selects a part of the [`Store`] and returns it as a state. The composable is only recomposed when the selected
+    /// value changes. The state is `None` until the value is first selected, which happens before the next
+    /// recomposition.
+    pub fn use_store_selector<
+        T: Send + Sync + 'static,
+        S: PartialEq + Clone + Send + Sync + 'static,
+    >(
+        &mut self,
+        selector: impl Fn(&T) -> S + Send + Sync + 'static,
+    ) -> State<Option<S>> {
+        let selected = self.use_state(None);
+        let state_id = selected.get_id();
+        let typed_state_id: TypedStateId<Option<S>> = selected.get_typed_id();
+        let selector = Arc::new(selector);
+
+        self.use_system_once(move |mut subscriptions: ResMut<StoreSubscriptions<T>>| {
+            let selector = selector.clone();
+
+            subscriptions.subscriptions.insert(
+                state_id,
+                StoreSubscription {
+                    select: Box::new(move |store, state| {
+                        state.set_neq(typed_state_id, Some(selector(store)));
+                    }),
+                    initialized: false,
+                },
+            );
+        });
+
+        self.use_unmount_system_once(move |mut subscriptions: ResMut<StoreSubscriptions<T>>| {
+            subscriptions.subscriptions.remove(&state_id);
+        });
+
+        selected
+    }
+}
@@ -0,0 +1,335 @@
+use crate::{
+    layout_direction::justify_text,
+    modify::{Modifier, Modify, ModifyFunctions},
+    spawn::Spawn,
+    Compose, Scope,
+};
+use bevy_asset::Handle;
+use bevy_color::Color;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    system::{EntityCommands, Resource},
+    world::EntityWorldMut,
+};
+use bevy_hierarchy::{BuildChildren, Children, DespawnRecursiveExt};
+use bevy_text::{Font, TextColor, TextFont, TextSpan};
+use bevy_ui::widget::Text;
+use std::{fmt::Display, sync::Arc};
+
+/// The fonts that are used for the bold and italic spans of a [`StyledText`]. Bevy has no notion of font weights or
+/// styles, so each style needs its own font. Spans whose style has no font use the font of the text.
+///
+/// # Example
+/// ```ignore
+/// fn load_fonts(mut commands: Commands, server: Res<AssetServer>) {
+///     commands.insert_resource(TextFonts {
+///         bold: Some(server.load("fonts/Inter-Bold.ttf")),
+///         italic: Some(server.load("fonts/Inter-Italic.ttf")),
+///         ..default()
+///     });
+/// }
+/// ```
+#[derive(Resource, Clone, Default, Debug)]
+pub struct TextFonts {
+    pub bold: Option<Handle<Font>>,
+    pub italic: Option<Handle<Font>>,
+    pub bold_italic: Option<Handle<Font>>,
+}
+
+impl TextFonts {
+    /// Returns the font for the given style, if there is one.
+    fn get(&self, bold: bool, italic: bool) -> Option<Handle<Font>> {
+        match (bold, italic) {
+            (true, true) => self
+                .bold_italic
+                .clone()
+                .or_else(|| self.bold.clone())
+                .or_else(|| self.italic.clone()),
+            (true, false) => self.bold.clone(),
+            (false, true) => self.italic.clone(),
+            (false, false) => None,
+        }
+    }
+}
+
+/// A span of a [`StyledText`]. Properties that aren't set are inherited from the text.
+#[derive(Clone, PartialEq, Debug)]
+pub struct StyledSpan {
+    text: String,
+    bold: bool,
+    italic: bool,
+    font: Option<Handle<Font>>,
+    font_size: Option<f32>,
+    color: Option<Color>,
+}
+
+impl StyledSpan {
+    /// Creates a new span from the given value.
+    pub fn new(text: impl Display) -> Self {
+        Self {
+            text: text.to_string(),
+            bold: false,
+            italic: false,
+            font: None,
+            font_size: None,
+            color: None,
+        }
+    }
+
+    /// Makes the span bold. See [`TextFonts`].
+    pub fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Makes the span italic. See [`TextFonts`].
+    pub fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Sets the font of the span, which takes precedence over the bold and italic fonts.
+    pub fn font(mut self, font: Handle<Font>) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets the font size of the span.
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font_size = Some(font_size);
+        self
+    }
+
+    /// Sets the color of the span.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    fn resolve(&self, text_font: &TextFont, text_color: Color, fonts: &TextFonts) -> ResolvedSpan {
+        let font = self
+            .font
+            .clone()
+            .or_else(|| fonts.get(self.bold, self.italic))
+            .unwrap_or_else(|| text_font.font.clone());
+
+        ResolvedSpan {
+            text: self.text.clone(),
+            font,
+            font_size: self.font_size.unwrap_or(text_font.font_size),
+            color: self.color.unwrap_or(text_color),
+        }
+    }
+}
+
+impl<T: Display> From<T> for StyledSpan {
+    fn from(text: T) -> Self {
+        StyledSpan::new(text)
+    }
+}
+
+/// A composable that spawns a text node made up of several spans with different fonts, sizes and colors. The spans are
+/// spawned as Bevy text spans, and are only updated when they change, so the text isn't shaped again on every
+/// recomposition.
+///
+/// # Example
+/// ```ignore
+/// fn score(cx: &mut Scope) -> impl Compose {
+///     let score = cx.use_state(0);
+///
+///     StyledText::new()
+///         .text("Score: ")
+///         .bold(*score)
+///         .span(StyledSpan::new(" points").italic().color(Color::srgb(0.6, 0.6, 0.6)))
+///         .font_size(20.0)
+/// }
+/// ```
+#[derive(Clone)]
+pub struct StyledText {
+    spans: Vec<StyledSpan>,
+    font: TextFont,
+    color: TextColor,
+    modifier: Modifier,
+}
+
+impl StyledText {
+    /// Creates a new text without any spans.
+    pub fn new() -> Self {
+        Self {
+            spans: Vec::new(),
+            font: TextFont::default(),
+            color: TextColor::default(),
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Adds a span to the text.
+    pub fn span(mut self, span: impl Into<StyledSpan>) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// Adds a span with the style of the text.
+    pub fn text(self, text: impl Display) -> Self {
+        self.span(StyledSpan::new(text))
+    }
+
+    /// Adds a bold span.
+    pub fn bold(self, text: impl Display) -> Self {
+        self.span(StyledSpan::new(text).bold())
+    }
+
+    /// Adds an italic span.
+    pub fn italic(self, text: impl Display) -> Self {
+        self.span(StyledSpan::new(text).italic())
+    }
+
+    /// Sets the font size of the spans that don't set their own.
+    pub fn font_size(mut self, font_size: f32) -> Self {
+        self.font.font_size = font_size;
+        self
+    }
+
+    /// Sets the font of the spans that don't set their own.
+    pub fn font(mut self, font: TextFont) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the color of the spans that don't set their own.
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = TextColor(color.into());
+        self
+    }
+}
+
+impl Default for StyledText {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Modify for StyledText {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for StyledText {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let justify = cx.use_layout_direction().justify_text();
+        let spans = self.spans.clone();
+        let font = self.font.clone();
+        let color = self.color.0;
+
+        let mut spawn = Spawn::new(self.color);
+
+        spawn
+            .modifier
+            .bundle_modifiers
+            .push(Arc::new(move |entity: &mut EntityCommands| {
+                let spans = spans.clone();
+                let font = font.clone();
+
+                entity.queue(move |mut entity: EntityWorldMut| {
+                    justify_text(&mut entity, justify);
+
+                    let fonts = entity
+                        .world()
+                        .get_resource::<TextFonts>()
+                        .cloned()
+                        .unwrap_or_default();
+
+                    let content = StyledTextContent {
+                        font: font.font.clone(),
+                        font_size: font.font_size,
+                        spans: spans
+                            .iter()
+                            .map(|span| span.resolve(&font, color, &fonts))
+                            .collect(),
+                    };
+
+                    // Like for `TextLabel`, inserting the text components makes Bevy shape the text again, so they are
+                    // only inserted when the content has changed.
+                    if entity.get::<StyledTextContent>() == Some(&content) {
+                        return;
+                    }
+
+                    entity.insert((Text::default(), font));
+                    update_spans(&mut entity, &content.spans);
+                    entity.insert(content);
+                });
+            }));
+
+        spawn.use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("StyledText")
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+struct ResolvedSpan {
+    text: String,
+    font: Handle<Font>,
+    font_size: f32,
+    color: Color,
+}
+
+/// The resolved spans of a [`StyledText`] entity, used to skip updates that don't change the text.
+#[derive(Component, Clone, PartialEq, Debug)]
+pub(crate) struct StyledTextContent {
+    font: Handle<Font>,
+    font_size: f32,
+    spans: Vec<ResolvedSpan>,
+}
+
+/// Marks a text span entity that is managed by a [`StyledText`].
+#[derive(Component)]
+pub(crate) struct StyledTextSpan;
+
+/// Updates the span entities of the text, reusing the existing ones.
+fn update_spans(entity: &mut EntityWorldMut, spans: &[ResolvedSpan]) {
+    let text_entity = entity.id();
+
+    entity.world_scope(|world| {
+        let existing_spans = world
+            .get::<Children>(text_entity)
+            .map(|children| {
+                children
+                    .iter()
+                    .copied()
+                    .filter(|child| world.get::<StyledTextSpan>(*child).is_some())
+                    .collect::<Vec<Entity>>()
+            })
+            .unwrap_or_default();
+
+        for (index, span) in spans.iter().enumerate() {
+            let bundle = (
+                TextSpan::new(span.text.clone()),
+                TextFont {
+                    font: span.font.clone(),
+                    font_size: span.font_size,
+                    ..Default::default()
+                },
+                TextColor(span.color),
+            );
+
+            match existing_spans.get(index) {
+                Some(span_entity) => {
+                    world.entity_mut(*span_entity).insert(bundle);
+                }
+                None => {
+                    let span_entity = world.spawn((StyledTextSpan, bundle)).id();
+                    world.entity_mut(text_entity).add_child(span_entity);
+                }
+            }
+        }
+
+        for span_entity in existing_spans.iter().skip(spans.len()) {
+            world.entity_mut(*span_entity).despawn_recursive();
+        }
+    });
+}
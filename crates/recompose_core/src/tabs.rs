@@ -0,0 +1,203 @@
+use crate::{
+    binding::{BindValue, Binding, BoundSetter, ChangeHandler},
+    dyn_compose::DynCompose,
+    keyed::Keyed,
+    label::TextLabel,
+    modify::{Modifier, Modify, ModifyFunctions},
+    state::SetState,
+    Compose, Scope,
+};
+use bevy_color::{Color, Srgba};
+use bevy_ecs::observer::Trigger;
+use bevy_picking::events::{Click, Pointer};
+use bevy_ui::{BackgroundColor, BorderColor, FlexDirection, Node, UiRect, Val};
+use std::{fmt::Display, sync::Arc};
+
+#[derive(Clone)]
+struct Tab {
+    label: String,
+    content: DynCompose,
+}
+
+/// A composable that shows a row of tab headers, and the content of the selected tab below them. Only the content of
+/// the selected tab is composed, so the states of the other tabs are reset when they are hidden.
+///
+/// The selected index is controlled by the parent: it is passed in with [`selected`](Tabs::selected), and edits are
+/// reported through [`on_select`](Tabs::on_select). Alternatively, the selected index can be bound to a state with
+/// [`bind_value`](BindValue::bind_value).
+///
+/// # Example
+/// ```ignore
+/// let tab = cx.use_state(0);
+///
+/// Tabs::new()
+///     .tab("Inventory", Inventory)
+///     .tab("Map", WorldMap)
+///     .bind_value(&tab)
+/// ```
+#[derive(Clone)]
+pub struct Tabs {
+    tabs: Vec<Tab>,
+    selected: usize,
+    on_select: Option<ChangeHandler<usize>>,
+    binding: Option<Binding<usize>>,
+    modifier: Modifier,
+}
+
+impl Tabs {
+    /// Creates a new tab container without any tabs, with the first tab selected.
+    pub fn new() -> Self {
+        Self {
+            tabs: Vec::new(),
+            selected: 0,
+            on_select: None,
+            binding: None,
+            modifier: Modifier::default(),
+        }
+    }
+
+    /// Adds a tab with the given label and content.
+    pub fn tab(mut self, label: impl Display, content: impl Compose + 'static) -> Self {
+        self.tabs.push(Tab {
+            label: label.to_string(),
+            content: DynCompose::new(content),
+        });
+        self
+    }
+
+    /// Sets the index of the selected tab.
+    pub fn selected(mut self, selected: usize) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the function that is called with the index of the new selected tab when a tab header is clicked.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(usize, &mut SetState) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_select = Some(Arc::new(on_select));
+        self
+    }
+}
+
+impl Default for Tabs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BindValue<usize> for Tabs {
+    fn binding(&mut self) -> &mut Option<Binding<usize>> {
+        &mut self.binding
+    }
+}
+
+impl Modify for Tabs {
+    fn modifier(&mut self) -> &mut Modifier {
+        &mut self.modifier
+    }
+}
+
+impl Compose for Tabs {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let selected = cx.use_binding(self.binding.as_ref(), self.selected);
+
+        let headers = self
+            .tabs
+            .iter()
+            .enumerate()
+            .map(|(index, tab)| {
+                let header = TabHeader {
+                    label: tab.label.clone(),
+                    index,
+                    is_selected: *selected == index,
+                    setter: selected.setter(),
+                    on_select: self.on_select.clone(),
+                };
+
+                Keyed::new(index, header)
+            })
+            .collect::<Vec<_>>();
+
+        let header_row = (
+            Node {
+                border: UiRect::bottom(Val::Px(1.0)),
+                ..Default::default()
+            },
+            BorderColor(Srgba::rgb(0.3, 0.3, 0.3).into()),
+        )
+            .children(headers);
+
+        // The content is keyed by the selected index, so that switching between tabs with the same composable type
+        // composes the new tab from scratch instead of reusing the states of the previous one.
+        let content = self
+            .tabs
+            .get(*selected)
+            .map(|tab| Keyed::new(*selected, tab.content.clone()));
+
+        Node {
+            flex_direction: FlexDirection::Column,
+            ..Default::default()
+        }
+        .children((header_row, content))
+        .use_modifier(&self.modifier)
+    }
+
+    fn name(&self) -> String {
+        String::from("Tabs")
+    }
+}
+
+#[derive(Clone)]
+struct TabHeader {
+    label: String,
+    index: usize,
+    is_selected: bool,
+    setter: BoundSetter<usize>,
+    on_select: Option<ChangeHandler<usize>>,
+}
+
+impl Compose for TabHeader {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let is_hovered = cx.use_state(false);
+
+        let index = self.index;
+        let setter = self.setter;
+        let on_select = self.on_select.clone();
+
+        let accent = Srgba::rgb(0.25, 0.5, 0.95);
+
+        let background = match *is_hovered {
+            true => Srgba::rgb(0.2, 0.2, 0.2).into(),
+            false => Color::NONE,
+        };
+
+        (
+            Node {
+                padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)),
+                border: UiRect::bottom(Val::Px(2.0)),
+                ..Default::default()
+            },
+            BackgroundColor(background),
+            BorderColor(if self.is_selected {
+                accent.into()
+            } else {
+                Color::NONE
+            }),
+        )
+            .children(TextLabel::new(&self.label).font_size(14.0))
+            .bind_hover(&is_hovered)
+            .observe(move |_: Trigger<Pointer<Click>>, mut state: SetState| {
+                setter.set(&mut state, index);
+
+                if let Some(on_select) = &on_select {
+                    on_select(index, &mut state);
+                }
+            })
+    }
+
+    fn name(&self) -> String {
+        String::from("TabHeader")
+    }
+}
@@ -0,0 +1,302 @@
+use crate::{
+    cursor::IgnorePickingTree,
+    dyn_compose::DynCompose,
+    modal::MODAL_Z_INDEX,
+    modify::ModifyFunctions,
+    pointer_events::{PointerEvent, PointerEventKind},
+    spawn::Spawn,
+    state::{ReadState, SetState, TypedStateId},
+    Compose, Scope,
+};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    observer::Trigger,
+    system::{Commands, EntityCommands, Query, Res},
+};
+use bevy_math::{Rect, Vec2};
+use bevy_picking::PickingBehavior;
+use bevy_render::view::Visibility;
+use bevy_time::{Real, Time};
+use bevy_transform::components::GlobalTransform;
+use bevy_ui::{ComputedNode, GlobalZIndex, Node, PositionType, Val};
+use std::{sync::Arc, time::Duration};
+
+/// The global z-index of tooltips, which places them above all other UI, including modals.
+pub const TOOLTIP_Z_INDEX: i32 = MODAL_Z_INDEX + 1;
+
+/// The distance between a tooltip and the entity it belongs to, in logical pixels.
+const TOOLTIP_GAP: f32 = 4.0;
+
+/// Where a tooltip is placed relative to the entity it belongs to.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum TooltipPlacement {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+/// The content and options of a tooltip, added to an entity with
+/// [`tooltip`](crate::modify::ModifyFunctions::tooltip). Any composable can be converted into a tooltip with the
+/// default options.
+///
+/// # Example
+/// ```ignore
+/// Button::new("Save").tooltip(
+///     Tooltip::new(TextLabel::new("Saves the document"))
+///         .delay(Duration::from_millis(300))
+///         .placement(TooltipPlacement::Top),
+/// )
+/// ```
+#[derive(Clone)]
+pub struct Tooltip {
+    content: DynCompose,
+    delay: Duration,
+    placement: TooltipPlacement,
+}
+
+impl Tooltip {
+    /// Creates a new tooltip with the given content, which is shown below the entity after hovering it for half a
+    /// second.
+    pub fn new(content: impl Compose + 'static) -> Self {
+        Self {
+            content: DynCompose::new(content),
+            delay: Duration::from_millis(500),
+            placement: TooltipPlacement::default(),
+        }
+    }
+
+    /// Sets how long the entity has to be hovered before the tooltip is shown.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Sets where the tooltip is placed relative to the entity.
+    pub fn placement(mut self, placement: TooltipPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+}
+
+impl<C: Compose + 'static> From<C> for Tooltip {
+    fn from(content: C) -> Self {
+        Self::new(content)
+    }
+}
+
+/// Tracks the hover of an entity with a tooltip. Inserted on the entity by the [`TooltipHost`] of the entity.
+#[derive(Component)]
+pub(crate) struct TooltipTrigger {
+    visible: TypedStateId<bool>,
+    delay: Duration,
+    hovered_since: Option<Duration>,
+    is_leaving: bool,
+    is_visible: bool,
+}
+
+/// Places the tooltip next to the target entity.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct TooltipAnchor {
+    target: Entity,
+    placement: TooltipPlacement,
+}
+
+/// Composes the tooltip of a spawned entity. It is composed as an additional child of the [`Spawn`] composable, so that
+/// the tooltip is decomposed together with the entity.
+#[derive(Clone)]
+pub(crate) struct TooltipHost {
+    pub(crate) tooltip: Tooltip,
+    pub(crate) target: Entity,
+}
+
+impl Compose for TooltipHost {
+    fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+        let visible = cx.use_state(false);
+        let registered_target = cx.use_state(None);
+
+        let target = self.target;
+        let visible_id = visible.get_typed_id();
+        let delay = self.tooltip.delay;
+
+        if *registered_target != Some(target) {
+            let previous_target = *registered_target;
+            cx.set_state_unchanged(&registered_target, Some(target));
+
+            cx.run_system(move |mut commands: Commands| {
+                if let Some(mut ec) = previous_target.and_then(|entity| commands.get_entity(entity))
+                {
+                    ec.remove::<TooltipTrigger>();
+                }
+
+                let Some(mut ec) = commands.get_entity(target) else {
+                    return;
+                };
+
+                ec.try_insert(TooltipTrigger {
+                    visible: visible_id,
+                    delay,
+                    hovered_since: None,
+                    is_leaving: false,
+                    is_visible: false,
+                });
+            });
+        }
+
+        let registered_target_id: TypedStateId<Option<Entity>> = registered_target.get_typed_id();
+
+        // The target is read rather than captured, since the system is only created once.
+        cx.use_unmount_system_once(move |state: ReadState, mut commands: Commands| {
+            let Some(target) = state.get(registered_target_id).and_then(|target| *target) else {
+                return;
+            };
+
+            let Some(mut ec) = commands.get_entity(target) else {
+                return;
+            };
+
+            ec.remove::<TooltipTrigger>();
+        });
+
+        let mut tooltip = Spawn::new((
+            Node {
+                position_type: PositionType::Absolute,
+                ..Default::default()
+            },
+            GlobalZIndex(TOOLTIP_Z_INDEX),
+            PickingBehavior::IGNORE,
+            IgnorePickingTree,
+            TooltipAnchor {
+                target,
+                placement: self.tooltip.placement,
+            },
+        ));
+
+        // The tooltip is hidden until it has been laid out and placed, but only when it is first spawned, since the
+        // bundle is inserted again on every recomposition.
+        tooltip
+            .modifier
+            .bundle_modifiers
+            .push(Arc::new(|entity: &mut EntityCommands| {
+                entity.try_insert_if_new(Visibility::Hidden);
+            }));
+
+        tooltip
+            .children(self.tooltip.content.clone())
+            .detached()
+            .some_if(*visible)
+    }
+
+    fn name(&self) -> String {
+        String::from("Tooltip")
+    }
+}
+
+/// Tracks the hover of entities with tooltips. Added to the entity by the `tooltip` modifier.
+pub(crate) fn track_tooltip_hover(
+    trigger: Trigger<PointerEvent>,
+    mut triggers: Query<&mut TooltipTrigger>,
+    time: Option<Res<Time<Real>>>,
+) {
+    let Ok(mut tooltip_trigger) = triggers.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    match trigger.kind {
+        PointerEventKind::Over => {
+            let now = time.map_or(Duration::ZERO, |time| time.elapsed());
+            tooltip_trigger.hovered_since.get_or_insert(now);
+            tooltip_trigger.is_leaving = false;
+        }
+        // Moving the pointer between the children of the entity causes an `Out` event followed by an `Over` event, which
+        // shouldn't restart the delay. Leaving is therefore only applied after all events of the frame.
+        PointerEventKind::Out => tooltip_trigger.is_leaving = true,
+        _ => {}
+    }
+}
+
+pub(crate) fn update_tooltip_triggers(
+    time: Option<Res<Time<Real>>>,
+    mut triggers: Query<&mut TooltipTrigger>,
+    mut state: SetState,
+) {
+    let now = time.map(|time| time.elapsed());
+
+    for mut tooltip_trigger in triggers.iter_mut() {
+        if tooltip_trigger.is_leaving {
+            tooltip_trigger.hovered_since = None;
+            tooltip_trigger.is_leaving = false;
+        }
+
+        let is_visible = match (tooltip_trigger.hovered_since, now) {
+            (Some(hovered_since), Some(now)) => {
+                now.saturating_sub(hovered_since) >= tooltip_trigger.delay
+            }
+            // Without a time source there is nothing to wait for, so the tooltip is shown right away.
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+
+        if tooltip_trigger.is_visible == is_visible {
+            continue;
+        }
+
+        tooltip_trigger.is_visible = is_visible;
+        state.set(tooltip_trigger.visible, is_visible);
+    }
+}
+
+/// Places tooltips next to their target entities, based on the layout of the previous frame.
+pub(crate) fn place_tooltips(
+    targets: Query<(&ComputedNode, &GlobalTransform)>,
+    mut tooltips: Query<(&TooltipAnchor, &ComputedNode, &mut Node, &mut Visibility)>,
+) {
+    for (anchor, tooltip_node, mut node, mut visibility) in tooltips.iter_mut() {
+        let Ok((target_node, target_transform)) = targets.get(anchor.target) else {
+            continue;
+        };
+
+        let scale = target_node.inverse_scale_factor();
+        let target_size = target_node.size() * scale;
+        let target_center = target_transform.translation().truncate() * scale;
+        let target_rect = Rect::from_center_size(target_center, target_size);
+        let size = tooltip_node.size() * tooltip_node.inverse_scale_factor();
+
+        // The tooltip hasn't been laid out yet, so it can't be placed.
+        if size == Vec2::ZERO {
+            continue;
+        }
+
+        let position = match anchor.placement {
+            TooltipPlacement::Top => Vec2::new(
+                target_center.x - size.x / 2.0,
+                target_rect.min.y - TOOLTIP_GAP - size.y,
+            ),
+            TooltipPlacement::Bottom => Vec2::new(
+                target_center.x - size.x / 2.0,
+                target_rect.max.y + TOOLTIP_GAP,
+            ),
+            TooltipPlacement::Left => Vec2::new(
+                target_rect.min.x - TOOLTIP_GAP - size.x,
+                target_center.y - size.y / 2.0,
+            ),
+            TooltipPlacement::Right => Vec2::new(
+                target_rect.max.x + TOOLTIP_GAP,
+                target_center.y - size.y / 2.0,
+            ),
+        };
+
+        let (left, top) = (Val::Px(position.x), Val::Px(position.y));
+
+        if node.left != left || node.top != top {
+            node.left = left;
+            node.top = top;
+        }
+
+        if *visibility != Visibility::Inherited {
+            *visibility = Visibility::Inherited;
+        }
+    }
+}
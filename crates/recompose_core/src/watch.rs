@@ -0,0 +1,119 @@
+use crate::{
+    state::{GetStateId, State, StateId, StateSetter, StateSetterAction},
+    Scope,
+};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::{Component, Tick},
+    entity::Entity,
+    system::{ResMut, Resource},
+    world::{EntityRef, Mut, World},
+};
+use std::collections::HashMap;
+
+/// When the watched component was last seen. `None` means that the subscription hasn't been updated yet, and
+/// `Some(None)` that the entity didn't have the component.
+type LastSeen = Option<Option<Tick>>;
+
+type ReadFn =
+    Box<dyn Fn(Option<EntityRef>, &mut LastSeen) -> Option<StateSetterAction> + Send + Sync>;
+
+struct ComponentSubscription {
+    entity: Entity,
+    read: ReadFn,
+    last_seen: LastSeen,
+}
+
+/// The components that are watched by [`use_component`](Scope::use_component), keyed by the id of the state that
+/// mirrors the component.
+#[derive(Resource, Default)]
+pub(crate) struct ComponentSubscriptions {
+    subscriptions: HashMap<StateId, ComponentSubscription>,
+}
+
+pub(crate) fn update_component_subscriptions(world: &mut World) {
+    let actions = world.resource_scope(|world, mut subscriptions: Mut<ComponentSubscriptions>| {
+        let mut actions = Vec::new();
+
+        for (state_id, subscription) in subscriptions.subscriptions.iter_mut() {
+            let entity = world.get_entity(subscription.entity).ok();
+
+            if let Some(action) = (subscription.read)(entity, &mut subscription.last_seen) {
+                actions.push((*state_id, action));
+            }
+        }
+
+        actions
+    });
+
+    let mut setter = world.resource_mut::<StateSetter>();
+
+    for (state_id, action) in actions {
+        setter.queued.insert(state_id, action);
+    }
+}
+
+impl Scope<'_> {
+    /// Returns the component of an arbitrary entity as a state. The composable is recomposed when the component is
+    /// changed, added or removed. The state is `None` while the entity doesn't have the component, and until the
+    /// component is first read, which happens before the next recomposition.
+    ///
+    /// This is useful for binding the UI to the game world, such as a health bar that displays the health of an enemy.
+    ///
+    /// # Example
+    /// ```ignore
+    /// fn health_bar(cx: &mut Scope, enemy: Entity) -> impl Compose {
+    ///     let health = cx.use_component::<Health>(enemy);
+    ///     let current = health.as_ref().map_or(0.0, |health| health.current);
+    ///
+    ///     Text::new(format!("{current}"))
+    /// }
+    /// ```
+    pub fn use_component<T: Component + Clone + PartialEq>(
+        &mut self,
+        entity: Entity,
+    ) -> State<Option<T>> {
+        let component = self.use_state(None);
+        let watched_entity = self.use_state(None);
+        let state_id = component.get_id();
+
+        // The subscription is replaced when the entity changes, so that the hook follows the entity it is given.
+        if *watched_entity != Some(entity) {
+            self.set_state_unchanged(&watched_entity, Some(entity));
+
+            self.run_system(move |mut subscriptions: ResMut<ComponentSubscriptions>| {
+                subscriptions.subscriptions.insert(
+                    state_id,
+                    ComponentSubscription {
+                        entity,
+                        read: Box::new(read_component::<T>),
+                        last_seen: None,
+                    },
+                );
+            });
+        }
+
+        self.use_unmount_system_once(move |mut subscriptions: ResMut<ComponentSubscriptions>| {
+            subscriptions.subscriptions.remove(&state_id);
+        });
+
+        component
+    }
+}
+
+fn read_component<T: Component + Clone + PartialEq>(
+    entity: Option<EntityRef>,
+    last_seen: &mut LastSeen,
+) -> Option<StateSetterAction> {
+    let component = entity.and_then(|entity| entity.get_ref::<T>());
+    let last_changed = component.as_ref().map(|component| component.last_changed());
+
+    if *last_seen == Some(last_changed) {
+        return None;
+    }
+
+    *last_seen = Some(last_changed);
+
+    let value = component.map(|component| component.clone());
+    Some(StateSetterAction::set_neq(value))
+}
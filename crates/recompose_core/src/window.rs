@@ -0,0 +1,72 @@
+use crate::{store::Store, Scope};
+use bevy_ecs::{
+    query::With,
+    system::{Query, ResMut},
+};
+use bevy_math::{UVec2, Vec2};
+use bevy_window::{PrimaryWindow, Window};
+
+/// The size, scale factor and focus of the primary window. The info is a [`Store`], so it can be read by systems with
+/// `Res<Store<WindowInfo>>`, and by composables with [`use_window`](Scope::use_window).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct WindowInfo {
+    /// The size of the window in logical pixels.
+    pub size: Vec2,
+    /// The size of the window in physical pixels.
+    pub physical_size: UVec2,
+    /// The ratio of physical pixels to logical pixels.
+    pub scale_factor: f32,
+    /// Whether the window has focus.
+    pub focused: bool,
+}
+
+impl Default for WindowInfo {
+    fn default() -> Self {
+        Self {
+            size: Vec2::ZERO,
+            physical_size: UVec2::ZERO,
+            scale_factor: 1.0,
+            focused: false,
+        }
+    }
+}
+
+pub(crate) fn track_primary_window(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut info: ResMut<Store<WindowInfo>>,
+) {
+    let window = windows
+        .get_single()
+        .map(|window| WindowInfo {
+            size: window.resolution.size(),
+            physical_size: window.resolution.physical_size(),
+            scale_factor: window.resolution.scale_factor(),
+            focused: window.focused,
+        })
+        .unwrap_or_default();
+
+    // The store is only touched when the window has changed, so that subscribers aren't notified every frame.
+    if info.0 != window {
+        info.0 = window;
+    }
+}
+
+impl Scope<'_> {
+    /// Returns the [`WindowInfo`] of the primary window. The composable is recomposed when the size, scale factor or
+    /// focus of the window changes. Without a primary window, the default info is returned.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let window = cx.use_window();
+    ///
+    /// if window.size.x < 600.0 {
+    ///     DynCompose::new(CompactLayout)
+    /// } else {
+    ///     DynCompose::new(WideLayout)
+    /// }
+    /// ```
+    pub fn use_window(&mut self) -> WindowInfo {
+        let window = self.use_store_selector(|window: &WindowInfo| *window);
+        window.unwrap_or_default()
+    }
+}
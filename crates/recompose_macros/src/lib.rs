@@ -0,0 +1,371 @@
+//! Derive macros for the [`recompose`](https://docs.rs/recompose/) crate. The macros are re-exported by `recompose`, so
+//! this crate shouldn't be used directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Field, Fields, Ident, Index};
+
+/// Derives `Compose` for a struct whose composition is a pure function of its fields. The struct has to provide a `view`
+/// method that returns the composable, and implement `PartialEq`.
+///
+/// The derive implements `Clone` for the struct, and composes the struct by calling `view`. When the parent recomposes
+/// and the struct is equal to the one of the previous composition, neither the struct nor its children are recomposed.
+/// See `Compose::is_unchanged` for more information.
+///
+/// # Example
+/// ```ignore
+/// #[derive(PartialEq, ComposeView)]
+/// struct Greeting {
+///     name: String,
+/// }
+///
+/// impl Greeting {
+///     fn view<'a>(&self) -> impl Compose + 'a {
+///         Text::new(format!("Hello, {}!", self.name)).to_compose()
+///     }
+/// }
+/// ```
+#[proc_macro_derive(ComposeView)]
+pub fn derive_compose_view(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let Data::Struct(data) = &input.data else {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "ComposeView can only be derived for structs.",
+        )
+        .to_compile_error()
+        .into();
+    };
+
+    let ident = &input.ident;
+    let name = ident.to_string();
+    let clone_body = clone_fields(&data.fields);
+
+    let mut clone_generics = input.generics.clone();
+
+    for param in clone_generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::core::clone::Clone));
+    }
+
+    let mut compose_generics = input.generics.clone();
+
+    for param in compose_generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::core::clone::Clone));
+        param.bounds.push(parse_quote!(::core::cmp::PartialEq));
+        param.bounds.push(parse_quote!(::core::marker::Send));
+        param.bounds.push(parse_quote!(::core::marker::Sync));
+        param.bounds.push(parse_quote!('static));
+    }
+
+    let (clone_impl_generics, ty_generics, where_clause) = clone_generics.split_for_impl();
+    let (compose_impl_generics, _, _) = compose_generics.split_for_impl();
+
+    quote! {
+        impl #clone_impl_generics ::core::clone::Clone for #ident #ty_generics #where_clause {
+            fn clone(&self) -> Self {
+                #clone_body
+            }
+        }
+
+        impl #compose_impl_generics ::recompose::prelude::Compose for #ident #ty_generics #where_clause {
+            fn compose<'a>(
+                &self,
+                _: &mut ::recompose::prelude::Scope,
+            ) -> impl ::recompose::prelude::Compose + 'a {
+                self.view()
+            }
+
+            fn is_unchanged(&self, previous: &Self) -> bool {
+                self == previous
+            }
+
+            fn name(&self) -> ::std::string::String {
+                ::std::string::String::from(#name)
+            }
+        }
+    }
+    .into()
+}
+
+/// Derives `Compose` for a struct, which removes the boilerplate that most widgets share. The composition of the struct
+/// is defined in one of two ways:
+///
+/// - By a method that is named with the `#[compose(method)]` attribute on the struct. The method has the same signature
+///   as `Compose::compose`.
+/// - By a field named `body`, or a field with the `#[compose]` attribute, which is composed as-is.
+///
+/// The derive also implements `Clone` for the struct, and `Modify` when the struct has a `modifier` field, so that all
+/// the modifier functions can be used on it. Unlike with `ComposeView`, the struct is recomposed every time its parent
+/// recomposes.
+///
+/// # Example
+/// ```ignore
+/// #[derive(Compose)]
+/// #[compose(render)]
+/// struct Card {
+///     title: String,
+///     modifier: Modifier,
+/// }
+///
+/// impl Card {
+///     fn render<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
+///         Node::default()
+///             .use_modifier(&self.modifier)
+///             .children(Text::new(self.title.clone()).to_compose())
+///     }
+/// }
+/// ```
+#[proc_macro_derive(Compose, attributes(compose))]
+pub fn derive_compose(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let Data::Struct(data) = &input.data else {
+        return syn::Error::new_spanned(&input.ident, "Compose can only be derived for structs.")
+            .to_compile_error()
+            .into();
+    };
+
+    let method = match compose_method(&input) {
+        Ok(method) => method,
+        Err(error) => return error.to_compile_error().into(),
+    };
+
+    let body_field = data.fields.iter().position(is_body_field);
+
+    let compose_body = match (method, body_field) {
+        (Some(method), None) => quote! { self.#method(cx) },
+        (None, Some(index)) => {
+            let member = field_member(&data.fields, index);
+            quote! { ::core::clone::Clone::clone(&self.#member) }
+        }
+        (Some(_), Some(_)) => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "Compose can't be derived with both a `#[compose(method)]` attribute and a body field.",
+            )
+            .to_compile_error()
+            .into();
+        }
+        (None, None) => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "Compose requires a `#[compose(method)]` attribute or a body field.",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let ident = &input.ident;
+    let name = ident.to_string();
+    let clone_body = clone_fields(&data.fields);
+
+    let mut clone_generics = input.generics.clone();
+
+    for param in clone_generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::core::clone::Clone));
+    }
+
+    let mut compose_generics = input.generics.clone();
+
+    for param in compose_generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::core::clone::Clone));
+        param.bounds.push(parse_quote!(::core::marker::Send));
+        param.bounds.push(parse_quote!(::core::marker::Sync));
+        param.bounds.push(parse_quote!('static));
+    }
+
+    let (clone_impl_generics, ty_generics, where_clause) = clone_generics.split_for_impl();
+    let (compose_impl_generics, _, _) = compose_generics.split_for_impl();
+
+    let modifier_field = data.fields.iter().position(|field| {
+        field
+            .ident
+            .as_ref()
+            .is_some_and(|ident| ident == "modifier")
+    });
+
+    let modify_impl = modifier_field.map(|index| {
+        let member = field_member(&data.fields, index);
+
+        quote! {
+            impl #compose_impl_generics ::recompose::prelude::Modify for #ident #ty_generics #where_clause {
+                fn modifier(&mut self) -> &mut ::recompose::prelude::Modifier {
+                    &mut self.#member
+                }
+            }
+        }
+    });
+
+    quote! {
+        impl #clone_impl_generics ::core::clone::Clone for #ident #ty_generics #where_clause {
+            fn clone(&self) -> Self {
+                #clone_body
+            }
+        }
+
+        impl #compose_impl_generics ::recompose::prelude::Compose for #ident #ty_generics #where_clause {
+            fn compose<'a>(
+                &self,
+                cx: &mut ::recompose::prelude::Scope,
+            ) -> impl ::recompose::prelude::Compose + 'a {
+                #compose_body
+            }
+
+            fn name(&self) -> ::std::string::String {
+                ::std::string::String::from(#name)
+            }
+        }
+
+        #modify_impl
+    }
+    .into()
+}
+
+/// Derives `Key` for a struct, so that it can be composed in a `Vec`. The struct is keyed by the field with the `#[key]`
+/// attribute, which has to implement `KeyValue`. Newtypes with a single field are keyed by that field, so they don't need
+/// the attribute.
+///
+/// # Example
+/// ```ignore
+/// #[derive(Clone, Key)]
+/// struct Todo {
+///     #[key]
+///     id: u32,
+///     label: String,
+/// }
+///
+/// #[derive(Clone, Key)]
+/// struct PlayerId(u64);
+/// ```
+#[proc_macro_derive(Key, attributes(key))]
+pub fn derive_key(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let Data::Struct(data) = &input.data else {
+        return syn::Error::new_spanned(&input.ident, "Key can only be derived for structs.")
+            .to_compile_error()
+            .into();
+    };
+
+    let key_fields = data
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| field.attrs.iter().any(|attr| attr.path().is_ident("key")))
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+
+    let key_field = match (key_fields.as_slice(), data.fields.len()) {
+        ([index], _) => *index,
+        ([], 1) => 0,
+        ([], _) => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "Key requires a `#[key]` attribute on the field that the struct is keyed by.",
+            )
+            .to_compile_error()
+            .into();
+        }
+        _ => {
+            return syn::Error::new_spanned(&input.ident, "Only one `#[key]` field is allowed.")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let ident = &input.ident;
+    let member = field_member(&data.fields, key_field);
+
+    let mut generics = input.generics.clone();
+
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::core::marker::Send));
+        param.bounds.push(parse_quote!(::core::marker::Sync));
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::recompose::prelude::Key for #ident #ty_generics #where_clause {
+            fn key(&self) -> &impl ::std::hash::Hash {
+                &self.#member
+            }
+
+            fn key_value(&self) -> ::core::option::Option<::recompose::prelude::KeyValueRef<'_>> {
+                ::core::option::Option::Some(::recompose::prelude::KeyValueRef::new(&self.#member))
+            }
+        }
+    }
+    .into()
+}
+
+/// Returns the method named by the `#[compose(method)]` attribute on the struct, if any.
+fn compose_method(input: &DeriveInput) -> syn::Result<Option<Ident>> {
+    let mut method = None;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("compose"))
+    {
+        if method.is_some() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "Only one `#[compose(method)]` attribute is allowed.",
+            ));
+        }
+
+        method = Some(attr.parse_args::<Ident>()?);
+    }
+
+    Ok(method)
+}
+
+fn is_body_field(field: &Field) -> bool {
+    let is_named_body = field.ident.as_ref().is_some_and(|ident| ident == "body");
+    let has_attribute = field
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("compose"));
+
+    is_named_body || has_attribute
+}
+
+fn field_member(fields: &Fields, index: usize) -> TokenStream2 {
+    match fields
+        .iter()
+        .nth(index)
+        .and_then(|field| field.ident.as_ref())
+    {
+        Some(ident) => quote! { #ident },
+        None => {
+            let index = Index::from(index);
+            quote! { #index }
+        }
+    }
+}
+
+fn clone_fields(fields: &Fields) -> TokenStream2 {
+    match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+
+            quote! {
+                Self {
+                    #(#names: ::core::clone::Clone::clone(&self.#names),)*
+                }
+            }
+        }
+        Fields::Unnamed(fields) => {
+            let indices = (0..fields.unnamed.len()).map(Index::from);
+
+            quote! {
+                Self(#(::core::clone::Clone::clone(&self.#indices),)*)
+            }
+        }
+        Fields::Unit => quote! { Self },
+    }
+}
@@ -11,7 +11,7 @@ fn main() {
 
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
-    commands.spawn((Root::new(squares), Node::default()));
+    commands.spawn_root(squares);
 }
 
 // `Fn(&mut Scope) -> impl Compose` implements Compose, so we can use functions for simple composables.
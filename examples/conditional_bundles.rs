@@ -11,7 +11,7 @@ fn main() {
 
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
-    commands.spawn((Root::new(squares), Node::default()));
+    commands.spawn_root(squares);
 }
 
 fn squares<'a>(cx: &mut Scope) -> impl Compose + use<'a> {
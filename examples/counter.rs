@@ -11,7 +11,7 @@ fn main() {
 
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
-    commands.spawn((Root::new(counter), Node::default()));
+    commands.spawn_root(counter);
 }
 
 fn counter<'a>(cx: &mut Scope) -> impl Compose + use<'a> {
@@ -11,7 +11,7 @@ fn main() {
 
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
-    commands.spawn((Root::new(squares_or_circles), Node::default()));
+    commands.spawn_root(squares_or_circles);
 }
 
 fn squares_or_circles<'a>(cx: &mut Scope) -> impl Compose + use<'a> {
@@ -16,7 +16,7 @@ fn main() {
 
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2d);
-    commands.spawn((Root::new(timer), Node::default()));
+    commands.spawn_root(timer);
 }
 
 // We use `TypedStateId` which holds both our manually created StateId, as well as the type generic of out desired
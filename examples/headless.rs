@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use recompose::prelude::*;
+
+// Composes a small scene without a window or renderer, and prints it in the scene format. This can be used by tools that
+// generate scenes or prefabs offline.
+fn main() {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).register_type::<Transform>();
+
+    let scene = compose_to_scene(&mut app, row);
+
+    let type_registry = app.world().resource::<AppTypeRegistry>().read();
+    let serialized = scene.serialize(&type_registry).unwrap();
+
+    println!("{serialized}");
+}
+
+fn row<'a>(_: &mut Scope) -> impl Compose + use<'a> {
+    Spawn::new((Name::new("Row"), Transform::default())).children((
+        Pillar(0.0),
+        Pillar(2.0),
+        Pillar(4.0),
+    ))
+}
+
+#[derive(Clone)]
+struct Pillar(f32);
+
+impl Compose for Pillar {
+    fn compose<'a>(&self, _: &mut Scope) -> impl Compose + 'a {
+        Spawn::new((Name::new("Pillar"), Transform::from_xyz(self.0, 0.0, 0.0)))
+    }
+}
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use recompose::prelude::*;
+use std::f32::consts::TAU;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(RecomposePlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+    // Roots that compose world-space entities need a `Transform` and `Visibility` for the hierarchy to propagate.
+    commands.spawn((
+        Root::new(orbit),
+        Transform::default(),
+        Visibility::default(),
+    ));
+}
+
+fn orbit<'a>(cx: &mut Scope) -> impl Compose + use<'a> {
+    let angle = cx.use_state(0.0_f32);
+
+    cx.set_state(&angle, (*angle + 0.01) % TAU);
+
+    // The children are positioned relative to the rotating parent.
+    Spawn::spatial(Transform::from_rotation(Quat::from_rotation_z(*angle))).children((
+        Planet(Srgba::RED.into(), Vec2::new(100.0, 0.0)),
+        Planet(Srgba::GREEN.into(), Vec2::new(-100.0, 0.0)),
+        Planet(Srgba::BLUE.into(), Vec2::new(0.0, 160.0)),
+    ))
+}
+
+#[derive(Clone)]
+struct Planet(Color, Vec2);
+
+impl Compose for Planet {
+    fn compose<'a>(&self, _: &mut Scope) -> impl Compose + 'a {
+        Spawn::spatial((
+            Sprite::from_color(self.0, Vec2::splat(32.0)),
+            Transform::from_translation(self.1.extend(0.0)),
+        ))
+    }
+}
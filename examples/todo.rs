@@ -4,7 +4,6 @@ use bevy::{
     prelude::*,
 };
 use recompose::prelude::*;
-use std::hash::Hash;
 
 fn main() {
     App::new()
@@ -72,10 +71,7 @@ fn todo<'a>(cx: &mut Scope) -> impl Compose + use<'a> {
                 ..default()
             }
             .children((
-                InputField {
-                    value: (*input).clone(),
-                    input_ref: input.get_typed_id(),
-                },
+                InputField::default().bind_value(&input),
                 Button {
                     label: "Add".to_string(),
                     color: tailwind::GREEN_300.into(),
@@ -89,11 +85,7 @@ fn todo<'a>(cx: &mut Scope) -> impl Compose + use<'a> {
                         return;
                     }
 
-                    state.modify(&todos, move |todos| {
-                        let mut todos = todos.clone();
-                        todos.push(input_value.clone());
-                        todos
-                    });
+                    state.push(&todos, input_value);
 
                     state.set(input.clone(), "".to_string());
                 }),
@@ -101,18 +93,13 @@ fn todo<'a>(cx: &mut Scope) -> impl Compose + use<'a> {
         ))
 }
 
-#[derive(Clone)]
+#[derive(Clone, Key)]
 struct Todo {
+    #[key]
     label: String,
     all_todos: TypedStateId<Vec<String>>,
 }
 
-impl Key for Todo {
-    fn key(&self) -> &impl Hash {
-        &self.label
-    }
-}
-
 impl Compose for Todo {
     fn compose<'a>(&self, _: &mut Scope) -> impl Compose + 'a {
         let all_todos = self.all_todos;
@@ -144,11 +131,7 @@ impl Compose for Todo {
             }
             .observe(move |_: Trigger<Pointer<Click>>, mut state: SetState| {
                 let label = label.clone();
-                state.modify(all_todos, move |todos| {
-                    let mut todos = todos.clone();
-                    todos.retain(|todo_label| *todo_label != label);
-                    todos
-                });
+                state.remove_where(all_todos, move |todo_label| *todo_label == label);
             }),
         ))
     }
@@ -197,10 +180,15 @@ impl Compose for Button {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 struct InputField {
-    value: String,
-    input_ref: TypedStateId<String>,
+    binding: Option<Binding<String>>,
+}
+
+impl BindValue<String> for InputField {
+    fn binding(&mut self) -> &mut Option<Binding<String>> {
+        &mut self.binding
+    }
 }
 
 // This is a very rudimentary (and bad!) implementation of an input field, but it works for this example.
@@ -208,7 +196,8 @@ impl Compose for InputField {
     fn compose<'a>(&self, cx: &mut Scope) -> impl Compose + 'a {
         let is_focused = cx.use_state(false);
         let is_focused_ref = is_focused.to_ref();
-        let input_ref = self.input_ref;
+        let value = cx.use_binding(self.binding.as_ref(), String::new());
+        let value_setter = value.setter();
 
         if *is_focused {
             cx.run_system(
@@ -224,7 +213,7 @@ impl Compose for InputField {
                     let events = key_events.read().cloned().collect::<Vec<_>>();
                     let was_just_pressed = just_pressed.get_just_pressed().len() > 0;
 
-                    state.modify(input_ref, move |val| {
+                    value_setter.modify(&mut state, move |val| {
                         let mut new_string = val.clone();
 
                         if was_just_pressed {
@@ -274,7 +263,7 @@ impl Compose for InputField {
                 (
                     Text::new(format!(
                         "{}{}",
-                        self.value.clone(),
+                        *value,
                         if *is_focused { "|" } else { " " }
                     )),
                     TextColor(tailwind::SLATE_900.into()),
@@ -283,7 +272,7 @@ impl Compose for InputField {
                     .to_compose(),
             )
             .observe(move |_: Trigger<Pointer<Click>>, mut state: SetState| {
-                state.set(&is_focused, !*is_focused);
+                state.toggle(&is_focused);
             })
     }
 }
@@ -21,7 +21,7 @@
 //!
 //! fn setup(mut commands: Commands) {
 //!     commands.spawn(Camera2d);
-//!     commands.spawn((Root::new(squares), Node::default()));
+//!     commands.spawn_root(squares);
 //! }
 //!
 //! // `Fn(&mut Scope) -> impl Compose` implements Compose, so we can use functions for simple composables.
@@ -91,6 +91,9 @@
 //!
 //! // Run a system once, when the composable is first composed.
 //! cx.use_system_once(|| { /* .. */ });
+//!
+//! // Run a system only when dependencies have changed.
+//! cx.use_effect_system(|names: Query<&Name>| { /* .. */ }, (&count, &name));
 //! ```
 //!
 //! # How it works
@@ -108,9 +111,10 @@
 //! - [`Spawn`](prelude::Spawn) - Spawns a new entity with the from a bundle.
 //! - [`DynCompose`](prelude::DynCompose) - Allows for dynamic composables that "erase" their type definition.
 //! - `Option<C>` - Composes `C` if the option is `Some`, otherwise does nothing.
+//! - `Cow<'static, C>` - Composes `C`, which lets shared props be borrowed instead of cloned.
 //! - Tuples `(C0, .., C9)` - Compose multiple composables at once.
 //! - `Vec<C>` - Compose any number of composables. This requires that the items implement the
-//!   [`Key`](prelude::Key)-trait.
+//!   [`Key`](prelude::Key)-trait, which can be derived with `#[derive(Key)]` and a `#[key]` field.
 //!     - [`Keyed`](prelude::Keyed) - Implements the `Key`-trait and can be used to wrap any composable. The added
 //!       advantage is that the type is "erased" so that composables of different types can be composed in the same
 //!       `Vec`.
@@ -137,12 +141,59 @@
 //! and easy to understand.
 
 pub mod prelude {
+    pub use recompose_core::action::*;
+    pub use recompose_core::animated_list::*;
+    pub use recompose_core::asset::*;
+    pub use recompose_core::binding::*;
     pub use recompose_core::bundle_extension::*;
+    pub use recompose_core::checkbox::*;
+    pub use recompose_core::cursor::*;
+    pub use recompose_core::diagnostics::*;
+    pub use recompose_core::dock::*;
+    pub use recompose_core::drag_drop::*;
+    pub use recompose_core::dropdown::*;
     pub use recompose_core::dyn_compose::*;
+    pub use recompose_core::error_boundary::*;
+    pub use recompose_core::feature_flags::*;
+    pub use recompose_core::flex::*;
+    pub use recompose_core::focus::*;
+    pub use recompose_core::fragment::*;
+    pub use recompose_core::gestures::*;
+    pub use recompose_core::grid::*;
+    pub use recompose_core::headless::*;
+    pub use recompose_core::icon::*;
+    pub use recompose_core::interaction::*;
+    pub use recompose_core::island::*;
     pub use recompose_core::keyed::*;
+    pub use recompose_core::label::*;
+    pub use recompose_core::layout::*;
+    pub use recompose_core::layout_direction::*;
+    pub use recompose_core::localization::*;
+    pub use recompose_core::memo::*;
+    pub use recompose_core::modal::*;
     pub use recompose_core::modify::*;
+    pub use recompose_core::perf_overlay::*;
+    pub use recompose_core::pointer_events::*;
+    pub use recompose_core::radio_group::*;
+    pub use recompose_core::reducer::*;
+    pub use recompose_core::reorderable_list::*;
     pub use recompose_core::scope::*;
+    pub use recompose_core::scroll_view::*;
+    pub use recompose_core::selection::*;
+    #[cfg(feature = "session")]
+    pub use recompose_core::session::*;
+    pub use recompose_core::settings::*;
+    pub use recompose_core::show::*;
+    pub use recompose_core::slider::*;
     pub use recompose_core::spawn::*;
+    pub use recompose_core::split_pane::*;
     pub use recompose_core::state::*;
+    pub use recompose_core::state_mirror::*;
+    pub use recompose_core::store::*;
+    pub use recompose_core::styled_text::*;
+    pub use recompose_core::tabs::*;
+    pub use recompose_core::tooltip::*;
+    pub use recompose_core::window::*;
     pub use recompose_core::*;
+    pub use recompose_macros::{Compose, ComposeView, Key};
 }